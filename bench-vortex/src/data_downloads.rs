@@ -10,7 +10,7 @@ use lance::Dataset;
 use log::info;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use tokio::runtime::Runtime;
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex::{IntoArray, ToArrayData};
 use vortex_dtype::DType;
 use vortex_error::{VortexError, VortexResult};
@@ -62,7 +62,7 @@ pub fn data_vortex_uncompressed(fname_out: &str, downloaded_data: PathBuf) -> Pa
         let mut write = File::create(path).unwrap();
         let mut writer = StreamWriter::try_new(&mut write, &CTX).unwrap();
 
-        let dtype = DType::from_arrow(reader.schema());
+        let dtype = DType::try_from_arrow(reader.schema()).unwrap();
         writer.write_schema(&dtype).unwrap();
         for batch_result in reader {
             writer
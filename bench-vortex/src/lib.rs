@@ -13,15 +13,18 @@ use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::arrow::ProjectionMask;
 use simplelog::{ColorChoice, Config, TermLogger, TerminalMode};
 use vortex::array::chunked::ChunkedArray;
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex::compress::Compressor;
 use vortex::encoding::EncodingRef;
 use vortex::{Context, IntoArray, OwnedArray, ToArrayData};
-use vortex_alp::ALPEncoding;
+use vortex_alp::{ALPEncoding, ALPRDEncoding};
+use vortex_byte_stream_split::ByteStreamSplitEncoding;
 use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
 use vortex_dtype::DType;
 use vortex_fastlanes::{BitPackedEncoding, FoREncoding};
+use vortex_fsst::FSSTEncoding;
+use vortex_gorilla::GorillaEncoding;
 use vortex_ree::REEEncoding;
 use vortex_roaring::RoaringBoolEncoding;
 
@@ -39,10 +42,14 @@ pub mod vortex_utils;
 lazy_static! {
     pub static ref CTX: Context = Context::default().with_encodings([
         &ALPEncoding as EncodingRef,
+        &ALPRDEncoding,
+        &ByteStreamSplitEncoding,
         &DictEncoding,
         &BitPackedEncoding,
         &FoREncoding,
         &DateTimePartsEncoding,
+        &FSSTEncoding,
+        &GorillaEncoding,
         // &DeltaEncoding,  Blows up the search space too much.
         &REEEncoding,
         &RoaringBoolEncoding,
@@ -149,7 +156,7 @@ pub fn compress_taxi_data() -> OwnedArray {
         })
         .collect_vec();
 
-    let compressed = ChunkedArray::try_new(chunks.clone(), DType::from_arrow(schema))
+    let compressed = ChunkedArray::try_new(chunks.clone(), DType::try_from_arrow(schema).unwrap())
         .unwrap()
         .into_array();
 
@@ -18,31 +18,25 @@ use log::info;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use tokio::runtime::Runtime;
 use vortex::array::chunked::ChunkedArray;
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex::compress::Compressor;
-use vortex::compute::take::take;
-use vortex::{IntoArray, OwnedArray, ToArrayData, ToStatic};
+use vortex::{IntoArray, OwnedArray, ToArrayData};
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
-use vortex_ipc::iter::FallibleLendingIterator;
-use vortex_ipc::reader::StreamReader;
-use vortex_ipc::writer::StreamWriter;
+use vortex_ipc::file::bytes_reader::BytesFileReader;
+use vortex_ipc::file::reader::FileReader;
+use vortex_ipc::file::writer::FileWriter;
 
 use crate::CTX;
 
 pub const BATCH_SIZE: usize = 65_536;
 
 pub fn open_vortex(path: &Path) -> VortexResult<OwnedArray> {
-    let mut file = File::open(path)?;
-
-    let mut reader = StreamReader::try_new(&mut file, &CTX)?;
-    let mut reader = reader.next()?.unwrap();
-    let dtype = reader.dtype().clone();
-    let mut chunks = vec![];
-    while let Some(chunk) = reader.next()? {
-        chunks.push(chunk.to_static())
-    }
-    Ok(ChunkedArray::try_new(chunks, dtype)?.into_array())
+    let reader = BytesFileReader::open(path, &CTX)?;
+    let chunks = (0..reader.nchunks())
+        .map(|idx| reader.read_chunk(idx))
+        .try_collect()?;
+    Ok(ChunkedArray::try_new(chunks, reader.dtype().clone())?.into_array())
 }
 
 pub fn rewrite_parquet_as_vortex<W: Write>(
@@ -51,9 +45,9 @@ pub fn rewrite_parquet_as_vortex<W: Write>(
 ) -> VortexResult<()> {
     let chunked = compress_parquet_to_vortex(parquet_path.as_path())?;
 
-    let mut writer = StreamWriter::try_new(write, &CTX).unwrap();
-    writer.write_array(&chunked.into_array()).unwrap();
-    Ok(())
+    let mut writer = FileWriter::try_new(write, &CTX)?;
+    writer.write_array(&chunked.into_array())?;
+    writer.finish()
 }
 
 pub fn compress_parquet_to_vortex(parquet_path: &Path) -> VortexResult<ChunkedArray<'static>> {
@@ -63,7 +57,7 @@ pub fn compress_parquet_to_vortex(parquet_path: &Path) -> VortexResult<ChunkedAr
     // FIXME(ngates): #157 the compressor should handle batch size.
     let reader = builder.with_batch_size(BATCH_SIZE).build()?;
 
-    let dtype = DType::from_arrow(reader.schema());
+    let dtype = DType::try_from_arrow(reader.schema())?;
 
     let chunks = reader
         .map(|batch_result| batch_result.unwrap())
@@ -94,8 +88,10 @@ pub fn write_csv_as_parquet(csv_path: PathBuf, output_path: &Path) -> VortexResu
 }
 
 pub fn take_vortex(path: &Path, indices: &[u64]) -> VortexResult<OwnedArray> {
-    let array = open_vortex(path)?;
-    let taken = take(&array, &indices.to_vec().into_array())?;
+    let file = File::open(path)?;
+    let mut reader = FileReader::try_new(file, &CTX)?;
+    // Only the chunks containing the requested rows are read from the file.
+    let taken = reader.take(&indices.to_vec().into_array())?;
     // For equivalence.... we flatten to make sure we're not cheating too much.
     taken.flatten().map(|x| x.into_array())
 }
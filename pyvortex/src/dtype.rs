@@ -2,9 +2,11 @@ use arrow::datatypes::{DataType, Field};
 use arrow::pyarrow::FromPyArrow;
 use pyo3::types::PyType;
 use pyo3::{pyclass, pymethods, Py, PyAny, PyResult, Python};
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex_dtype::DType;
 
+use crate::error::PyVortexError;
+
 #[pyclass(name = "DType", module = "vortex", subclass)]
 pub struct PyDType {
     inner: DType,
@@ -34,7 +36,8 @@ impl PyDType {
     ) -> PyResult<Py<Self>> {
         PyDType::wrap(
             cls.py(),
-            DType::from_arrow(&Field::new("_", arrow_dtype, nullable)),
+            DType::try_from_arrow(&Field::new("_", arrow_dtype, nullable))
+                .map_err(PyVortexError::map_err)?,
         )
     }
 }
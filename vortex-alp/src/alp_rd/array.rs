@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::stats::ArrayStatisticsCompute;
+use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::{impl_encoding, ArrayDType, ArrayFlatten, OwnedArray, ToArrayData};
+use vortex_dtype::{Nullability, PType};
+use vortex_error::vortex_bail;
+
+use crate::alp_rd::compress::decompress;
+use crate::alp_rd::{check_right_bit_width, RDEncoder, MAX_DICTIONARY_SIZE};
+use crate::match_each_alp_float_ptype;
+
+impl_encoding!("vortex.alprd", ALPRD);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ALPRDMetadata {
+    right_bit_width: u8,
+    left_parts_dictionary: Vec<u16>,
+    left_parts_dtype: DType,
+    right_parts_dtype: DType,
+    patches_dtype: Option<DType>,
+}
+
+impl ALPRDArray<'_> {
+    /// Create an array of floats whose bits are the left part, looked up in the dictionary or
+    /// taken from the patches, followed by `right_bit_width` bits of the right part.
+    ///
+    /// The left parts are u16 dictionary codes that hold the validity of the array, the right
+    /// parts are unsigned integers of the width of the float, and the patches are a nullable u16
+    /// array of the left parts that are not in the dictionary.
+    pub fn try_new(
+        dtype: DType,
+        left_parts: Array,
+        left_parts_dictionary: Vec<u16>,
+        right_parts: Array,
+        right_bit_width: u8,
+        patches: Option<Array>,
+    ) -> VortexResult<Self> {
+        let right_ptype = match dtype {
+            DType::Primitive(PType::F32, _) => PType::U32,
+            DType::Primitive(PType::F64, _) => PType::U64,
+            _ => vortex_bail!(MismatchedTypes: "f32 or f64", dtype),
+        };
+        if left_parts.dtype() != &DType::Primitive(PType::U16, dtype.nullability()) {
+            vortex_bail!(
+                MismatchedTypes: DType::Primitive(PType::U16, dtype.nullability()),
+                left_parts.dtype()
+            );
+        }
+        if right_parts.dtype() != &DType::Primitive(right_ptype, Nullability::NonNullable) {
+            vortex_bail!(
+                MismatchedTypes: DType::Primitive(right_ptype, Nullability::NonNullable),
+                right_parts.dtype()
+            );
+        }
+        if left_parts_dictionary.len() > MAX_DICTIONARY_SIZE {
+            vortex_bail!(
+                "Left parts dictionary has {} entries, at most {} are supported",
+                left_parts_dictionary.len(),
+                MAX_DICTIONARY_SIZE
+            );
+        }
+        check_right_bit_width(right_bit_width, right_ptype.bit_width())?;
+        if left_parts.len() != right_parts.len() {
+            vortex_bail!("Left and right parts must have the same length");
+        }
+        if let Some(patches) = patches.as_ref() {
+            if patches.dtype() != &DType::Primitive(PType::U16, Nullability::Nullable) {
+                vortex_bail!(
+                    MismatchedTypes: DType::Primitive(PType::U16, Nullability::Nullable),
+                    patches.dtype()
+                );
+            }
+            if patches.len() != left_parts.len() {
+                vortex_bail!("Patches must have the same length as the array");
+            }
+        }
+
+        let mut children = Vec::with_capacity(3);
+        children.push(left_parts.to_array_data());
+        children.push(right_parts.to_array_data());
+        if let Some(patches) = patches.as_ref() {
+            children.push(patches.to_array_data());
+        }
+
+        Self::try_from_parts(
+            dtype,
+            ALPRDMetadata {
+                right_bit_width,
+                left_parts_dictionary,
+                left_parts_dtype: left_parts.dtype().clone(),
+                right_parts_dtype: right_parts.dtype().clone(),
+                patches_dtype: patches.map(|p| p.dtype().clone()),
+            },
+            children.into(),
+            StatsSet::new(),
+        )
+    }
+
+    pub fn encode(array: Array<'_>) -> VortexResult<OwnedArray> {
+        if let Ok(parray) = PrimitiveArray::try_from(array) {
+            match_each_alp_float_ptype!(parray.ptype(), |$T| {
+                RDEncoder::new(parray.typed_data::<$T>())
+                    .encode::<$T>(&parray)?
+                    .into_array()
+            })
+        } else {
+            vortex_bail!("ALP-RD can only encode primitive arrays");
+        }
+    }
+
+    #[inline]
+    pub fn left_parts(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().left_parts_dtype)
+            .expect("Missing left parts")
+    }
+
+    #[inline]
+    pub fn right_parts(&self) -> Array {
+        self.array()
+            .child(1, &self.metadata().right_parts_dtype)
+            .expect("Missing right parts")
+    }
+
+    pub fn patches(&self) -> Option<Array> {
+        self.metadata().patches_dtype.as_ref().map(|dt| {
+            self.array()
+                .child(2, dt)
+                .expect("Missing patches with present metadata flag")
+        })
+    }
+
+    #[inline]
+    pub fn left_parts_dictionary(&self) -> &[u16] {
+        &self.metadata().left_parts_dictionary
+    }
+
+    #[inline]
+    pub fn right_bit_width(&self) -> u8 {
+        self.metadata().right_bit_width
+    }
+}
+
+impl ArrayValidity for ALPRDArray<'_> {
+    fn is_valid(&self, index: usize) -> bool {
+        self.left_parts().with_dyn(|a| a.is_valid(index))
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.left_parts().with_dyn(|a| a.logical_validity())
+    }
+}
+
+impl ArrayFlatten for ALPRDArray<'_> {
+    fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
+    where
+        Self: 'a,
+    {
+        decompress(&self).map(Flattened::Primitive)
+    }
+}
+
+impl AcceptArrayVisitor for ALPRDArray<'_> {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("left_parts", &self.left_parts())?;
+        visitor.visit_child("right_parts", &self.right_parts())?;
+        if let Some(patches) = self.patches() {
+            visitor.visit_child("patches", &patches)?;
+        }
+        Ok(())
+    }
+}
+
+impl ArrayStatisticsCompute for ALPRDArray<'_> {}
+
+impl ArrayTrait for ALPRDArray<'_> {
+    fn len(&self) -> usize {
+        self.left_parts().len()
+    }
+}
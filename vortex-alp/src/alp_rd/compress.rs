@@ -0,0 +1,213 @@
+use itertools::Itertools;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::array::sparse::{Sparse, SparseArray};
+use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::alp_rd::{ALPRDArray, ALPRDEncoding, ALPRDFloat, RDEncoder};
+use crate::match_each_alp_float_ptype;
+
+impl EncodingCompression for ALPRDEncoding {
+    fn can_compress(
+        &self,
+        array: &Array,
+        _config: &CompressConfig,
+    ) -> Option<&dyn EncodingCompression> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // Only supports f32 and f64
+        if !matches!(parray.ptype(), PType::F32 | PType::F64) {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress(
+        &self,
+        array: &Array,
+        like: Option<&Array>,
+        ctx: Compressor,
+    ) -> VortexResult<Array<'static>> {
+        let like_rd = like.map(|like_array| ALPRDArray::try_from(like_array).unwrap());
+        let parray = array.as_primitive();
+
+        let encoder = match like_rd.as_ref() {
+            Some(l) => {
+                RDEncoder::from_parts(l.right_bit_width(), l.left_parts_dictionary().to_vec())
+            }
+            None => match_each_alp_float_ptype!(parray.ptype(), |$T| {
+                RDEncoder::new(parray.typed_data::<$T>())
+            })?,
+        };
+        let (left_parts, right_parts, patches) = match_each_alp_float_ptype!(parray.ptype(), |$T| {
+            encoder.split::<$T>(&parray)
+        })??;
+
+        let compressed_left_parts = ctx.named("left_parts").excluding(&ALPRDEncoding).compress(
+            &left_parts,
+            like_rd.as_ref().map(|l| l.left_parts()).as_ref(),
+        )?;
+        let compressed_right_parts = ctx
+            .named("right_parts")
+            .excluding(&ALPRDEncoding)
+            .compress(
+                &right_parts,
+                like_rd.as_ref().map(|l| l.right_parts()).as_ref(),
+            )?;
+        let compressed_patches = patches
+            .map(|p| {
+                ctx.auxiliary("patches")
+                    .excluding(&ALPRDEncoding)
+                    .compress(&p, like_rd.as_ref().and_then(|l| l.patches()).as_ref())
+            })
+            .transpose()?;
+
+        ALPRDArray::try_new(
+            parray.dtype().clone(),
+            compressed_left_parts,
+            encoder.dictionary().to_vec(),
+            compressed_right_parts,
+            encoder.right_bit_width(),
+            compressed_patches,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+pub fn decompress(array: &ALPRDArray) -> VortexResult<PrimitiveArray<'static>> {
+    match_each_alp_float_ptype!(array.dtype().try_into()?, |$T| {
+        decompress_typed::<$T>(array)
+    })?
+}
+
+fn decompress_typed<T: ALPRDFloat>(array: &ALPRDArray) -> VortexResult<PrimitiveArray<'static>> {
+    let left_codes = array.left_parts().flatten_primitive()?;
+    let dictionary = array.left_parts_dictionary();
+    let codes = left_codes.typed_data::<u16>();
+    let mut left_parts = codes
+        .iter()
+        .map(|&code| dictionary.get(code as usize).copied())
+        .collect::<Vec<_>>();
+
+    if let Some(patches) = array.patches() {
+        if patches.encoding().id() != Sparse::ID {
+            vortex_bail!("can't patch ALP-RD array with {}", patches);
+        }
+        let patches = SparseArray::try_from(patches)?;
+        let values = patches.values().flatten_primitive()?;
+        for (index, &left) in patches
+            .resolved_indices()
+            .into_iter()
+            .zip(values.typed_data::<u16>())
+        {
+            left_parts[index] = Some(left);
+        }
+    }
+    // Null and patched values may have codes outside of the dictionary.
+    let left_parts: Vec<u16> = left_parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, left)| match left {
+            Some(left) => Ok(left),
+            None if !left_codes.is_valid(i) => Ok(0),
+            None => Err(vortex_err!("Left part code {} out of bounds", codes[i])),
+        })
+        .try_collect()?;
+
+    let right_bit_width = array.right_bit_width() as usize;
+    let right_parts = array.right_parts().flatten_primitive()?;
+    let decoded = left_parts
+        .into_iter()
+        .zip(right_parts.typed_data::<T::UINT>())
+        .map(|(left, &right)| T::from_bits((T::UINT::from(left) << right_bit_width) | right))
+        .collect::<Vec<_>>();
+
+    Ok(PrimitiveArray::from_vec(
+        decoded,
+        left_codes.validity().to_static(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::compress::{Compressor, EncodingCompression};
+    use vortex::{Context, IntoArray};
+
+    use super::*;
+
+    fn readings() -> Vec<f64> {
+        (0..2048)
+            .map(|i| 20.0 + (i as f64 * 0.37).sin() * 3.5)
+            .collect()
+    }
+
+    #[test]
+    fn round_trip_full_precision() {
+        let values = readings();
+        let array = PrimitiveArray::from(values.clone());
+        let encoded =
+            ALPRDArray::try_from(ALPRDArray::encode(array.into_array()).unwrap()).unwrap();
+        assert!(encoded.left_parts_dictionary().len() <= 8);
+        assert!(encoded.right_bit_width() < 64);
+        assert!(encoded.patches().is_none());
+
+        let decoded = decompress(&encoded).unwrap();
+        assert_eq!(decoded.typed_data::<f64>(), values.as_slice());
+    }
+
+    #[test]
+    fn round_trip_patched_and_nullable() {
+        let mut values = readings().into_iter().map(Some).collect::<Vec<_>>();
+        values[3] = None;
+        values[100] = Some(-1.0e300);
+        values[101] = Some(f64::NAN);
+        values[2000] = None;
+        let array = PrimitiveArray::from_nullable_vec(values.clone());
+        let encoded =
+            ALPRDArray::try_from(ALPRDArray::encode(array.into_array()).unwrap()).unwrap();
+        assert!(encoded.patches().is_some());
+
+        let decoded = decompress(&encoded).unwrap();
+        for (i, v) in values.iter().enumerate() {
+            assert_eq!(decoded.validity().is_valid(i), v.is_some());
+            if let Some(v) = v {
+                assert_eq!(decoded.typed_data::<f64>()[i].to_bits(), v.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn compress_f32_like() {
+        let values = (0..1024)
+            .map(|i| (i as f32 * 0.013).exp())
+            .collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        let ctx = Context::default();
+        let compressed = ALPRDEncoding
+            .compress(&array, None, Compressor::new(&ctx))
+            .unwrap();
+        let rd = ALPRDArray::try_from(&compressed).unwrap();
+        assert_eq!(
+            decompress(&rd).unwrap().typed_data::<f32>(),
+            values.as_slice()
+        );
+
+        // Compressing like another array keeps its cut and dictionary.
+        let other = PrimitiveArray::from(values.iter().rev().copied().collect::<Vec<_>>());
+        let recompressed = ALPRDEncoding
+            .compress(
+                &other.into_array(),
+                Some(&compressed),
+                Compressor::new(&ctx),
+            )
+            .unwrap();
+        let other_rd = ALPRDArray::try_from(&recompressed).unwrap();
+        assert_eq!(other_rd.right_bit_width(), rd.right_bit_width());
+        assert_eq!(other_rd.left_parts_dictionary(), rd.left_parts_dictionary());
+    }
+}
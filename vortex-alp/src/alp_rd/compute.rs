@@ -0,0 +1,157 @@
+use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
+use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::take::{take, TakeFn};
+use vortex::compute::ArrayCompute;
+use vortex::{Array, ArrayDType, IntoArray, OwnedArray};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::alp_rd::{ALPRDArray, ALPRDFloat};
+use crate::match_each_alp_float_ptype;
+
+impl ArrayCompute for ALPRDArray<'_> {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ALPRDArray<'_> {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        let code = scalar_at(&self.left_parts(), index)?;
+        if code.is_null() {
+            return Ok(Scalar::null(self.dtype()));
+        }
+
+        let left: u16 = match self
+            .patches()
+            .map(|p| scalar_at(&p, index))
+            .transpose()?
+            .filter(|p| !p.is_null())
+        {
+            Some(patch) => patch.try_into()?,
+            None => {
+                let code: u16 = code.try_into()?;
+                *self
+                    .left_parts_dictionary()
+                    .get(code as usize)
+                    .ok_or_else(|| vortex_err!("Left part code {} out of bounds", code))?
+            }
+        };
+        let right = scalar_at(&self.right_parts(), index)?;
+        let right_bit_width = self.right_bit_width() as usize;
+
+        match_each_alp_float_ptype!(self.dtype().try_into()?, |$T| {
+            let right: <$T as ALPRDFloat>::UINT = right.try_into()?;
+            let bits = (<$T as ALPRDFloat>::UINT::from(left) << right_bit_width) | right;
+            PrimitiveScalar::try_new(
+                Some(<$T as ALPRDFloat>::from_bits(bits)),
+                self.dtype().nullability(),
+            )?
+            .into()
+        })
+    }
+}
+
+impl SliceFn for ALPRDArray<'_> {
+    fn slice(&self, start: usize, end: usize) -> VortexResult<OwnedArray> {
+        Ok(ALPRDArray::try_new(
+            self.dtype().clone(),
+            slice(&self.left_parts(), start, end)?,
+            self.left_parts_dictionary().to_vec(),
+            slice(&self.right_parts(), start, end)?,
+            self.right_bit_width(),
+            self.patches().map(|p| slice(&p, start, end)).transpose()?,
+        )?
+        .into_array())
+    }
+}
+
+impl TakeFn for ALPRDArray<'_> {
+    fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
+        Ok(ALPRDArray::try_new(
+            self.dtype().clone(),
+            take(&self.left_parts(), indices)?,
+            self.left_parts_dictionary().to_vec(),
+            take(&self.right_parts(), indices)?,
+            self.right_bit_width(),
+            self.patches().map(|p| take(&p, indices)).transpose()?,
+        )?
+        .into_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::scalar_at::{scalar_at, scalar_values};
+    use vortex::compute::slice::slice;
+    use vortex::compute::take::take;
+    use vortex::IntoArray;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::ALPRDArray;
+
+    fn values() -> Vec<Option<f64>> {
+        (0..100)
+            .map(|i| (i % 9 != 0).then(|| 1.0 / (i as f64 + 0.7)))
+            .chain([Some(f64::MAX), Some(-0.0)])
+            .collect()
+    }
+
+    #[test]
+    fn scalar_at_slice_take() {
+        let values = values();
+        let array =
+            ALPRDArray::encode(PrimitiveArray::from_nullable_vec(values.clone()).into_array())
+                .unwrap();
+        assert!(ALPRDArray::try_from(&array).unwrap().patches().is_some());
+        assert_eq!(scalar_values::<f64>(&array).unwrap(), values);
+
+        let sliced = slice(&array, 95, 102).unwrap();
+        assert_eq!(sliced.encoding().id(), array.encoding().id());
+        assert_eq!(scalar_values::<f64>(&sliced).unwrap(), values[95..102]);
+        assert_eq!(
+            scalar_values::<f64>(&sliced.flatten_primitive().unwrap().into_array()).unwrap(),
+            values[95..102]
+        );
+
+        let indices = PrimitiveArray::from(vec![101u32, 0, 9, 100, 5]).into_array();
+        let taken = take(&array, &indices).unwrap();
+        assert_eq!(taken.encoding().id(), array.encoding().id());
+        let expected = [values[101], values[0], values[9], values[100], values[5]];
+        assert_eq!(scalar_values::<f64>(&taken).unwrap(), expected);
+        assert_eq!(
+            scalar_values::<f64>(&taken.flatten_primitive().unwrap().into_array()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn invalid_left_parts() {
+        let rd = |left_codes: Vec<u16>, right_bit_width: u8| {
+            ALPRDArray::try_new(
+                DType::Primitive(PType::F64, Nullability::NonNullable),
+                PrimitiveArray::from(left_codes).into_array(),
+                vec![0x3FF0],
+                PrimitiveArray::from(vec![0u64, 0]).into_array(),
+                right_bit_width,
+                None,
+            )
+        };
+        assert!(rd(vec![0, 0], 47).is_err());
+        assert!(rd(vec![0, 0], 64).is_err());
+
+        let array = rd(vec![0, 5], 48).unwrap().into_array();
+        assert_eq!(f64::try_from(scalar_at(&array, 0).unwrap()).unwrap(), 1.0);
+        assert!(scalar_at(&array, 1).is_err());
+        assert!(array.flatten_primitive().is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+pub use array::*;
+use itertools::Itertools;
+use num_traits::{Float, One, PrimInt, ToPrimitive};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::array::sparse::SparseArray;
+use vortex::validity::Validity;
+use vortex::{ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+mod array;
+mod compress;
+mod compute;
+
+/// The maximum number of distinct left parts kept in the dictionary, such that codes fit in 3 bits.
+pub const MAX_DICTIONARY_SIZE: usize = 8;
+/// The maximum width of the left parts, such that they and their patches fit in a u16.
+const MAX_LEFT_BIT_WIDTH: usize = 16;
+/// The number of values sampled to choose the cut and the dictionary.
+const SAMPLE_SIZE: usize = 1024;
+/// The estimated cost in bits of a left part that isn't in the dictionary: a u16 value and a
+/// u64 position.
+const EXCEPTION_BITS: usize = 80;
+
+/// A float that can be split into a left and right part of its bit representation.
+pub trait ALPRDFloat: NativePType + Float {
+    type UINT: NativePType + PrimInt + From<u16>;
+
+    const BITS: usize;
+
+    fn to_bits(self) -> Self::UINT;
+
+    fn from_bits(bits: Self::UINT) -> Self;
+}
+
+impl ALPRDFloat for f32 {
+    type UINT = u32;
+
+    const BITS: usize = 32;
+
+    fn to_bits(self) -> Self::UINT {
+        f32::to_bits(self)
+    }
+
+    fn from_bits(bits: Self::UINT) -> Self {
+        f32::from_bits(bits)
+    }
+}
+
+impl ALPRDFloat for f64 {
+    type UINT = u64;
+
+    const BITS: usize = 64;
+
+    fn to_bits(self) -> Self::UINT {
+        f64::to_bits(self)
+    }
+
+    fn from_bits(bits: Self::UINT) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+/// Splits floats into a left part of their high bits, looked up in a small dictionary, and a right
+/// part of their remaining low bits, which is stored as is.
+///
+/// Floats that don't have a decimal representation still tend to share their sign, exponent and
+/// first bits of mantissa, so the left parts are few and compress far better than the whole value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RDEncoder {
+    right_bit_width: u8,
+    dictionary: Vec<u16>,
+}
+
+impl RDEncoder {
+    /// Choose the cut and the dictionary that minimise the estimated size of a sample of the values.
+    pub fn new<T: ALPRDFloat>(values: &[T]) -> Self {
+        let sample = if values.len() > SAMPLE_SIZE {
+            values
+                .iter()
+                .step_by(values.len() / SAMPLE_SIZE)
+                .map(|v| v.to_bits())
+                .collect_vec()
+        } else {
+            values.iter().map(|v| v.to_bits()).collect_vec()
+        };
+
+        let mut best = Self {
+            right_bit_width: (T::BITS - 1) as u8,
+            dictionary: Vec::new(),
+        };
+        let mut best_size = usize::MAX;
+        for left_bit_width in 1..=MAX_LEFT_BIT_WIDTH {
+            let right_bit_width = T::BITS - left_bit_width;
+            let (dictionary, exceptions) = build_dictionary(&sample, right_bit_width);
+            let code_bit_width =
+                (usize::BITS - dictionary.len().saturating_sub(1).leading_zeros()) as usize;
+            let size =
+                sample.len() * (right_bit_width + code_bit_width) + exceptions * EXCEPTION_BITS;
+            if size < best_size {
+                best_size = size;
+                best = Self {
+                    right_bit_width: right_bit_width as u8,
+                    dictionary,
+                };
+            }
+        }
+        best
+    }
+
+    /// Create an encoder from the cut and dictionary of a previously encoded array.
+    pub fn from_parts(right_bit_width: u8, dictionary: Vec<u16>) -> Self {
+        Self {
+            right_bit_width,
+            dictionary,
+        }
+    }
+
+    pub fn right_bit_width(&self) -> u8 {
+        self.right_bit_width
+    }
+
+    pub fn dictionary(&self) -> &[u16] {
+        &self.dictionary
+    }
+
+    /// Split the values of the array, whose left parts are patched in where they aren't in the
+    /// dictionary.
+    pub fn encode<T: ALPRDFloat>(&self, array: &PrimitiveArray) -> VortexResult<OwnedALPRDArray> {
+        let (left_parts, right_parts, patches) = self.split::<T>(array)?;
+        ALPRDArray::try_new(
+            array.dtype().clone(),
+            left_parts,
+            self.dictionary.clone(),
+            right_parts,
+            self.right_bit_width,
+            patches,
+        )
+    }
+
+    pub(crate) fn split<T: ALPRDFloat>(
+        &self,
+        array: &PrimitiveArray,
+    ) -> VortexResult<(OwnedArray, OwnedArray, Option<OwnedArray>)> {
+        check_right_bit_width(self.right_bit_width, T::BITS)?;
+        let right_mask = (T::UINT::one() << self.right_bit_width as usize) - T::UINT::one();
+        let validity = array.validity();
+
+        let mut left_parts = Vec::with_capacity(array.len());
+        let mut right_parts = Vec::with_capacity(array.len());
+        let mut exception_positions: Vec<u64> = Vec::new();
+        let mut exceptions: Vec<u16> = Vec::new();
+        for (i, v) in array.typed_data::<T>().iter().enumerate() {
+            let bits = v.to_bits();
+            right_parts.push(bits & right_mask);
+            let left = (bits >> self.right_bit_width as usize)
+                .to_u16()
+                .expect("left part must fit in u16");
+            match self.dictionary.iter().position(|&d| d == left) {
+                Some(code) => left_parts.push(code as u16),
+                None => {
+                    left_parts.push(0);
+                    if validity.is_valid(i) {
+                        exception_positions.push(i as u64);
+                        exceptions.push(left);
+                    }
+                }
+            }
+        }
+
+        let patches = (!exceptions.is_empty()).then(|| {
+            SparseArray::new(
+                PrimitiveArray::from(exception_positions).into_array(),
+                PrimitiveArray::from_vec(exceptions, Validity::AllValid).into_array(),
+                array.len(),
+                Scalar::null(&DType::Primitive(PType::U16, Nullability::Nullable)),
+            )
+            .into_array()
+        });
+
+        Ok((
+            PrimitiveArray::from_vec(left_parts, validity).into_array(),
+            PrimitiveArray::from(right_parts).into_array(),
+            patches,
+        ))
+    }
+}
+
+/// Check that cutting off `right_bit_width` of `bits` bits leaves a non-empty left part that fits
+/// in a u16.
+fn check_right_bit_width(right_bit_width: u8, bits: usize) -> VortexResult<()> {
+    let right_bit_width = right_bit_width as usize;
+    if right_bit_width >= bits || right_bit_width < bits - MAX_LEFT_BIT_WIDTH {
+        vortex_bail!(
+            "Invalid right bit width {} for {} bit floats",
+            right_bit_width,
+            bits
+        );
+    }
+    Ok(())
+}
+
+/// The most frequent left parts when cutting off `right_bit_width` bits, and the number of values
+/// whose left part doesn't make it into the dictionary.
+fn build_dictionary<U: PrimInt>(bits: &[U], right_bit_width: usize) -> (Vec<u16>, usize) {
+    let mut counts: HashMap<u16, usize> = HashMap::new();
+    for b in bits {
+        let left = (*b >> right_bit_width)
+            .to_u16()
+            .expect("left part must fit in u16");
+        *counts.entry(left).or_default() += 1;
+    }
+
+    let dictionary = counts
+        .iter()
+        .sorted_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)))
+        .map(|(&left, _)| left)
+        .take(MAX_DICTIONARY_SIZE)
+        .collect_vec();
+    let exceptions = bits.len() - dictionary.iter().map(|left| counts[left]).sum::<usize>();
+    (dictionary, exceptions)
+}
@@ -1,7 +1,9 @@
 pub use alp::*;
+pub use alp_rd::*;
 pub use array::*;
 
 mod alp;
+mod alp_rd;
 mod array;
 mod compress;
 mod compute;
@@ -0,0 +1,88 @@
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::boolean::{BooleanFn, BooleanOperator, NotFn};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+
+impl BooleanFn for BoolArray<'_> {
+    fn boolean(&self, other: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray> {
+        let other = other.clone().flatten_bool()?;
+        let lhs = self.boolean_buffer();
+        let rhs = other.boolean_buffer();
+
+        let (values, nulls) = match (
+            self.logical_validity().to_null_buffer()?,
+            other.logical_validity().to_null_buffer()?,
+        ) {
+            (None, None) => (
+                match operator {
+                    BooleanOperator::And => &lhs & &rhs,
+                    BooleanOperator::Or => &lhs | &rhs,
+                    BooleanOperator::AndNot => &lhs & &!&rhs,
+                    BooleanOperator::Xor => &lhs ^ &rhs,
+                },
+                None,
+            ),
+            (lhs_nulls, rhs_nulls) => {
+                let lhs_valid = valid_buffer(lhs_nulls, self.len());
+                let rhs_valid = valid_buffer(rhs_nulls, self.len());
+                let (values, valid) = match operator {
+                    BooleanOperator::And => kleene_and(&lhs, &lhs_valid, &rhs, &rhs_valid),
+                    BooleanOperator::Or => kleene_or(&lhs, &lhs_valid, &rhs, &rhs_valid),
+                    BooleanOperator::AndNot => kleene_and(&lhs, &lhs_valid, &!&rhs, &rhs_valid),
+                    BooleanOperator::Xor => (&lhs ^ &rhs, &lhs_valid & &rhs_valid),
+                };
+                (values, Some(NullBuffer::new(valid)))
+            }
+        };
+
+        let validity = match nulls {
+            _ if !self.dtype().is_nullable() && !other.dtype().is_nullable() => {
+                Validity::NonNullable
+            }
+            None => Validity::AllValid,
+            Some(n) => Validity::from(n),
+        };
+        BoolArray::try_new(values, validity).map(|a| a.into_array())
+    }
+}
+
+impl NotFn for BoolArray<'_> {
+    fn not(&self) -> VortexResult<OwnedArray> {
+        BoolArray::try_new(!&self.boolean_buffer(), self.validity().to_static())
+            .map(|a| a.into_array())
+    }
+}
+
+fn valid_buffer(nulls: Option<NullBuffer>, len: usize) -> BooleanBuffer {
+    nulls.map_or_else(|| BooleanBuffer::new_set(len), |n| n.into_inner())
+}
+
+/// The result is true if both sides are known to be true, and false if either side is known to
+/// be false.
+fn kleene_and(
+    lhs: &BooleanBuffer,
+    lhs_valid: &BooleanBuffer,
+    rhs: &BooleanBuffer,
+    rhs_valid: &BooleanBuffer,
+) -> (BooleanBuffer, BooleanBuffer) {
+    let known_true = &(lhs & lhs_valid) & &(rhs & rhs_valid);
+    let known_false = &(&!lhs & lhs_valid) | &(&!rhs & rhs_valid);
+    let valid = &known_true | &known_false;
+    (known_true, valid)
+}
+
+/// The result is true if either side is known to be true, and false if both sides are known to
+/// be false.
+fn kleene_or(
+    lhs: &BooleanBuffer,
+    lhs_valid: &BooleanBuffer,
+    rhs: &BooleanBuffer,
+    rhs_valid: &BooleanBuffer,
+) -> (BooleanBuffer, BooleanBuffer) {
+    let known_true = &(lhs & lhs_valid) | &(rhs & rhs_valid);
+    let valid = &known_true | &(lhs_valid & rhs_valid);
+    (known_true, valid)
+}
@@ -0,0 +1,30 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::cast::{cast, CastFn};
+use crate::{ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+
+impl CastFn for BoolArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        let validity = self
+            .validity()
+            .cast_nullability(dtype.nullability(), self.len())?;
+        match dtype {
+            DType::Bool(_) => {
+                BoolArray::try_new(self.boolean_buffer(), validity).map(|a| a.into_array())
+            }
+            // Booleans are cast to numbers as 0 and 1.
+            DType::Primitive(..) => {
+                let values = self
+                    .boolean_buffer()
+                    .iter()
+                    .map(|b| b as u8)
+                    .collect::<Vec<_>>();
+                cast(PrimitiveArray::from_vec(values, validity).array(), dtype)
+            }
+            _ => vortex_bail!(MismatchedTypes: self.dtype(), dtype),
+        }
+    }
+}
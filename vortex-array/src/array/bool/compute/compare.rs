@@ -0,0 +1,17 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::bool::BoolArray;
+use crate::compute::compare::{CompareFn, Operator};
+use crate::{IntoArray, OwnedArray};
+
+impl CompareFn for BoolArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        let value = bool::try_from(other)?;
+        let predicate = operator.to_predicate::<bool>();
+        let buffer =
+            BooleanBuffer::from_iter(self.boolean_buffer().iter().map(|b| predicate(&b, &value)));
+        BoolArray::try_new(buffer, self.validity()).map(|a| a.into_array())
+    }
+}
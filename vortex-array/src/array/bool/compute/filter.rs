@@ -0,0 +1,39 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::filter::FilterFn;
+use crate::{IntoArray, OwnedArray};
+
+impl FilterFn for BoolArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        let buffer = self.boolean_buffer();
+        let filtered = BooleanBuffer::from_iter(
+            mask.boolean_buffer()
+                .set_indices()
+                .map(|idx| buffer.value(idx)),
+        );
+        BoolArray::try_new(filtered, self.validity().filter(mask)?).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::compute::filter::filter;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{ArrayTrait, IntoArray};
+
+    #[test]
+    fn filter_nullable() {
+        let array = BoolArray::from_iter([Some(true), None, Some(false), Some(true)]);
+        let mask = BoolArray::from(vec![false, true, true, true]);
+        let filtered = BoolArray::try_from(filter(&array.into_array(), &mask).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert!(scalar_at(filtered.array(), 0).unwrap().is_null());
+        assert_eq!(
+            filtered.boolean_buffer().iter().skip(1).collect::<Vec<_>>(),
+            [false, true]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::hash::{hash_bytes, hash_valid, HashFn};
+use crate::validity::ArrayValidity;
+use crate::{ArrayTrait, OwnedArray};
+
+impl HashFn for BoolArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        let values = self.boolean_buffer();
+        let (false_hash, true_hash) = (hash_bytes(&[0]), hash_bytes(&[1]));
+        Ok(hash_valid(
+            self.len(),
+            |i| validity.as_ref().map_or(true, |v| v.is_valid(i)),
+            |i| {
+                if values.value(i) {
+                    true_hash
+                } else {
+                    false_hash
+                }
+            },
+        ))
+    }
+}
@@ -1,21 +1,41 @@
 use crate::array::bool::BoolArray;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::boolean::{BooleanFn, NotFn};
+use crate::compute::cast::CastFn;
+use crate::compute::compare::CompareFn;
 use crate::compute::fill::FillForwardFn;
+use crate::compute::filter::FilterFn;
+use crate::compute::hash::HashFn;
+use crate::compute::null::FillNullFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::ArgSortFn;
 use crate::compute::take::TakeFn;
+use crate::compute::unique::ValueCountsFn;
 use crate::compute::ArrayCompute;
 
 mod as_arrow;
 mod as_contiguous;
+mod boolean;
+mod cast;
+mod compare;
 mod fill;
+mod filter;
 mod flatten;
+mod hash;
+mod null;
 mod scalar_at;
 mod slice;
+mod sort;
 mod take;
+mod unique;
 
 impl ArrayCompute for BoolArray<'_> {
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -24,10 +44,38 @@ impl ArrayCompute for BoolArray<'_> {
         Some(self)
     }
 
+    fn boolean(&self) -> Option<&dyn BooleanFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         Some(self)
     }
 
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -39,4 +87,8 @@ impl ArrayCompute for BoolArray<'_> {
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
 }
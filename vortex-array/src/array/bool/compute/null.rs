@@ -0,0 +1,19 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::bool::BoolArray;
+use crate::compute::null::FillNullFn;
+use crate::validity::ArrayValidity;
+use crate::{IntoArray, OwnedArray};
+
+impl FillNullFn for BoolArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        let valid = self.logical_validity().to_present_null_buffer()?;
+        let buffer = if bool::try_from(fill_value)? {
+            &self.boolean_buffer() | &!valid.inner()
+        } else {
+            &self.boolean_buffer() & valid.inner()
+        };
+        Ok(BoolArray::from(buffer).into_array())
+    }
+}
@@ -0,0 +1,19 @@
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::sort::{argsort_by, ArgSortFn, SortOptions};
+use crate::validity::ArrayValidity;
+use crate::{ArrayTrait, OwnedArray};
+
+impl ArgSortFn for BoolArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        let values = self.boolean_buffer();
+        Ok(argsort_by(
+            self.len(),
+            options,
+            |i| validity.as_ref().map_or(true, |v| v.is_valid(i)),
+            |a, b| values.value(a).cmp(&values.value(b)),
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::filter::filter;
+use crate::compute::unique::ValueCountsFn;
+use crate::validity::ArrayValidity;
+use crate::{ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+
+impl ValueCountsFn for BoolArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let (valid, trues) = match self.logical_validity().to_null_buffer()? {
+            None => (self.len(), self.boolean_buffer().count_set_bits()),
+            Some(nulls) => (
+                nulls.len() - nulls.null_count(),
+                (&self.boolean_buffer() & nulls.inner()).count_set_bits(),
+            ),
+        };
+        let falses = valid - trues;
+
+        let (values, counts) = if self.dtype().is_nullable() {
+            (
+                BoolArray::from_iter([None, Some(false), Some(true)]),
+                vec![(self.len() - valid) as u64, falses as u64, trues as u64],
+            )
+        } else {
+            (
+                BoolArray::from(vec![false, true]),
+                vec![falses as u64, trues as u64],
+            )
+        };
+        let used = BoolArray::from(counts.iter().map(|&c| c > 0).collect::<Vec<_>>());
+        Ok((
+            filter(&values.into_array(), &used)?,
+            PrimitiveArray::from(counts.into_iter().filter(|&c| c > 0).collect::<Vec<_>>())
+                .into_array(),
+        ))
+    }
+}
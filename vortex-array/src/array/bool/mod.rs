@@ -44,7 +44,8 @@ impl BoolArray<'_> {
                     validity: validity.to_metadata(buffer.len())?,
                     length: buffer.len(),
                 },
-                Some(Buffer::from(buffer.into_inner())),
+                // The buffer may be sliced at a bit offset, which must not be lost.
+                Some(Buffer::from(buffer.sliced())),
                 validity.into_array_data().into_iter().collect_vec().into(),
                 StatsSet::new(),
             )?,
@@ -124,6 +125,7 @@ impl EncodingCompression for BoolEncoding {}
 mod tests {
     use crate::array::bool::BoolArray;
     use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
     use crate::IntoArray;
 
     #[test]
@@ -132,4 +134,14 @@ mod tests {
         let scalar: bool = scalar_at(&arr, 0).unwrap().try_into().unwrap();
         assert!(scalar);
     }
+
+    #[test]
+    fn slice_bit_offset() {
+        let arr = BoolArray::from(vec![true, true, true, false]).into_array();
+        let sliced = slice(&arr, 2, 4).unwrap().flatten_bool().unwrap();
+        assert_eq!(
+            sliced.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false]
+        );
+    }
 }
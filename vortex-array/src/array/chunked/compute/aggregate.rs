@@ -0,0 +1,61 @@
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::aggregate::{
+    count_distinct, merge_min_max, merge_sums, min_max, sum, CountDistinctFn, MinMaxFn, SumFn,
+};
+use crate::ArrayDType;
+
+impl SumFn for ChunkedArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let sums = self
+            .chunks()
+            .map(|c| sum(&c))
+            .collect::<VortexResult<Vec<_>>>()?;
+        merge_sums(PType::try_from(self.dtype())?, sums)
+    }
+}
+
+impl MinMaxFn for ChunkedArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        let parts = self
+            .chunks()
+            .map(|c| min_max(&c))
+            .collect::<VortexResult<Vec<_>>>()?;
+        merge_min_max(PType::try_from(self.dtype())?, parts)
+    }
+}
+
+impl CountDistinctFn for ChunkedArray<'_> {
+    fn count_distinct(&self) -> VortexResult<usize> {
+        // Values may be repeated across chunks, so this is only an upper bound.
+        self.chunks().map(|c| count_distinct(&c)).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::aggregate::{count, min_max, sum};
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn aggregate_chunks() {
+        let chunks = vec![
+            PrimitiveArray::from_nullable_vec(vec![Some(5u16), None]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![None::<u16>]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(2u16), Some(9)]).into_array(),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        assert_eq!(sum(&array).unwrap(), Some(16u64).into());
+        assert_eq!(count(&array).unwrap(), 3);
+        assert_eq!(
+            min_max(&array).unwrap(),
+            (Some(2u16).into(), Some(9u16).into())
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::arithmetic::{
+    arithmetic, arithmetic_scalar, ArithmeticFn, ArithmeticScalarFn, BinaryOperator, OverflowMode,
+};
+use crate::compute::slice::slice;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
+
+impl ArithmeticScalarFn for ChunkedArray<'_> {
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| arithmetic_scalar(&chunk, other, operator, mode))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
+
+impl ArithmeticFn for ChunkedArray<'_> {
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let other_chunk = slice(other, offset, offset + chunk.len())?;
+            offset += chunk.len();
+            chunks.push(arithmetic(&chunk, &other_chunk, operator, mode)?);
+        }
+        let dtype = self
+            .dtype()
+            .with_nullability((self.dtype().is_nullable() || other.dtype().is_nullable()).into());
+        ChunkedArray::try_new(chunks, dtype).map(|a| a.into_array())
+    }
+}
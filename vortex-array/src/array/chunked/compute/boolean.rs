@@ -0,0 +1,31 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::boolean::{boolean, not, BooleanFn, BooleanOperator, NotFn};
+use crate::compute::slice::slice;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
+
+impl BooleanFn for ChunkedArray<'_> {
+    fn boolean(&self, other: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray> {
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let other_chunk = slice(other, offset, offset + chunk.len())?;
+            offset += chunk.len();
+            chunks.push(boolean(&chunk, &other_chunk, operator)?);
+        }
+        let dtype = DType::Bool((self.dtype().is_nullable() || other.dtype().is_nullable()).into());
+        ChunkedArray::try_new(chunks, dtype).map(|a| a.into_array())
+    }
+}
+
+impl NotFn for ChunkedArray<'_> {
+    fn not(&self) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| not(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,50 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::compare::{compare, CompareFn, Operator};
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl CompareFn for ChunkedArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| compare(&chunk, other, operator))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, DType::Bool(self.dtype().nullability()))
+            .map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::compare::{compare, Operator};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn compare_chunks() {
+        let chunks = vec![
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(4i32), Some(2)]).into_array(),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        let result = compare(&array, &2i32.into(), Operator::Gt).unwrap();
+        assert!(ChunkedArray::try_from(&result).is_ok());
+        assert_eq!(result.dtype(), &DType::Bool(Nullability::Nullable));
+        let values = (0..result.len())
+            .map(|i| bool::try_from(scalar_at(&result, i).unwrap()).ok())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [Some(false), None, Some(true), Some(true), Some(false)]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::array::chunked::ChunkedArray;
+use crate::compute::filter::{filter, FilterFn};
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl FilterFn for ChunkedArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        let mask = mask.boolean_buffer();
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let chunk_mask = mask.slice(offset, chunk.len());
+            offset += chunk.len();
+            // Skip any chunks that are filtered out entirely.
+            if chunk_mask.count_set_bits() == 0 {
+                continue;
+            }
+            chunks.push(filter(&chunk, &BoolArray::from(chunk_mask))?);
+        }
+        ChunkedArray::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn filter_chunks() {
+        let chunks = vec![
+            PrimitiveArray::from(vec![1u8, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![4u8, 5]).into_array(),
+            PrimitiveArray::from(vec![6u8]).into_array(),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        let mask = BoolArray::from(vec![false, true, true, false, false, true]);
+
+        let filtered = ChunkedArray::try_from(filter(&array, &mask).unwrap()).unwrap();
+        assert_eq!(filtered.nchunks(), 2);
+        let values = filtered
+            .chunks()
+            .flat_map(|c| c.flatten_primitive().unwrap().typed_data::<u8>().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, [2, 3, 6]);
+    }
+}
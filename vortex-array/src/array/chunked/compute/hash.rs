@@ -0,0 +1,21 @@
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::as_contiguous::as_contiguous;
+use crate::compute::hash::{hash, HashFn};
+use crate::{IntoArray, OwnedArray};
+
+impl HashFn for ChunkedArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        // The hashes are concatenated, since they are usually consumed as a whole.
+        let chunks = self
+            .chunks()
+            .map(|chunk| Ok(hash(&chunk)?.flatten_primitive()?.into_array()))
+            .collect::<VortexResult<Vec<_>>>()?;
+        if chunks.is_empty() {
+            return Ok(PrimitiveArray::from(Vec::<u64>::new()).into_array());
+        }
+        as_contiguous(&chunks)
+    }
+}
@@ -1,23 +1,90 @@
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::chunked::ChunkedArray;
+use crate::compute::aggregate::{CountDistinctFn, MinMaxFn, SumFn};
+use crate::compute::arithmetic::{ArithmeticFn, ArithmeticScalarFn};
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::boolean::{BooleanFn, NotFn};
+use crate::compute::cast::{cast, CastFn};
+use crate::compute::compare::CompareFn;
+use crate::compute::filter::FilterFn;
+use crate::compute::hash::HashFn;
+use crate::compute::null::{FillNullFn, IsNullFn};
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
-use crate::compute::scalar_subtract::SubtractScalarFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::string::{StringLengthFn, StringMatchFn, StringTransformFn};
 use crate::compute::take::TakeFn;
+use crate::compute::unique::ValueCountsFn;
 use crate::compute::ArrayCompute;
-use crate::{Array, OwnedArray, ToStatic};
+use crate::{Array, IntoArray, OwnedArray, ToStatic};
 
+mod aggregate;
+mod arithmetic;
+mod boolean;
+mod compare;
+mod filter;
+mod hash;
+mod null;
 mod slice;
+mod string;
 mod take;
+mod unique;
 
 impl ArrayCompute for ChunkedArray<'_> {
+    fn arithmetic(&self) -> Option<&dyn ArithmeticFn> {
+        Some(self)
+    }
+
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        Some(self)
+    }
+
     fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
         Some(self)
     }
 
+    fn boolean(&self) -> Option<&dyn BooleanFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -26,15 +93,41 @@ impl ArrayCompute for ChunkedArray<'_> {
         Some(self)
     }
 
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn string_transform(&self) -> Option<&dyn StringTransformFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
         Some(self)
     }
 }
 
+impl CastFn for ChunkedArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| cast(&chunk, dtype))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, dtype.clone()).map(|a| a.into_array())
+    }
+}
+
 impl AsContiguousFn for ChunkedArray<'_> {
     fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<OwnedArray> {
         // Combine all the chunks into one, then call as_contiguous again.
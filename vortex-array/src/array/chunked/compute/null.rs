@@ -0,0 +1,27 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::null::{fill_null, is_null, FillNullFn, IsNullFn};
+use crate::validity::Validity;
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl IsNullFn for ChunkedArray<'_> {
+    fn is_null(&self) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| is_null(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, Validity::DTYPE).map(|a| a.into_array())
+    }
+}
+
+impl FillNullFn for ChunkedArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| fill_null(&chunk, fill_value))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, self.dtype().as_nonnullable()).map(|a| a.into_array())
+    }
+}
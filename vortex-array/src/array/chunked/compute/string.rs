@@ -0,0 +1,40 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::string::{
+    length, string_length_dtype, string_match, string_transform, StringLengthFn, StringMatchFn,
+    StringPredicate, StringTransform, StringTransformFn,
+};
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl StringMatchFn for ChunkedArray<'_> {
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| string_match(&chunk, predicate))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, DType::Bool(self.dtype().nullability()))
+            .map(|a| a.into_array())
+    }
+}
+
+impl StringLengthFn for ChunkedArray<'_> {
+    fn string_length(&self) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| length(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, string_length_dtype(self.dtype())).map(|a| a.into_array())
+    }
+}
+
+impl StringTransformFn for ChunkedArray<'_> {
+    fn string_transform(&self, transform: &StringTransform) -> VortexResult<OwnedArray> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| string_transform(&chunk, transform))
+            .collect::<VortexResult<Vec<_>>>()?;
+        ChunkedArray::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
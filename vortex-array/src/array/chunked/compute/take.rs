@@ -1,19 +1,22 @@
-use vortex_dtype::PType;
+use vortex_dtype::{DType, PType};
 use vortex_error::VortexResult;
 
 use crate::array::chunked::ChunkedArray;
 use crate::compute::cast::cast;
 use crate::compute::take::{take, TakeFn};
-use crate::{Array, IntoArray, OwnedArray, ToArray, ToStatic};
-use crate::{ArrayDType, ArrayTrait};
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
 
 impl TakeFn for ChunkedArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
-        if self.len() == indices.len() {
-            return Ok(self.to_array().to_static());
+        if indices.is_empty() {
+            return ChunkedArray::try_new(vec![], self.dtype().clone()).map(|a| a.into_array());
         }
 
-        let indices = cast(indices, PType::U64.into())?.flatten_primitive()?;
+        let indices = cast(
+            indices,
+            &DType::Primitive(PType::U64, indices.dtype().nullability()),
+        )?
+        .flatten_primitive()?;
 
         // While the chunk idx remains the same, accumulate a list of chunk indices.
         let mut chunks = Vec::new();
@@ -55,6 +58,7 @@ mod test {
     use itertools::Itertools;
 
     use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
     use crate::compute::as_contiguous::as_contiguous;
     use crate::compute::take::take;
     use crate::{ArrayDType, ArrayTrait, AsArray, IntoArray};
@@ -78,4 +82,38 @@ mod test {
         .into_primitive();
         assert_eq!(result.typed_data::<i32>(), &[1, 1, 1, 2]);
     }
+
+    #[test]
+    fn take_permutation() {
+        let a = vec![1i32, 2, 3].into_array();
+        let arr = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone()).unwrap();
+        let indices = vec![5u64, 4, 3, 2, 1, 0].into_array();
+
+        let result = as_contiguous(
+            &ChunkedArray::try_from(take(arr.as_array_ref(), &indices).unwrap())
+                .unwrap()
+                .chunks()
+                .collect_vec(),
+        )
+        .unwrap()
+        .into_primitive();
+        assert_eq!(result.typed_data::<i32>(), &[3, 2, 1, 3, 2, 1]);
+    }
+
+    #[test]
+    fn take_nullable_indices() {
+        let a = vec![1i32, 2, 3].into_array();
+        let arr = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone()).unwrap();
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(4u32), Some(0)]).into_array();
+
+        let result = as_contiguous(
+            &ChunkedArray::try_from(take(arr.as_array_ref(), &indices).unwrap())
+                .unwrap()
+                .chunks()
+                .collect_vec(),
+        )
+        .unwrap()
+        .into_primitive();
+        assert_eq!(result.typed_data::<i32>(), &[2, 1]);
+    }
 }
@@ -0,0 +1,101 @@
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::unique::{dict_encode, used_value_counts, value_counts, ValueCountsFn};
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl ValueCountsFn for ChunkedArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let (values, counts): (Vec<_>, Vec<_>) = self
+            .chunks()
+            .map(|chunk| value_counts(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // The same value may occur in several chunks, so dictionary encode the values of every
+        // chunk to sum up the counts of equal values.
+        let values = ChunkedArray::try_new(values, self.dtype().clone())?.into_array();
+        let (codes, values) = dict_encode(&values)?;
+        let codes = codes.flatten_primitive()?;
+        let counts = counts
+            .into_iter()
+            .map(|c| c.flatten_primitive())
+            .collect::<VortexResult<Vec<_>>>()?;
+        let mut merged = vec![0u64; values.len()];
+        for (&code, &count) in codes
+            .typed_data::<u64>()
+            .iter()
+            .zip(counts.iter().flat_map(|c| c.typed_data::<u64>()))
+        {
+            merged[code as usize] += count;
+        }
+        used_value_counts(&values, merged)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::unique::value_counts;
+    use crate::{Array, ArrayDType, IntoArray};
+
+    /// The values and counts as strings, ordered by value.
+    fn counted(array: &Array) -> Vec<(String, u64)> {
+        let (values, counts) = value_counts(array).unwrap();
+        let counts = counts.flatten_primitive().unwrap();
+        let mut counted = (0..values.len())
+            .map(|i| {
+                (
+                    scalar_at(&values, i).unwrap().to_string(),
+                    counts.typed_data::<u64>()[i],
+                )
+            })
+            .collect::<Vec<_>>();
+        counted.sort();
+        counted
+    }
+
+    #[test]
+    fn value_counts_chunks() {
+        let chunks = vec![
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(2)]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(2i32), Some(2), Some(3)]).into_array(),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        assert_eq!(
+            counted(&array),
+            [
+                ("1(i32)".to_string(), 1),
+                ("2(i32)".to_string(), 3),
+                ("3(i32)".to_string(), 1),
+                ("<none>(i32?)".to_string(), 1),
+            ]
+        );
+
+        let strings = ChunkedArray::try_new(
+            vec![
+                VarBinArray::from(vec!["a", "b"]).into_array(),
+                VarBinViewArray::from(vec!["b", "c", "b"]).into_array(),
+            ],
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(
+            counted(&strings),
+            [
+                ("\"a\"".to_string(), 1),
+                ("\"b\"".to_string(), 3),
+                ("\"c\"".to_string(), 1),
+            ]
+        );
+    }
+}
@@ -2,14 +2,12 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{Nullability, PType};
 use vortex_error::vortex_bail;
-use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
 use crate::compute::scalar_at::scalar_at;
-use crate::compute::scalar_subtract::{subtract_scalar, SubtractScalarFn};
 use crate::compute::search_sorted::{search_sorted, SearchSortedSide};
 use crate::validity::Validity::NonNullable;
-use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData, OwnedArray, ToArrayData};
 
@@ -130,30 +128,23 @@ impl ArrayTrait for ChunkedArray<'_> {
 }
 
 impl ArrayValidity for ChunkedArray<'_> {
-    fn is_valid(&self, _index: usize) -> bool {
-        todo!()
+    fn is_valid(&self, index: usize) -> bool {
+        let (chunk_index, chunk_offset) = self.find_chunk_idx(index);
+        self.chunk(chunk_index)
+            .map(|c| c.with_dyn(|a| a.is_valid(chunk_offset)))
+            .unwrap_or(false)
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        todo!()
+        self.chunks()
+            .map(|c| c.with_dyn(|a| a.logical_validity()))
+            .collect::<Validity>()
+            .to_logical(self.len())
     }
 }
 
 impl EncodingCompression for ChunkedEncoding {}
 
-impl SubtractScalarFn for ChunkedArray<'_> {
-    fn subtract_scalar(&self, to_subtract: &Scalar) -> VortexResult<OwnedArray> {
-        self.chunks()
-            .map(|chunk| subtract_scalar(&chunk, to_subtract))
-            .collect::<VortexResult<Vec<_>>>()
-            .map(|chunks| {
-                ChunkedArray::try_new(chunks, self.dtype().clone())
-                    .expect("Subtraction on chunked array changed dtype")
-                    .into_array()
-            })
-    }
-}
-
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability};
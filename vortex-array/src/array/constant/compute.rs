@@ -1,26 +1,120 @@
+use std::cmp::Ordering;
+
 use itertools::Itertools;
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_err, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{BoolScalar, Scalar};
 
+use crate::array::bool::BoolArray;
 use crate::array::constant::ConstantArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::{merge_min_max, sum_repeated, CountDistinctFn, MinMaxFn, SumFn};
+use crate::compute::arithmetic::{
+    scalar_arithmetic, ArithmeticScalarFn, BinaryOperator, OverflowMode,
+};
 use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::boolean::{scalar_bool, BooleanFn, BooleanOperator, NotFn};
+use crate::compute::cast::CastFn;
+use crate::compute::compare::{CompareFn, Operator};
+use crate::compute::filter::FilterFn;
+use crate::compute::hash::{hash_scalar, HashFn};
 use crate::compute::scalar_at::ScalarAtFn;
+use crate::compute::sort::{argsort_by, ArgSortFn, SortOptions};
 use crate::compute::take::TakeFn;
+use crate::compute::unique::ValueCountsFn;
 use crate::compute::ArrayCompute;
-use crate::{Array, ArrayTrait, IntoArray, OwnedArray};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
 
 impl ArrayCompute for ConstantArray<'_> {
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        Some(self)
+    }
+
     fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
         Some(self)
     }
 
+    fn boolean(&self) -> Option<&dyn BooleanFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
+}
+
+impl CastFn for ConstantArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        Ok(ConstantArray::new(self.scalar().cast(dtype)?, self.len()).into_array())
+    }
+}
+
+impl ArithmeticScalarFn for ConstantArray<'_> {
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        let scalar = scalar_arithmetic(self.scalar(), other, operator, mode)?;
+        Ok(ConstantArray::new(scalar, self.len()).into_array())
+    }
+}
+
+impl ArgSortFn for ConstantArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        // All values are equal, so they keep their original order.
+        Ok(argsort_by(
+            self.len(),
+            options,
+            |_| true,
+            |_, _| Ordering::Equal,
+        ))
+    }
 }
 
 impl AsContiguousFn for ConstantArray<'_> {
@@ -45,14 +139,111 @@ impl AsContiguousFn for ConstantArray<'_> {
     }
 }
 
+impl BooleanFn for ConstantArray<'_> {
+    fn boolean(&self, other: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray> {
+        let nullability = (self.dtype().is_nullable() || other.dtype().is_nullable()).into();
+        let lhs = scalar_bool(self.scalar())?;
+        let value = if let Ok(other) = ConstantArray::try_from(other) {
+            operator.apply(lhs, scalar_bool(other.scalar())?)
+        } else {
+            // Without looking at the other side, the result is only known if it is decided by
+            // the constant alone.
+            match (operator, lhs) {
+                (BooleanOperator::And | BooleanOperator::AndNot, Some(false)) => Some(false),
+                (BooleanOperator::Or, Some(true)) => Some(true),
+                _ => {
+                    let flattened = self.array().clone().flatten_bool()?;
+                    return BooleanFn::boolean(&flattened, other, operator);
+                }
+            }
+        };
+        Ok(ConstantArray::new(BoolScalar::try_new(value, nullability)?, self.len()).into_array())
+    }
+}
+
+impl NotFn for ConstantArray<'_> {
+    fn not(&self) -> VortexResult<OwnedArray> {
+        let value = scalar_bool(self.scalar())?.map(|v| !v);
+        let scalar = BoolScalar::try_new(value, self.dtype().nullability())?;
+        Ok(ConstantArray::new(scalar, self.len()).into_array())
+    }
+}
+
+impl CompareFn for ConstantArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        let nullability = self.dtype().nullability();
+        let scalar: Scalar = if self.scalar().is_null() {
+            BoolScalar::try_new(None, nullability)?.into()
+        } else {
+            BoolScalar::try_new(
+                Some(operator.to_predicate()(self.scalar(), other)),
+                nullability,
+            )?
+            .into()
+        };
+        Ok(ConstantArray::new(scalar, self.len()).into_array())
+    }
+}
+
+impl CountDistinctFn for ConstantArray<'_> {
+    fn count_distinct(&self) -> VortexResult<usize> {
+        Ok(if self.scalar().is_null() { 0 } else { 1 })
+    }
+}
+
+impl FilterFn for ConstantArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        Ok(ConstantArray::new(
+            self.scalar().clone(),
+            mask.boolean_buffer().count_set_bits(),
+        )
+        .into_array())
+    }
+}
+
+impl HashFn for ConstantArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        Ok(ConstantArray::new(hash_scalar(self.scalar())?, self.len()).into_array())
+    }
+}
+
+impl MinMaxFn for ConstantArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        merge_min_max(
+            PType::try_from(self.dtype())?,
+            [(self.scalar().clone(), self.scalar().clone())],
+        )
+    }
+}
+
 impl ScalarAtFn for ConstantArray<'_> {
     fn scalar_at(&self, _index: usize) -> VortexResult<Scalar> {
         Ok(self.scalar().clone())
     }
 }
 
+impl SumFn for ConstantArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        sum_repeated(self.scalar(), self.len())
+    }
+}
+
 impl TakeFn for ConstantArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         Ok(ConstantArray::new(self.scalar().clone(), indices.len()).into_array())
     }
 }
+
+impl ValueCountsFn for ConstantArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let counts: Vec<u64> = if self.is_empty() {
+            vec![]
+        } else {
+            vec![self.len() as u64]
+        };
+        Ok((
+            ConstantArray::new(self.scalar().clone(), counts.len()).into_array(),
+            PrimitiveArray::from(counts).into_array(),
+        ))
+    }
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef as ArrowArrayRef, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray,
+};
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::array::datetime::TimeUnit;
+use crate::array::extension::ExtensionArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, ArrayData, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(InstantArray::ID);
+}
+
+/// An array of points in time, stored as the time since the Unix epoch in UTC.
+///
+/// The timezone is only used for display and conversion purposes, and is held in the extension
+/// metadata after the time unit byte.
+pub struct InstantArray<'a> {
+    ext: ExtensionArray<'a>,
+    time_unit: TimeUnit,
+    timezone: Arc<str>,
+}
+
+impl InstantArray<'_> {
+    pub const ID: &'static str = "vortex.instant";
+
+    pub fn try_new(
+        time_unit: TimeUnit,
+        timezone: impl Into<Arc<str>>,
+        timestamps: Array,
+    ) -> VortexResult<Self> {
+        if !timestamps.dtype().is_int() {
+            vortex_bail!("Timestamps must be an integer array")
+        }
+        let timezone = timezone.into();
+        Ok(Self {
+            ext: ExtensionArray::new(InstantArray::ext_dtype(time_unit, &timezone), timestamps),
+            time_unit,
+            timezone,
+        })
+    }
+
+    pub fn ext_dtype(time_unit: TimeUnit, timezone: &str) -> ExtDType {
+        let mut metadata = Vec::with_capacity(1 + timezone.len());
+        metadata.push(time_unit.into());
+        metadata.extend_from_slice(timezone.as_bytes());
+        ExtDType::new(ID.clone(), Some(ExtMetadata::from(metadata.as_slice())))
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    pub fn timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    pub fn timestamps(&self) -> Array {
+        self.ext.storage()
+    }
+}
+
+impl<'a> TryFrom<&ExtensionArray<'a>> for InstantArray<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray<'a>) -> Result<Self, Self::Error> {
+        let (time_unit, timezone) = try_parse_metadata(value.ext_dtype())?;
+        InstantArray::try_new(time_unit, timezone, value.storage())
+    }
+}
+
+impl<'a> TryFrom<&Array<'a>> for InstantArray<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &Array<'a>) -> Result<Self, Self::Error> {
+        let ext = ExtensionArray::try_from(value)?;
+        let (time_unit, timezone) = try_parse_metadata(ext.ext_dtype())?;
+        InstantArray::try_new(time_unit, timezone, ext.storage())
+    }
+}
+
+impl AsArrowArray for InstantArray<'_> {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        // An Instant maps to an Arrow Timestamp array with a timezone.
+        let timestamps = self.timestamps();
+        let dtype = DType::Primitive(PType::I64, timestamps.dtype().nullability());
+        let timestamps = cast(&timestamps, &dtype)?.flatten_primitive()?;
+        let validity = timestamps.logical_validity().to_null_buffer()?;
+        let buffer = timestamps.scalar_buffer::<i64>();
+        let timezone = self.timezone.clone();
+
+        Ok(match self.time_unit() {
+            TimeUnit::Ns => {
+                Arc::new(TimestampNanosecondArray::new(buffer, validity).with_timezone(timezone))
+            }
+            TimeUnit::Us => {
+                Arc::new(TimestampMicrosecondArray::new(buffer, validity).with_timezone(timezone))
+            }
+            TimeUnit::Ms => {
+                Arc::new(TimestampMillisecondArray::new(buffer, validity).with_timezone(timezone))
+            }
+            TimeUnit::S => {
+                Arc::new(TimestampSecondArray::new(buffer, validity).with_timezone(timezone))
+            }
+        })
+    }
+}
+
+impl IntoArrayData for InstantArray<'_> {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+fn try_parse_metadata(ext_dtype: &ExtDType) -> VortexResult<(TimeUnit, Arc<str>)> {
+    if ext_dtype.id().as_ref() != InstantArray::ID {
+        vortex_bail!("Extension type {} is not an instant", ext_dtype.id())
+    }
+    let metadata = ext_dtype
+        .metadata()
+        .ok_or_else(|| vortex_err!("Missing metadata"))?
+        .as_ref();
+    let (&unit, timezone) = metadata
+        .split_first()
+        .ok_or_else(|| vortex_err!("Missing time unit in metadata"))?;
+    let time_unit =
+        TimeUnit::try_from(unit).map_err(|_| vortex_err!("Invalid time unit in metadata"))?;
+    let timezone =
+        std::str::from_utf8(timezone).map_err(|_| vortex_err!("Invalid timezone in metadata"))?;
+    Ok((time_unit, timezone.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::TimestampMillisecondType;
+    use arrow_schema::{DataType, Field, TimeUnit as ArrowTimeUnit};
+    use vortex_dtype::DType;
+
+    use crate::array::datetime::{InstantArray, TimeUnit};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::{FromArrowArray, TryFromArrowType};
+    use crate::compute::as_arrow::as_arrow;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, IntoArray, IntoArrayData};
+
+    #[test]
+    fn arrow_round_trip() {
+        let timestamps = PrimitiveArray::from_vec(
+            vec![0i64, 1_700_000_000_000, -86_400_000],
+            Validity::from(vec![true, false, true]),
+        );
+        let array = InstantArray::try_new(TimeUnit::Ms, "UTC", timestamps.into_array())
+            .unwrap()
+            .into_array_data()
+            .into_array();
+
+        let arrow = as_arrow(&array).unwrap();
+        assert_eq!(
+            arrow.data_type(),
+            &DataType::Timestamp(ArrowTimeUnit::Millisecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            arrow.as_primitive::<TimestampMillisecondType>().value(2),
+            -86_400_000
+        );
+        assert!(arrow.is_null(1));
+
+        let read = ArrayData::from_arrow(arrow, true).into_array();
+        assert_eq!(read.dtype(), array.dtype());
+        let instant = InstantArray::try_from(&read).unwrap();
+        assert_eq!(instant.time_unit(), TimeUnit::Ms);
+        assert_eq!(instant.timezone(), "UTC");
+    }
+
+    #[test]
+    fn dtype_from_arrow() {
+        let field = Field::new(
+            "ts",
+            DataType::Timestamp(ArrowTimeUnit::Millisecond, Some("+01:00".into())),
+            false,
+        );
+        let DType::Extension(ext, _) = DType::try_from_arrow(&field).unwrap() else {
+            panic!("Expected an extension dtype")
+        };
+        assert_eq!(ext, InstantArray::ext_dtype(TimeUnit::Ms, "+01:00"));
+    }
+}
@@ -68,7 +68,9 @@ impl<'a> TryFrom<&ExtensionArray<'a>> for LocalDateTimeArray<'a> {
 impl AsArrowArray for LocalDateTimeArray<'_> {
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
         // A LocalDateTime maps to an Arrow Timestamp array with no timezone.
-        let timestamps = cast(&self.timestamps(), PType::I64.into())?.flatten_primitive()?;
+        let timestamps = self.timestamps();
+        let dtype = DType::Primitive(PType::I64, timestamps.dtype().nullability());
+        let timestamps = cast(&timestamps, &dtype)?.flatten_primitive()?;
         let validity = timestamps.logical_validity().to_null_buffer()?;
         let buffer = timestamps.scalar_buffer::<i64>();
 
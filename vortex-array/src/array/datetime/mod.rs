@@ -1,11 +1,13 @@
 use std::fmt::{Display, Formatter};
 
+pub use instant::*;
 use lazy_static::lazy_static;
 pub use localdatetime::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
 use vortex_dtype::ExtMetadata;
 
+mod instant;
 mod localdatetime;
 
 #[derive(
@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, Decimal128Array, Decimal256Array};
+use arrow_buffer::{i256, ScalarBuffer};
+use itertools::Itertools;
+use vortex_dtype::{DType, DecimalDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::array::extension::ExtensionArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::array::varbin::VarBinArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayData, ArrayTrait, IntoArray, IntoArrayData};
+
+/// An array of fixed-point decimals, see [`DecimalDType`] for how the values are stored.
+pub struct DecimalArray<'a> {
+    ext: ExtensionArray<'a>,
+    decimal: DecimalDType,
+}
+
+impl DecimalArray<'_> {
+    pub fn try_new(decimal: DecimalDType, storage: Array) -> VortexResult<Self> {
+        let expected = decimal.storage_dtype(storage.dtype().nullability());
+        if storage.dtype() != &expected {
+            vortex_bail!(MismatchedTypes: expected, storage.dtype())
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(decimal.ext_dtype(), storage),
+            decimal,
+        })
+    }
+
+    /// Create a decimal array from unscaled values, returning an error if a value does not fit
+    /// into the storage of the decimal.
+    pub fn from_values(
+        decimal: DecimalDType,
+        values: Vec<i128>,
+        validity: Validity,
+    ) -> VortexResult<DecimalArray<'static>> {
+        if !decimal.is_i64() {
+            let bytes = values
+                .iter()
+                .map(|&v| decimal.value_to_bytes(v))
+                .flatten_ok()
+                .try_collect()?;
+            return Self::from_le_bytes(decimal, bytes, validity);
+        }
+        let values: Vec<i64> = values
+            .into_iter()
+            .map(|v| {
+                i64::try_from(v)
+                    .map_err(|_| vortex_err!("Value {} does not fit into a {}", v, decimal))
+            })
+            .try_collect()?;
+        DecimalArray::try_new(
+            decimal,
+            PrimitiveArray::from_vec(values, validity).into_array(),
+        )
+    }
+
+    /// Create a decimal array that is not stored as i64 from the concatenated little-endian
+    /// binary representation of its values, see [`DecimalDType::value_width`].
+    pub fn from_le_bytes(
+        decimal: DecimalDType,
+        bytes: Vec<u8>,
+        validity: Validity,
+    ) -> VortexResult<DecimalArray<'static>> {
+        if decimal.is_i64() {
+            vortex_bail!("Values of {} are not stored as binary", decimal)
+        }
+        let width = decimal.value_width();
+        if bytes.len() % width != 0 {
+            vortex_bail!(
+                "Values of {} must be {} bytes, got {} bytes in total",
+                decimal,
+                width,
+                bytes.len()
+            )
+        }
+        let offsets = (0..=(bytes.len() / width) as u64)
+            .map(|i| i * width as u64)
+            .collect_vec();
+        let storage = VarBinArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            PrimitiveArray::from(bytes).into_array(),
+            DType::Binary(validity.nullability()),
+            validity,
+        )?;
+        DecimalArray::try_new(decimal, storage.into_array())
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        self.decimal
+    }
+
+    pub fn storage(&self) -> Array {
+        self.ext.storage()
+    }
+
+    /// The unscaled values of the array, with null values set to zero. Returns an error if a value
+    /// does not fit into an i128.
+    pub fn values(&self) -> VortexResult<Vec<i128>> {
+        if self.decimal.is_i64() {
+            let storage = self.storage().flatten_primitive()?;
+            return Ok(storage
+                .typed_data::<i64>()
+                .iter()
+                .map(|&v| v as i128)
+                .collect());
+        }
+
+        let storage = self.storage().flatten_varbin()?;
+        (0..storage.len())
+            .map(|i| {
+                if !storage.is_valid(i) {
+                    return Ok(0);
+                }
+                self.decimal.value_from_bytes(storage.bytes_at(i)?.as_ref())
+            })
+            .collect()
+    }
+}
+
+impl<'a> TryFrom<&ExtensionArray<'a>> for DecimalArray<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray<'a>) -> Result<Self, Self::Error> {
+        DecimalArray::try_new(DecimalDType::try_from(value.ext_dtype())?, value.storage())
+    }
+}
+
+impl<'a> TryFrom<&Array<'a>> for DecimalArray<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &Array<'a>) -> Result<Self, Self::Error> {
+        let ext = ExtensionArray::try_from(value)?;
+        DecimalArray::try_new(DecimalDType::try_from(ext.ext_dtype())?, ext.storage())
+    }
+}
+
+/// Decimals with a precision of up to [`DecimalDType::MAX_I128_PRECISION`] are converted to a
+/// Decimal128 array, wider decimals to a Decimal256 array. An Arrow Decimal256 array with a
+/// precision of up to 38 therefore comes back as a Decimal128 array.
+impl AsArrowArray for DecimalArray<'_> {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let nulls = self.ext.logical_validity().to_null_buffer()?;
+        if self.decimal.precision() <= DecimalDType::MAX_I128_PRECISION {
+            let values = ScalarBuffer::from(self.values()?);
+            return Ok(Arc::new(
+                Decimal128Array::new(values, nulls)
+                    .with_precision_and_scale(self.decimal.precision(), self.decimal.scale())?,
+            ));
+        }
+
+        let storage = self.storage().flatten_varbin()?;
+        let values: Vec<i256> = (0..storage.len())
+            .map(|i| -> VortexResult<i256> {
+                if !storage.is_valid(i) {
+                    return Ok(i256::ZERO);
+                }
+                let bytes: [u8; 32] = storage
+                    .bytes_at(i)?
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| vortex_err!("Values of {} must be 32 bytes", self.decimal))?;
+                Ok(i256::from_le_bytes(bytes))
+            })
+            .try_collect()?;
+        Ok(Arc::new(
+            Decimal256Array::new(ScalarBuffer::from(values), nulls)
+                .with_precision_and_scale(self.decimal.precision(), self.decimal.scale())?,
+        ))
+    }
+}
+
+impl IntoArrayData for DecimalArray<'_> {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Decimal128Type, Decimal256Type};
+    use arrow_array::{ArrayRef as ArrowArrayRef, Decimal256Array};
+    use arrow_buffer::i256;
+    use arrow_schema::{DataType, Field};
+    use vortex_dtype::{DType, DecimalDType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::decimal::DecimalArray;
+    use crate::arrow::{FromArrowArray, TryFromArrowType};
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, IntoArray, IntoArrayData};
+
+    fn decimals(precision: u8) -> DecimalArray<'static> {
+        DecimalArray::from_values(
+            DecimalDType::try_new(precision, 2).unwrap(),
+            vec![12345, -5, 0],
+            Validity::from(vec![true, true, false]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn scalar_at_decimal() {
+        for precision in [10, 38] {
+            let array = decimals(precision).into_array_data().into_array();
+            let Scalar::Extension(ext) = scalar_at(&array, 1).unwrap() else {
+                panic!("Expected an extension scalar")
+            };
+            assert_eq!(ext.decimal_value().unwrap(), Some(-5));
+            assert_eq!(ext.to_string(), "-0.05");
+            assert!(scalar_at(&array, 2).unwrap().is_null());
+        }
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        for precision in [10, 38] {
+            let array = decimals(precision).into_array_data().into_array();
+            let arrow = as_arrow(&array).unwrap();
+            let arrow_decimals = arrow.as_primitive::<Decimal128Type>();
+            assert_eq!(arrow_decimals.precision(), precision);
+            assert_eq!(arrow_decimals.scale(), 2);
+            assert_eq!(arrow_decimals.value(0), 12345);
+            assert!(arrow.is_null(2));
+
+            let read = ArrayData::from_arrow(arrow, true).into_array();
+            assert_eq!(read.dtype(), array.dtype());
+            assert_eq!(
+                DecimalArray::try_from(&read).unwrap().values().unwrap()[..2],
+                [12345, -5]
+            );
+        }
+    }
+
+    #[test]
+    fn arrow_decimal256_round_trip() {
+        let wide = i256::from_i128(i128::MAX) * i256::from_i128(10);
+        let arrow: ArrowArrayRef = Arc::new(
+            Decimal256Array::from(vec![Some(wide), Some(i256::from_i128(-5)), None])
+                .with_precision_and_scale(60, 2)
+                .unwrap(),
+        );
+        let array = ArrayData::from_arrow(arrow, true).into_array();
+        let Scalar::Extension(ext) = scalar_at(&array, 1).unwrap() else {
+            panic!("Expected an extension scalar")
+        };
+        assert_eq!(ext.to_string(), "-0.05");
+        assert!(DecimalArray::try_from(&array).unwrap().values().is_err());
+
+        let read = as_arrow(&array).unwrap();
+        let read_decimals = read.as_primitive::<Decimal256Type>();
+        assert_eq!(read_decimals.precision(), 60);
+        assert_eq!(read_decimals.value(0), wide);
+        assert_eq!(read_decimals.value(1), i256::from_i128(-5));
+        assert!(read.is_null(2));
+    }
+
+    #[test]
+    fn value_out_of_range() {
+        let decimal = DecimalDType::try_new(18, 0).unwrap();
+        assert!(
+            DecimalArray::from_values(decimal, vec![i128::MAX], Validity::NonNullable).is_err()
+        );
+    }
+
+    #[test]
+    fn arrow_invalid_precision() {
+        let field = Field::new("d", DataType::Decimal256(80, 0), true);
+        assert!(DType::try_from_arrow(&field).is_err());
+    }
+}
@@ -2,7 +2,8 @@ use arrow_array::ArrayRef as ArrowArrayRef;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::{ExtScalar, Scalar};
 
-use crate::array::datetime::LocalDateTimeArray;
+use crate::array::datetime::{InstantArray, LocalDateTimeArray};
+use crate::array::decimal::DecimalArray;
 use crate::array::extension::ExtensionArray;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
@@ -51,11 +52,13 @@ impl ArrayCompute for ExtensionArray<'_> {
 
 impl AsArrowArray for ExtensionArray<'_> {
     /// To support full compatability with Arrow, we hard-code the conversion of our datetime
-    /// arrays to Arrow's Timestamp arrays here. For all other extension arrays, we return an
-    /// Arrow extension array with the same definition.
+    /// and decimal arrays to Arrow's Timestamp and Decimal arrays here. For all other
+    /// extension arrays, we return an Arrow extension array with the same definition.
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
         match self.id().as_ref() {
             "vortex.localdatetime" => LocalDateTimeArray::try_from(self)?.as_arrow(),
+            "vortex.instant" => InstantArray::try_from(self)?.as_arrow(),
+            "vortex.decimal" => DecimalArray::try_from(self)?.as_arrow(),
             _ => vortex_bail!("Arrow extension arrays not yet supported"),
         }
     }
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, GenericListArray};
+use arrow_schema::Field;
+use itertools::Itertools;
+use vortex_dtype::{match_each_integer_ptype, PType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{ListScalar, Scalar};
+
+use crate::array::list::ListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::arrow::wrappers::as_offset_buffer;
+use crate::compute::as_arrow::{as_arrow, AsArrowArray};
+use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::cast;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray, ToArray};
+
+impl ArrayCompute for ListArray<'_> {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for ListArray<'_> {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        // Arrow lists require either i32 or i64 offsets
+        let offsets = self.offsets().flatten_primitive()?;
+        let offsets = match offsets.ptype() {
+            PType::I32 | PType::I64 => offsets,
+            PType::U64 => cast(&offsets.to_array(), PType::I64.into())?.flatten_primitive()?,
+            _ => cast(&offsets.to_array(), PType::I32.into())?.flatten_primitive()?,
+        };
+        let elements = as_arrow(&self.elements())?;
+        let field = Arc::new(Field::new(
+            "item",
+            elements.data_type().clone(),
+            self.element_dtype().is_nullable(),
+        ));
+        let nulls = self.logical_validity().to_null_buffer()?;
+
+        Ok(match offsets.ptype() {
+            PType::I32 => Arc::new(GenericListArray::<i32>::try_new(
+                field,
+                as_offset_buffer::<i32>(offsets),
+                elements,
+                nulls,
+            )?),
+            PType::I64 => Arc::new(GenericListArray::<i64>::try_new(
+                field,
+                as_offset_buffer::<i64>(offsets),
+                elements,
+                nulls,
+            )?),
+            _ => vortex_bail!("Invalid offsets type {}", offsets.ptype()),
+        })
+    }
+}
+
+impl AsContiguousFn for ListArray<'_> {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<OwnedArray> {
+        let lists: Vec<ListArray> = arrays.iter().map(ListArray::try_from).try_collect()?;
+        let elements_chunks: Vec<Array> =
+            lists.iter().map(|a| a.sliced_elements()).try_collect()?;
+        let elements = as_contiguous(&elements_chunks)?;
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(arrays.iter().map(|a| a.with_dyn(|a| a.logical_validity())))
+        } else {
+            Validity::NonNullable
+        };
+
+        let mut offsets = vec![0u64];
+        for list in lists.iter() {
+            let offsets_array = cast(&list.offsets(), PType::U64.into())?.flatten_primitive()?;
+            let offsets_slice = offsets_array.typed_data::<u64>();
+            let first_offset = offsets_slice[0];
+            let shift = offsets.last().copied().unwrap_or(0);
+            offsets.extend(
+                offsets_slice
+                    .iter()
+                    .skip(1) // Ignore the zero offset for each array
+                    .map(|o| o + shift - first_offset),
+            );
+        }
+
+        ListArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            elements,
+            self.dtype().nullability(),
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for ListArray<'_> {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype()));
+        }
+
+        let elements = self.elements();
+        let values = (self.offset_at(index)?..self.offset_at(index + 1)?)
+            .map(|i| scalar_at(&elements, i))
+            .try_collect()?;
+        Ok(ListScalar::new(self.dtype().clone(), Some(values)).into())
+    }
+}
+
+impl SliceFn for ListArray<'_> {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<OwnedArray> {
+        ListArray::try_new(
+            slice(&self.offsets(), start, stop + 1)?,
+            self.elements(),
+            self.dtype().nullability(),
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for ListArray<'_> {
+    fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
+        let offsets = cast(&self.offsets(), PType::U64.into())?.flatten_primitive()?;
+        let offsets = offsets.typed_data::<u64>();
+        let len = self.len();
+        let primitive_indices = indices.clone().flatten_primitive()?;
+
+        // Gather the elements of every taken list, so the elements only need a single take.
+        let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+        new_offsets.push(0u64);
+        let mut element_indices = Vec::new();
+        match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            for &idx in primitive_indices.typed_data::<$I>() {
+                let idx = idx as usize;
+                if idx >= len {
+                    vortex_bail!(OutOfBounds: idx, 0, len);
+                }
+                element_indices.extend(offsets[idx]..offsets[idx + 1]);
+                new_offsets.push(element_indices.len() as u64);
+            }
+        });
+
+        ListArray::try_new(
+            PrimitiveArray::from(new_offsets).into_array(),
+            take(
+                &self.elements(),
+                &PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            self.dtype().nullability(),
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,329 @@
+use num_traits::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{match_each_integer_ptype, Nullability};
+use vortex_error::{vortex_bail, vortex_err};
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::scalar_at::scalar_at;
+use crate::compute::slice::slice;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArray, OwnedArray, ToArrayData};
+
+mod compute;
+mod stats;
+
+impl_encoding!("vortex.list", List);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMetadata {
+    validity: ValidityMetadata,
+    offsets_dtype: DType,
+}
+
+impl ListArray<'_> {
+    pub fn try_new(
+        offsets: Array,
+        elements: Array,
+        nullability: Nullability,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("List offsets must contain at least one element");
+        }
+        if nullability != validity.nullability() {
+            vortex_bail!("incorrect validity {:?}", validity);
+        }
+        validate_offsets(&offsets, elements.len())?;
+
+        let dtype = DType::List(Box::new(elements.dtype().clone()), nullability);
+        let metadata = ListMetadata {
+            validity: validity.to_metadata(offsets.len() - 1)?,
+            offsets_dtype: offsets.dtype().clone(),
+        };
+
+        let mut children = Vec::with_capacity(3);
+        children.push(offsets.to_array_data());
+        children.push(elements.to_array_data());
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().offsets_dtype)
+            .expect("missing offsets")
+    }
+
+    pub fn element_dtype(&self) -> &DType {
+        let DType::List(element_dtype, _) = self.dtype() else {
+            unreachable!()
+        };
+        element_dtype
+    }
+
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.array()
+            .child(1, self.element_dtype())
+            .expect("missing elements")
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(2, &Validity::DTYPE))
+    }
+
+    pub fn offset_at(&self, index: usize) -> VortexResult<usize> {
+        let offsets = self.offsets();
+        if index >= offsets.len() {
+            vortex_bail!(OutOfBounds: index, 0, offsets.len());
+        }
+        match PrimitiveArray::try_from(&offsets) {
+            Ok(p) => match_each_integer_ptype!(p.ptype(), |$P| {
+                p.typed_data::<$P>()[index]
+                    .to_usize()
+                    .ok_or_else(|| vortex_err!("Invalid list offset at index {}", index))
+            }),
+            Err(_) => usize::try_from(&scalar_at(&offsets, index)?),
+        }
+    }
+
+    /// The elements of the list at the given index.
+    pub fn elements_at(&self, index: usize) -> VortexResult<OwnedArray> {
+        slice(
+            &self.elements(),
+            self.offset_at(index)?,
+            self.offset_at(index + 1)?,
+        )
+    }
+
+    /// The elements referenced by the offsets of this array, which may be sliced.
+    pub fn sliced_elements(&self) -> VortexResult<OwnedArray> {
+        slice(
+            &self.elements(),
+            self.offset_at(0)?,
+            self.offset_at(self.len())?,
+        )
+    }
+}
+
+/// Check that list offsets are non-negative, non-decreasing and within the elements.
+fn validate_offsets(offsets: &Array, num_elements: usize) -> VortexResult<()> {
+    let offsets = offsets.clone().flatten_primitive()?;
+    match_each_integer_ptype!(offsets.ptype(), |$P| {
+        let values = offsets.typed_data::<$P>();
+        if values.windows(2).any(|w| w[0] > w[1]) {
+            vortex_bail!("List offsets must be non-decreasing");
+        }
+        if values[0].to_usize().is_none() {
+            vortex_bail!("List offsets must be non-negative, got {}", values[0]);
+        }
+        let last = values[values.len() - 1]
+            .to_usize()
+            .ok_or_else(|| vortex_err!("Invalid list offset {}", values[values.len() - 1]))?;
+        if last > num_elements {
+            vortex_bail!(OutOfBounds: last, 0, num_elements);
+        }
+    });
+    Ok(())
+}
+
+impl ArrayFlatten for ListArray<'_> {
+    fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
+    where
+        Self: 'a,
+    {
+        Ok(Flattened::List(ListArray::try_new(
+            self.offsets().flatten()?.into_array(),
+            self.elements().flatten()?.into_array(),
+            self.dtype().nullability(),
+            self.validity(),
+        )?))
+    }
+}
+
+impl ArrayTrait for ListArray<'_> {
+    fn len(&self) -> usize {
+        self.offsets().len() - 1
+    }
+}
+
+impl ArrayValidity for ListArray<'_> {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ListArray<'_> {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl EncodingCompression for ListEncoding {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::{ListScalar, Scalar};
+
+    use crate::array::constant::ConstantArray;
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, IntoArray, OwnedArray};
+
+    fn list_array() -> OwnedArray {
+        // [[1, 2], [], [3, 4, 5], null]
+        ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 5, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            Nullability::Nullable,
+            Validity::from(vec![true, true, true, false]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn list_scalar(values: &[i32]) -> Scalar {
+        ListScalar::new(
+            DType::List(
+                Box::new(DType::Primitive(PType::I32, Nullability::NonNullable)),
+                Nullability::Nullable,
+            ),
+            Some(values.iter().map(|&v| v.into()).collect()),
+        )
+        .into()
+    }
+
+    fn values(array: &OwnedArray, index: usize) -> Vec<i32> {
+        ListArray::try_from(array)
+            .unwrap()
+            .elements_at(index)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<i32>()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_scalar_at() {
+        let array = list_array();
+        assert_eq!(array.len(), 4);
+        assert_eq!(scalar_at(&array, 0).unwrap(), list_scalar(&[1, 2]));
+        assert_eq!(scalar_at(&array, 1).unwrap(), list_scalar(&[]));
+        assert_eq!(scalar_at(&array, 2).unwrap(), list_scalar(&[3, 4, 5]));
+        assert!(scalar_at(&array, 3).unwrap().is_null());
+    }
+
+    #[test]
+    fn slice_array() {
+        let sliced = slice(&list_array(), 1, 3).unwrap();
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.dtype(), list_array().dtype());
+        assert!(values(&sliced, 0).is_empty());
+        assert_eq!(values(&sliced, 1), [3, 4, 5]);
+    }
+
+    #[test]
+    fn take_array() {
+        let indices = PrimitiveArray::from(vec![2u64, 3, 0, 2]).into_array();
+        let taken = take(&list_array(), &indices).unwrap();
+        assert_eq!(taken.len(), 4);
+        assert_eq!(values(&taken, 0), [3, 4, 5]);
+        assert!(scalar_at(&taken, 1).unwrap().is_null());
+        assert_eq!(values(&taken, 2), [1, 2]);
+        assert_eq!(values(&taken, 3), [3, 4, 5]);
+    }
+
+    #[test]
+    fn take_out_of_bounds() {
+        let indices = PrimitiveArray::from(vec![0u64, 4]).into_array();
+        assert!(take(&list_array(), &indices).is_err());
+    }
+
+    #[test]
+    fn flatten_children() {
+        let array = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 5]).into_array(),
+            ConstantArray::new(7i32, 5).into_array(),
+            Nullability::NonNullable,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let flattened = ListArray::try_from(array.flatten().unwrap().into_array()).unwrap();
+        assert_eq!(
+            PrimitiveArray::try_from(flattened.elements())
+                .unwrap()
+                .typed_data::<i32>(),
+            [7; 5]
+        );
+    }
+
+    #[test]
+    fn contiguous() {
+        let sliced = slice(&list_array(), 2, 4).unwrap();
+        let array = as_contiguous(&[list_array(), sliced]).unwrap();
+        assert_eq!(array.len(), 6);
+        assert_eq!(values(&array, 4), [3, 4, 5]);
+        assert!(scalar_at(&array, 5).unwrap().is_null());
+        assert_eq!(
+            ListArray::try_from(&array)
+                .unwrap()
+                .sliced_elements()
+                .unwrap()
+                .len(),
+            8
+        );
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        let arrow = as_arrow(&slice(&list_array(), 1, 4).unwrap()).unwrap();
+        assert_eq!(arrow.len(), 3);
+        assert_eq!(arrow.null_count(), 1);
+
+        let array = ArrayData::from_arrow(arrow, true).into_array();
+        assert_eq!(array.dtype(), list_array().dtype());
+        assert!(values(&array, 0).is_empty());
+        assert_eq!(values(&array, 1), [3, 4, 5]);
+        assert!(scalar_at(&array, 2).unwrap().is_null());
+    }
+
+    #[test]
+    fn invalid_offsets() {
+        let elements = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        for offsets in [vec![0i32, 2, 1], vec![-1, 2, 3], vec![0, 2, 4]] {
+            assert!(ListArray::try_new(
+                PrimitiveArray::from(offsets).into_array(),
+                elements.clone(),
+                Nullability::NonNullable,
+                Validity::NonNullable,
+            )
+            .is_err());
+        }
+    }
+}
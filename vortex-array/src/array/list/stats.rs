@@ -0,0 +1,15 @@
+use vortex_error::VortexResult;
+
+use crate::array::list::ListArray;
+use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::validity::ArrayValidity;
+
+impl ArrayStatisticsCompute for ListArray<'_> {
+    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+        let null_count = self
+            .logical_validity()
+            .to_present_null_buffer()?
+            .null_count();
+        Ok(StatsSet::of(Stat::NullCount, null_count.into()))
+    }
+}
@@ -2,7 +2,9 @@ pub mod bool;
 pub mod chunked;
 pub mod constant;
 pub mod datetime;
+pub mod decimal;
 pub mod extension;
+pub mod list;
 pub mod primitive;
 pub mod sparse;
 pub mod r#struct;
@@ -0,0 +1,96 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::{
+    count_distinct_bytes, CountDistinctFn, MinMaxFn, SumAccumulator, SumFn,
+};
+use crate::validity::ArrayValidity;
+
+impl SumFn for PrimitiveArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        let mut accumulator = SumAccumulator::new(self.ptype());
+        match_each_native_ptype!(self.ptype(), |$T| {
+            for (i, &v) in self.typed_data::<$T>().iter().enumerate() {
+                if validity.as_ref().map_or(true, |n| n.is_valid(i)) {
+                    accumulator.add(v, 1)?;
+                }
+            }
+        });
+        Ok(accumulator.finish())
+    }
+}
+
+impl MinMaxFn for PrimitiveArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let mut min: Option<$T> = None;
+            let mut max: Option<$T> = None;
+            for (i, &v) in self.typed_data::<$T>().iter().enumerate() {
+                // Skip nulls and NaNs, which are not ordered.
+                if validity.as_ref().map_or(false, |n| n.is_null(i)) || v.partial_cmp(&v).is_none() {
+                    continue;
+                }
+                min = Some(min.map_or(v, |m| if v < m { v } else { m }));
+                max = Some(max.map_or(v, |m| if v > m { v } else { m }));
+            }
+            Ok((PrimitiveScalar::nullable(min).into(), PrimitiveScalar::nullable(max).into()))
+        })
+    }
+}
+
+impl CountDistinctFn for PrimitiveArray<'_> {
+    fn count_distinct(&self) -> VortexResult<usize> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        let values = self
+            .buffer()
+            .chunks_exact(self.ptype().byte_width())
+            .enumerate()
+            .filter(|(i, _)| validity.as_ref().map_or(true, |n| n.is_valid(*i)))
+            .map(|(_, v)| v);
+        Ok(count_distinct_bytes(values))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::aggregate::{count_distinct, mean, min_max, sum};
+    use crate::IntoArray;
+
+    #[test]
+    fn aggregate_nullable() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i8), None, Some(-7), Some(3), Some(120)])
+                .into_array();
+        assert_eq!(sum(&array).unwrap(), Some(119i64).into());
+        assert_eq!(mean(&array).unwrap(), Some(29.75f64).into());
+        assert_eq!(
+            min_max(&array).unwrap(),
+            (Some(-7i8).into(), Some(120i8).into())
+        );
+        assert_eq!(count_distinct(&array).unwrap(), 3);
+    }
+
+    #[test]
+    fn aggregate_widens() {
+        let array = vec![u32::MAX; 4].into_array();
+        assert_eq!(sum(&array).unwrap(), Some(4 * u32::MAX as u64).into());
+        assert!(sum(&vec![i64::MAX, 1].into_array()).is_err());
+    }
+
+    #[test]
+    fn aggregate_floats() {
+        let array = vec![1.5f32, f32::NAN, -2.5].into_array();
+        assert_eq!(
+            min_max(&array).unwrap(),
+            (Some(-2.5f32).into(), Some(1.5f32).into())
+        );
+        let all_null = PrimitiveArray::from_nullable_vec(vec![None::<f64>]).into_array();
+        assert!(sum(&all_null).unwrap().is_null());
+        assert!(mean(&all_null).unwrap().is_null());
+    }
+}
@@ -0,0 +1,114 @@
+use arrow_buffer::NullBuffer;
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::arithmetic::{
+    ArithmeticFn, ArithmeticScalarFn, BinaryOperator, OverflowMode, PrimitiveArithmetic,
+};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+
+impl ArithmeticScalarFn for PrimitiveArray<'_> {
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let rhs = <$T>::try_from(other)?;
+            let lhs = self.typed_data::<$T>();
+            let values = apply_valid(self.len(), nulls.as_ref(), |i| lhs[i].apply(rhs, operator, mode))?;
+            Ok(PrimitiveArray::from_vec(values, self.validity().to_static()).into_array())
+        })
+    }
+}
+
+impl ArithmeticFn for PrimitiveArray<'_> {
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        let other = other.clone().flatten_primitive()?;
+        let nulls = NullBuffer::union(
+            self.logical_validity().to_null_buffer()?.as_ref(),
+            other.logical_validity().to_null_buffer()?.as_ref(),
+        );
+        let validity = match (
+            &nulls,
+            self.dtype().is_nullable() || other.dtype().is_nullable(),
+        ) {
+            (_, false) => Validity::NonNullable,
+            (None, true) => Validity::AllValid,
+            (Some(n), true) => Validity::from(n.clone()),
+        };
+
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let lhs = self.typed_data::<$T>();
+            let rhs = other.typed_data::<$T>();
+            let values = apply_valid(self.len(), nulls.as_ref(), |i| lhs[i].apply(rhs[i], operator, mode))?;
+            Ok(PrimitiveArray::from_vec(values, validity).into_array())
+        })
+    }
+}
+
+/// Evaluate the operation at every valid index, leaving the null values zeroed.
+fn apply_valid<T: PrimitiveArithmetic>(
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    op: impl Fn(usize) -> VortexResult<T>,
+) -> VortexResult<Vec<T>> {
+    (0..len)
+        .map(|i| match nulls {
+            Some(n) if n.is_null(i) => Ok(T::default()),
+            _ => op(i),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::arithmetic::{arithmetic_scalar, BinaryOperator, OverflowMode};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::IntoArray;
+
+    #[test]
+    fn arithmetic_scalar_nullable() {
+        let values =
+            PrimitiveArray::from_nullable_vec(vec![Some(i8::MIN), None, Some(4)]).into_array();
+        let result = arithmetic_scalar(
+            &values,
+            &(-1i8).into(),
+            BinaryOperator::Div,
+            OverflowMode::Saturating,
+        )
+        .unwrap();
+        assert_eq!(scalar_at(&result, 0).unwrap(), Some(i8::MAX).into());
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+        assert_eq!(scalar_at(&result, 2).unwrap(), Some(-4i8).into());
+    }
+
+    #[test]
+    fn arithmetic_scalar_float() {
+        let values = vec![1.5f64, -3.0].into_array();
+        let result = arithmetic_scalar(
+            &values,
+            &0.0f64.into(),
+            BinaryOperator::Div,
+            OverflowMode::Checked,
+        )
+        .unwrap()
+        .flatten_primitive()
+        .unwrap();
+        assert_eq!(
+            result.typed_data::<f64>(),
+            [f64::INFINITY, f64::NEG_INFINITY]
+        );
+    }
+}
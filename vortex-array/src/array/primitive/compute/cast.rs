@@ -1,44 +1,72 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::NativePType;
 use vortex_dtype::{match_each_native_ptype, DType};
-use vortex_dtype::{NativePType, PType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
+use crate::array::bool::BoolArray;
 use crate::array::primitive::PrimitiveArray;
+use crate::array::varbin::VarBinArray;
 use crate::compute::cast::CastFn;
-use crate::validity::Validity;
-use crate::ArrayDType;
+use crate::validity::ArrayValidity;
+use crate::{ArrayDType, ArrayTrait};
 use crate::{IntoArray, OwnedArray};
 
 impl CastFn for PrimitiveArray<'_> {
     fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
-        let ptype = PType::try_from(dtype)?;
+        let validity = self
+            .validity()
+            .cast_nullability(dtype.nullability(), self.len())?;
+        let valid = self.logical_validity().to_present_null_buffer()?;
 
-        // Short-cut if we can just change the nullability
-        if self.ptype() == ptype && !self.dtype().is_nullable() && dtype.is_nullable() {
-            match_each_native_ptype!(self.ptype(), |$T| {
-                return Ok(
-                    PrimitiveArray::try_new(self.scalar_buffer::<$T>(), Validity::AllValid)?
-                        .into_array(),
-                );
-            })
+        match dtype {
+            // Short-cut if we can just change the nullability
+            DType::Primitive(ptype, _) if *ptype == self.ptype() => {
+                match_each_native_ptype!(self.ptype(), |$T| {
+                    Ok(PrimitiveArray::try_new(self.scalar_buffer::<$T>(), validity)?.into_array())
+                })
+            }
+            DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+                Ok(PrimitiveArray::from_vec(
+                    cast::<$T>(self, valid.inner())?,
+                    validity,
+                ).into_array())
+            }),
+            // Numbers are true if they are non-zero.
+            DType::Bool(_) => match_each_native_ptype!(self.ptype(), |$T| {
+                let zero = <$T>::default();
+                let values = self.typed_data::<$T>();
+                let buffer = BooleanBuffer::collect_bool(values.len(), |i| values[i] != zero);
+                BoolArray::try_new(buffer, validity).map(|a| a.into_array())
+            }),
+            DType::Utf8(_) if self.ptype().is_int() => {
+                match_each_native_ptype!(self.ptype(), |$T| {
+                    Ok(VarBinArray::from_iter(
+                        self.typed_data::<$T>()
+                            .iter()
+                            .zip(valid.iter())
+                            .map(|(v, is_valid)| is_valid.then(|| v.to_string())),
+                        dtype.clone(),
+                    )
+                    .into_array())
+                })
+            }
+            _ => vortex_bail!(MismatchedTypes: self.dtype(), dtype),
         }
-
-        // FIXME(ngates): #260 - check validity and nullability
-        match_each_native_ptype!(ptype, |$T| {
-            Ok(PrimitiveArray::from_vec(
-                cast::<$T>(self)?,
-                self.validity().clone(),
-            ).into_array())
-        })
     }
 }
 
-fn cast<T: NativePType>(array: &PrimitiveArray) -> VortexResult<Vec<T>> {
+/// Cast the valid values of the array, leaving the default value at null indices.
+fn cast<T: NativePType>(array: &PrimitiveArray, valid: &BooleanBuffer) -> VortexResult<Vec<T>> {
     match_each_native_ptype!(array.ptype(), |$E| {
         array
             .typed_data::<$E>()
             .iter()
+            .zip(valid.iter())
             // TODO(ngates): allow configurable checked/unchecked casting
-            .map(|&v| {
+            .map(|(&v, is_valid)| {
+                if !is_valid {
+                    return Ok(T::default());
+                }
                 T::from(v).ok_or_else(|| {
                     vortex_err!(ComputeError: "Failed to cast {} to {:?}", v, T::PTYPE)
                 })
@@ -49,10 +77,12 @@ fn cast<T: NativePType>(array: &PrimitiveArray) -> VortexResult<Vec<T>> {
 
 #[cfg(test)]
 mod test {
-    use vortex_dtype::PType;
+    use vortex_dtype::{DType, Nullability, PType};
     use vortex_error::VortexError;
 
-    use crate::{compute, IntoArray};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{compute, ArrayDType, IntoArray};
 
     #[test]
     fn cast_u32_u8() {
@@ -81,4 +111,37 @@ mod test {
         };
         assert_eq!(s.to_string(), "Failed to cast -1 to U32");
     }
+
+    #[test]
+    fn cast_nullability() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![Some(-1i32), None]).into_array();
+        // Invalid values are never cast, so the null slot can't fail the cast.
+        let wide = compute::cast::cast(&arr, &DType::Primitive(PType::I64, Nullability::Nullable))
+            .unwrap();
+        assert!(!wide.with_dyn(|a| a.is_valid(1)));
+        assert!(compute::cast::cast(
+            &arr,
+            &DType::Primitive(PType::I32, Nullability::NonNullable)
+        )
+        .is_err());
+
+        let arr = vec![0u8, 7].into_array();
+        let nullable =
+            compute::cast::cast(&arr, &DType::Primitive(PType::U8, Nullability::Nullable)).unwrap();
+        assert!(nullable.dtype().is_nullable());
+        let bools = compute::cast::cast(&arr, &DType::Bool(Nullability::NonNullable))
+            .unwrap()
+            .flatten_bool()
+            .unwrap();
+        assert_eq!(
+            bools.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, true]
+        );
+        let strings = compute::cast::cast(&arr, &DType::Utf8(Nullability::NonNullable)).unwrap();
+        assert_eq!(
+            String::try_from(scalar_at(&strings, 1).unwrap()).unwrap(),
+            "7"
+        );
+        assert!(compute::cast::cast(&arr, &DType::Binary(Nullability::NonNullable)).is_err());
+    }
 }
@@ -0,0 +1,106 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::compare::{CompareFn, Operator};
+use crate::{IntoArray, OwnedArray};
+
+impl CompareFn for PrimitiveArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let value = <$T>::try_from(other)?;
+            let predicate = operator.to_predicate::<$T>();
+            let values = self.typed_data::<$T>();
+            let buffer = BooleanBuffer::collect_bool(values.len(), |i| predicate(&values[i], &value));
+            BoolArray::try_new(buffer, self.validity()).map(|a| a.into_array())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::compare::{compare, Operator};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::IntoArray;
+
+    fn compare_values(operator: Operator) -> Vec<bool> {
+        let array = PrimitiveArray::from(vec![1u32, 5, 3, 7]).into_array();
+        // The scalar is cast to the dtype of the array.
+        let result = compare(&array, &3i64.into(), operator).unwrap();
+        BoolArray::try_from(result)
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn compare_operators() {
+        assert_eq!(compare_values(Operator::Eq), [false, false, true, false]);
+        assert_eq!(compare_values(Operator::NotEq), [true, true, false, true]);
+        assert_eq!(compare_values(Operator::Gt), [false, true, false, true]);
+        assert_eq!(compare_values(Operator::Gte), [false, true, true, true]);
+        assert_eq!(compare_values(Operator::Lt), [true, false, false, false]);
+        assert_eq!(compare_values(Operator::Lte), [true, false, true, false]);
+    }
+
+    fn compare_u8(other: Scalar, operator: Operator) -> Vec<bool> {
+        let array = PrimitiveArray::from(vec![0u8, 44, 255]).into_array();
+        BoolArray::try_from(compare(&array, &other, operator).unwrap())
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn compare_out_of_range() {
+        // 300 would wrap to 44 if it was cast to u8.
+        assert_eq!(compare_u8(300i32.into(), Operator::Eq), [false; 3]);
+        assert_eq!(compare_u8(300i32.into(), Operator::Lt), [true; 3]);
+        assert_eq!(compare_u8((-1i64).into(), Operator::Gt), [true; 3]);
+        assert_eq!(compare_u8((-1i64).into(), Operator::NotEq), [true; 3]);
+        assert_eq!(compare_u8(f64::INFINITY.into(), Operator::Gte), [false; 3]);
+
+        let array = PrimitiveArray::from(vec![0u32, u32::MAX]).into_array();
+        let result = compare(&array, &(-1i64).into(), Operator::Eq).unwrap();
+        assert_eq!(
+            BoolArray::try_from(result)
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn compare_fractional() {
+        assert_eq!(compare_u8(44.5f64.into(), Operator::Eq), [false; 3]);
+        assert_eq!(compare_u8(44.5f64.into(), Operator::NotEq), [true; 3]);
+        assert_eq!(
+            compare_u8(43.5f64.into(), Operator::Gte),
+            [false, true, true]
+        );
+        assert_eq!(
+            compare_u8(44.5f64.into(), Operator::Lte),
+            [true, true, false]
+        );
+        assert_eq!(compare_u8(f64::NAN.into(), Operator::Lt), [false; 3]);
+    }
+
+    #[test]
+    fn compare_nullable() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1.5f64), None, Some(-2.0)]);
+        let result = compare(&array.into_array(), &0.0f64.into(), Operator::Gt).unwrap();
+        assert!(bool::try_from(scalar_at(&result, 0).unwrap()).unwrap());
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+    }
+}
@@ -0,0 +1,38 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::filter::FilterFn;
+use crate::{IntoArray, OwnedArray};
+
+impl FilterFn for PrimitiveArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        let validity = self.validity();
+        let buffer = mask.boolean_buffer();
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.typed_data::<$T>();
+            Ok(PrimitiveArray::from_vec(
+                buffer.set_indices().map(|idx| values[idx]).collect(),
+                validity.filter(mask)?,
+            )
+            .into_array())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::IntoArray;
+
+    #[test]
+    fn filter_primitive() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array();
+        let mask = BoolArray::from(vec![true, false, false, true, true]);
+        let filtered = filter(&array, &mask).unwrap().flatten_primitive().unwrap();
+        assert_eq!(filtered.typed_data::<i32>(), [1, 4, 5]);
+    }
+}
@@ -0,0 +1,22 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::AsBytes;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::hash::{hash_bytes, hash_valid, HashFn};
+use crate::validity::ArrayValidity;
+use crate::{ArrayTrait, OwnedArray};
+
+impl HashFn for PrimitiveArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.typed_data::<$T>();
+            Ok(hash_valid(
+                self.len(),
+                |i| validity.as_ref().map_or(true, |v| v.is_valid(i)),
+                |i| hash_bytes(values[i].as_bytes()),
+            ))
+        })
+    }
+}
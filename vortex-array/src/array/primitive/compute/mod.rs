@@ -1,26 +1,52 @@
 use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::{CountDistinctFn, MinMaxFn, SumFn};
+use crate::compute::arithmetic::{ArithmeticFn, ArithmeticScalarFn};
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::AsContiguousFn;
 use crate::compute::cast::CastFn;
+use crate::compute::compare::CompareFn;
 use crate::compute::fill::FillForwardFn;
+use crate::compute::filter::FilterFn;
+use crate::compute::hash::HashFn;
+use crate::compute::null::FillNullFn;
 use crate::compute::scalar_at::ScalarAtFn;
-use crate::compute::scalar_subtract::SubtractScalarFn;
 use crate::compute::search_sorted::SearchSortedFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::ArgSortFn;
 use crate::compute::take::TakeFn;
+use crate::compute::unique::ValueCountsFn;
 use crate::compute::ArrayCompute;
 
+mod aggregate;
+mod arithmetic;
 mod as_arrow;
 mod as_contiguous;
 mod cast;
+mod compare;
 mod fill;
+mod filter;
+mod hash;
+mod null;
 mod scalar_at;
 mod search_sorted;
 mod slice;
-mod subtract_scalar;
+mod sort;
 mod take;
+mod unique;
 
 impl ArrayCompute for PrimitiveArray<'_> {
+    fn arithmetic(&self) -> Option<&dyn ArithmeticFn> {
+        Some(self)
+    }
+
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        Some(self)
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -33,15 +59,35 @@ impl ArrayCompute for PrimitiveArray<'_> {
         Some(self)
     }
 
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        Some(self)
+    }
+
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         Some(self)
     }
 
-    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
@@ -53,7 +99,15 @@ impl ArrayCompute for PrimitiveArray<'_> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
 }
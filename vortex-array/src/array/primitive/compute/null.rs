@@ -0,0 +1,24 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::null::FillNullFn;
+use crate::validity::ArrayValidity;
+use crate::{IntoArray, OwnedArray};
+
+impl FillNullFn for PrimitiveArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        let valid = self.logical_validity().to_present_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let fill = <$T>::try_from(fill_value)?;
+            Ok(self
+                .typed_data::<$T>()
+                .iter()
+                .zip(valid.iter())
+                .map(|(v, is_valid)| if is_valid { *v } else { fill })
+                .collect::<Vec<_>>()
+                .into_array())
+        })
+    }
+}
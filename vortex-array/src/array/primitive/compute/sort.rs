@@ -0,0 +1,22 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::sort::{argsort_by, total_cmp, ArgSortFn, SortOptions};
+use crate::validity::ArrayValidity;
+use crate::{ArrayTrait, OwnedArray};
+
+impl ArgSortFn for PrimitiveArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        let validity = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.typed_data::<$T>();
+            Ok(argsort_by(
+                self.len(),
+                options,
+                |i| validity.as_ref().map_or(true, |v| v.is_valid(i)),
+                |a, b| total_cmp(&values[a], &values[b]),
+            ))
+        })
+    }
+}
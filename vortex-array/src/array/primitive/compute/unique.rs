@@ -0,0 +1,15 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::unique::{dict_encode_typed_primitive, dict_value_counts, ValueCountsFn};
+use crate::{IntoArray, OwnedArray};
+
+impl ValueCountsFn for PrimitiveArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let (codes, values) = match_each_native_ptype!(self.ptype(), |$T| {
+            dict_encode_typed_primitive::<$T>(self)
+        });
+        dict_value_counts(&codes.into_array(), &values.into_array())
+    }
+}
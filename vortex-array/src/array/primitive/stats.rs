@@ -72,14 +72,9 @@ impl<'a, T: PStatsType> ArrayStatisticsCompute for NullableValues<'a, T> {
             return Ok(StatsSet::new());
         }
 
-        let first_non_null_idx = self
-            .1
-            .iter()
-            .enumerate()
-            .skip_while(|(_, valid)| !*valid)
-            .map(|(idx, _)| idx)
-            .next()
-            .expect("Must be at least one non-null value");
+        let Some(first_non_null_idx) = self.1.iter().position(|valid| valid) else {
+            return all_null_stats::<T>(values.len());
+        };
 
         let mut stats = StatsAccumulator::new_with_leading_nulls(
             values[first_non_null_idx],
@@ -1,19 +1,27 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
-use vortex_dtype::match_each_integer_ptype;
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
+use crate::array::bool::BoolArray;
 use crate::array::primitive::{OwnedPrimitiveArray, PrimitiveArray};
 use crate::array::sparse::SparseArray;
+use crate::compute::aggregate::{
+    merge_min_max, merge_sums, min_max, sum, sum_repeated, MinMaxFn, SumFn,
+};
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::{cast, CastFn};
+use crate::compute::filter::FilterFn;
+use crate::compute::null::{FillNullFn, IsNullFn};
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::slice::SliceFn;
 use crate::compute::take::{take, TakeFn};
 use crate::compute::ArrayCompute;
-use crate::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayData, OwnedArray};
 
+mod null;
 mod slice;
 
 impl ArrayCompute for SparseArray<'_> {
@@ -21,6 +29,26 @@ impl ArrayCompute for SparseArray<'_> {
         Some(self)
     }
 
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -29,6 +57,10 @@ impl ArrayCompute for SparseArray<'_> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -55,6 +87,29 @@ impl AsContiguousFn for SparseArray<'_> {
     }
 }
 
+impl CastFn for SparseArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        SparseArray::try_new_with_offset(
+            self.indices(),
+            cast(&self.values(), dtype)?,
+            self.len(),
+            self.indices_offset(),
+            self.fill_value().cast(dtype)?,
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl MinMaxFn for SparseArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        let mut parts = vec![min_max(&self.values())?];
+        if self.len() > self.values().len() {
+            parts.push((self.fill_value().clone(), self.fill_value().clone()));
+        }
+        merge_min_max(PType::try_from(self.dtype())?, parts)
+    }
+}
+
 impl ScalarAtFn for SparseArray<'_> {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         match self.find_index(index)? {
@@ -64,6 +119,20 @@ impl ScalarAtFn for SparseArray<'_> {
     }
 }
 
+impl SumFn for SparseArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        // Every position without a patch holds the fill value.
+        let fill_count = self.len() - self.values().len();
+        merge_sums(
+            PType::try_from(self.dtype())?,
+            [
+                sum(&self.values())?,
+                sum_repeated(self.fill_value(), fill_count)?,
+            ],
+        )
+    }
+}
+
 impl TakeFn for SparseArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         let flat_indices = indices.clone().flatten_primitive()?;
@@ -86,6 +155,38 @@ impl TakeFn for SparseArray<'_> {
     }
 }
 
+impl FilterFn for SparseArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        let mask = mask.boolean_buffer();
+
+        // Only the patches that are selected are kept, shifted down by the number of values
+        // that were filtered out before them.
+        let mut positions = Vec::new();
+        let mut patch_indices = Vec::new();
+        let mut prev_index = 0;
+        let mut selected_before = 0;
+        for (patch_idx, index) in self.resolved_indices().into_iter().enumerate() {
+            selected_before += mask.slice(prev_index, index - prev_index).count_set_bits();
+            prev_index = index;
+            if mask.value(index) {
+                positions.push(selected_before as u64);
+                patch_indices.push(patch_idx as u64);
+            }
+        }
+
+        Ok(SparseArray::new(
+            PrimitiveArray::from(positions).into_array(),
+            take(
+                &self.values(),
+                &PrimitiveArray::from(patch_indices).into_array(),
+            )?,
+            mask.count_set_bits(),
+            self.fill_value().clone(),
+        )
+        .into_array())
+    }
+}
+
 fn take_map(
     array: &SparseArray,
     indices: &PrimitiveArray,
@@ -141,10 +242,13 @@ mod test {
     use vortex_dtype::{DType, Nullability, PType};
     use vortex_scalar::Scalar;
 
+    use crate::array::bool::BoolArray;
     use crate::array::primitive::PrimitiveArray;
     use crate::array::sparse::compute::take_map;
     use crate::array::sparse::SparseArray;
+    use crate::compute::aggregate::{min_max, sum};
     use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::filter::filter;
     use crate::compute::slice::slice;
     use crate::compute::take::take;
     use crate::validity::Validity;
@@ -177,6 +281,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn sparse_filter() {
+        let mask = BoolArray::from((0..100).map(|i| i % 2 == 1).collect::<Vec<_>>());
+        let filtered = SparseArray::try_from(filter(&sparse_array(), &mask).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 50);
+        assert_eq!(
+            filtered.indices().into_primitive().typed_data::<u64>(),
+            [18, 23, 49]
+        );
+        assert_eq!(
+            filtered.values().into_primitive().typed_data::<f64>(),
+            [0.47, 9.99, 3.5]
+        );
+    }
+
     #[test]
     fn nonexistent_take() {
         let sparse = sparse_array();
@@ -250,4 +369,30 @@ mod test {
         );
         assert_eq!(patch_indices.typed_data::<u64>(), [0u64, 1, 2, 3]);
     }
+
+    #[test]
+    fn sparse_aggregates() {
+        let sparse = sparse_array();
+        assert_eq!(
+            sum(&sparse).unwrap(),
+            Some(1.23f64 + 0.47 + 9.99 + 3.5).into()
+        );
+        assert_eq!(
+            min_max(&sparse).unwrap(),
+            (Some(0.47f64).into(), Some(9.99f64).into())
+        );
+
+        let filled = SparseArray::new(
+            PrimitiveArray::from(vec![2u64]).into_array(),
+            PrimitiveArray::from(vec![-4i32]).into_array(),
+            5,
+            7i32.into(),
+        )
+        .into_array();
+        assert_eq!(sum(&filled).unwrap(), Some(24i64).into());
+        assert_eq!(
+            min_max(&filled).unwrap(),
+            (Some(-4i32).into(), Some(7i32).into())
+        );
+    }
 }
@@ -0,0 +1,41 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::sparse::SparseArray;
+use crate::compute::null::{fill_null, is_null, FillNullFn, IsNullFn};
+use crate::{ArrayTrait, IntoArray, IntoArrayData, OwnedArray};
+
+impl IsNullFn for SparseArray<'_> {
+    fn is_null(&self) -> VortexResult<OwnedArray> {
+        SparseArray::try_new_with_offset(
+            self.indices(),
+            is_null(&self.values())?,
+            self.len(),
+            self.indices_offset(),
+            self.fill_value().is_null().into(),
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl FillNullFn for SparseArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        if !self.fill_value().is_null() {
+            // Every value at an unpatched index is valid, so only the patches need filling. But
+            // the fill value must become non-nullable, so fill the flattened array instead.
+            return fill_null(
+                &self.clone().into_array().flatten()?.into_array(),
+                fill_value,
+            );
+        }
+
+        SparseArray::try_new_with_offset(
+            self.indices(),
+            fill_null(&self.values(), fill_value)?,
+            self.len(),
+            self.indices_offset(),
+            fill_value.clone(),
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
@@ -1,13 +1,14 @@
-use arrow_buffer::BooleanBufferBuilder;
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
 use itertools::Itertools;
-use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
 use vortex_error::{VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
+use crate::array::bool::BoolArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::sparse::SparseArray;
 use crate::validity::Validity;
-use crate::{ArrayFlatten, ArrayTrait, Flattened};
+use crate::{ArrayDType, ArrayFlatten, ArrayTrait, Flattened};
 
 impl ArrayFlatten for SparseArray<'_> {
     fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
@@ -17,9 +18,28 @@ impl ArrayFlatten for SparseArray<'_> {
         // Resolve our indices into a vector of usize applying the offset
         let indices = self.resolved_indices();
 
+        // Unpatched values are valid if the fill value is, patched values if the patch is
         let mut validity = BooleanBufferBuilder::new(self.len());
-        validity.append_n(self.len(), false);
-        let values = self.values().flatten_primitive()?;
+        validity.append_n(self.len(), !self.fill_value().is_null());
+        let values = self.values();
+        let values_validity = values
+            .with_dyn(|a| a.logical_validity())
+            .to_present_null_buffer()?;
+        for (idx, valid) in indices.iter().zip_eq(values_validity.iter()) {
+            validity.set_bit(*idx, valid);
+        }
+        let validity = if self.dtype().is_nullable() {
+            Validity::from(validity.finish())
+        } else {
+            Validity::NonNullable
+        };
+
+        if matches!(self.dtype(), DType::Bool(_)) {
+            let values = values.flatten_bool()?.boolean_buffer();
+            return flatten_sparse_bools(values, &indices, self.len(), self.fill_value(), validity);
+        }
+
+        let values = values.flatten_primitive()?;
         match_each_native_ptype!(values.ptype(), |$P| {
             flatten_sparse_values(
                 values.typed_data::<$P>(),
@@ -32,12 +52,33 @@ impl ArrayFlatten for SparseArray<'_> {
     }
 }
 
+fn flatten_sparse_bools(
+    values: BooleanBuffer,
+    indices: &[usize],
+    len: usize,
+    fill_value: &Scalar,
+    validity: Validity,
+) -> VortexResult<Flattened<'static>> {
+    let fill = if fill_value.is_null() {
+        false
+    } else {
+        fill_value.try_into()?
+    };
+    let mut result = BooleanBufferBuilder::new(len);
+    result.append_n(len, fill);
+    for (v, idx) in values.iter().zip_eq(indices) {
+        result.set_bit(*idx, v);
+    }
+
+    BoolArray::try_new(result.finish(), validity).map(Flattened::Bool)
+}
+
 fn flatten_sparse_values<T: NativePType + for<'a> TryFrom<&'a Scalar, Error = VortexError>>(
     values: &[T],
     indices: &[usize],
     len: usize,
     fill_value: &Scalar,
-    mut validity: BooleanBufferBuilder,
+    validity: Validity,
 ) -> VortexResult<Flattened<'static>> {
     let primitive_fill = if fill_value.is_null() {
         T::default()
@@ -48,14 +89,9 @@ fn flatten_sparse_values<T: NativePType + for<'a> TryFrom<&'a Scalar, Error = Vo
 
     for (v, idx) in values.iter().zip_eq(indices) {
         result[*idx] = *v;
-        validity.set_bit(*idx, true);
     }
 
-    let validity = validity.finish();
-    let array = if fill_value.is_null() {
-        PrimitiveArray::from_vec(result, Validity::from(validity))
-    } else {
-        PrimitiveArray::from(result)
-    };
-    Ok(Flattened::Primitive(array))
+    Ok(Flattened::Primitive(PrimitiveArray::from_vec(
+        result, validity,
+    )))
 }
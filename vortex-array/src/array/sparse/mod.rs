@@ -3,7 +3,6 @@ use vortex_dtype::match_each_integer_ptype;
 use vortex_error::vortex_bail;
 use vortex_scalar::Scalar;
 
-use crate::array::constant::ConstantArray;
 use crate::compute::search_sorted::{search_sorted, SearchSortedSide};
 use crate::stats::ArrayStatisticsCompute;
 use crate::validity::{ArrayValidity, LogicalValidity};
@@ -143,28 +142,16 @@ impl ArrayValidity for SparseArray<'_> {
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        let validity = if self.fill_value().is_null() {
-            // If we have a null fill value, then the result is a Sparse array with a fill_value
-            // of true, and patch values of false.
-            SparseArray::try_new_with_offset(
-                self.indices(),
-                ConstantArray::new(false, self.len()).into_array(),
-                self.len(),
-                self.indices_offset(),
-                true.into(),
-            )
-        } else {
-            // If the fill_value is non-null, then the validity is based on the validity of the
-            // existing values.
-            SparseArray::try_new_with_offset(
-                self.indices(),
-                self.values()
-                    .with_dyn(|a| a.logical_validity().into_array()),
-                self.len(),
-                self.indices_offset(),
-                true.into(),
-            )
-        }
+        // The patched values are valid if the patches are, and all other values if the fill
+        // value is non-null.
+        let validity = SparseArray::try_new_with_offset(
+            self.indices(),
+            self.values()
+                .with_dyn(|a| a.logical_validity().into_array()),
+            self.len(),
+            self.indices_offset(),
+            (!self.fill_value().is_null()).into(),
+        )
         .unwrap();
 
         LogicalValidity::Array(validity.into_array_data())
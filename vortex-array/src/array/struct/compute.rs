@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use arrow_array::{
@@ -8,18 +9,25 @@ use itertools::Itertools;
 use vortex_error::VortexResult;
 use vortex_scalar::{Scalar, StructScalar};
 
+use crate::array::primitive::PrimitiveArray;
 use crate::array::r#struct::StructArray;
 use crate::compute::as_arrow::{as_arrow, AsArrowArray};
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::hash::{combine_hashes, hash, HashFn, NULL_HASH};
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::slice::{slice, SliceFn};
+use crate::compute::sort::{argsort_by, lexsort, ArgSortFn, SortOptions};
 use crate::compute::take::{take, TakeFn};
 use crate::compute::ArrayCompute;
-use crate::validity::Validity;
+use crate::validity::{ArrayValidity, Validity};
 use crate::ArrayTrait;
 use crate::{Array, ArrayDType, IntoArray, OwnedArray};
 
 impl ArrayCompute for StructArray<'_> {
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -28,6 +36,10 @@ impl ArrayCompute for StructArray<'_> {
         Some(self)
     }
 
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -41,6 +53,41 @@ impl ArrayCompute for StructArray<'_> {
     }
 }
 
+impl ArgSortFn for StructArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        let fields = self.children().collect_vec();
+        let validity = self.logical_validity().to_null_buffer()?;
+        let is_valid = |i| validity.as_ref().map_or(true, |v| v.is_valid(i));
+        if fields.is_empty() {
+            return Ok(argsort_by(self.len(), options, is_valid, |_, _| {
+                Ordering::Equal
+            }));
+        }
+
+        let indices = lexsort(&fields, &vec![options; fields.len()])?;
+        if validity.is_none() {
+            return Ok(indices);
+        }
+
+        // Order the valid rows by their rank amongst the fields, and the null rows around them.
+        let mut ranks = vec![0; self.len()];
+        for (rank, &index) in indices
+            .flatten_primitive()?
+            .typed_data::<u64>()
+            .iter()
+            .enumerate()
+        {
+            ranks[index as usize] = rank;
+        }
+        Ok(argsort_by(
+            self.len(),
+            options.with_descending(false),
+            is_valid,
+            |a, b| ranks[a].cmp(&ranks[b]),
+        ))
+    }
+}
+
 impl AsArrowArray for StructArray<'_> {
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
         let field_arrays: Vec<ArrowArrayRef> =
@@ -101,6 +148,26 @@ impl AsContiguousFn for StructArray<'_> {
     }
 }
 
+impl HashFn for StructArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        let mut hashes = vec![NULL_HASH; self.len()];
+        for field in self.children() {
+            let field_hashes = hash(&field)?.flatten_primitive()?;
+            for (h, &field_hash) in hashes.iter_mut().zip(field_hashes.typed_data::<u64>()) {
+                *h = combine_hashes(*h, field_hash);
+            }
+        }
+        if let Some(validity) = self.logical_validity().to_null_buffer()? {
+            for (h, valid) in hashes.iter_mut().zip(validity.iter()) {
+                if !valid {
+                    *h = NULL_HASH;
+                }
+            }
+        }
+        Ok(PrimitiveArray::from(hashes).into_array())
+    }
+}
+
 impl ScalarAtFn for StructArray<'_> {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(StructScalar::new(
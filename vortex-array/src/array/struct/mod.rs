@@ -126,12 +126,12 @@ impl ArrayTrait for StructArray<'_> {
 }
 
 impl ArrayValidity for StructArray<'_> {
-    fn is_valid(&self, _index: usize) -> bool {
-        todo!()
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        todo!()
+        self.validity().to_logical(self.len())
     }
 }
 
@@ -0,0 +1,23 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::varbin::VarBinArray;
+use crate::compute::cast::{check_utf8, CastFn};
+use crate::{ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+
+impl CastFn for VarBinArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        if !matches!(dtype, DType::Utf8(_) | DType::Binary(_)) {
+            vortex_bail!(MismatchedTypes: self.dtype(), dtype);
+        }
+        if matches!(dtype, DType::Utf8(_)) && !matches!(self.dtype(), DType::Utf8(_)) {
+            check_utf8(self)?;
+        }
+
+        let validity = self
+            .validity()
+            .cast_nullability(dtype.nullability(), self.len())?;
+        VarBinArray::try_new(self.offsets(), self.bytes(), dtype.clone(), validity)
+            .map(|a| a.into_array())
+    }
+}
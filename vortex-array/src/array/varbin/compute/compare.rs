@@ -0,0 +1,39 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::varbin::VarBinArray;
+use crate::compute::compare::{compare_accessor, CompareFn, Operator};
+use crate::OwnedArray;
+
+impl CompareFn for VarBinArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        compare_accessor(self, other, operator, self.validity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::bool::BoolArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::compare::{compare, Operator};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::IntoArray;
+
+    #[test]
+    fn compare_strings() {
+        let array = VarBinArray::from_iter(
+            vec![Some("apple"), None, Some("cherry"), Some("banana")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let result =
+            BoolArray::try_from(compare(&array, &"banana".into(), Operator::Gte).unwrap()).unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+        assert!(scalar_at(result.array(), 1).unwrap().is_null());
+    }
+}
@@ -14,18 +14,32 @@ use crate::array::varbin::{varbin_scalar, VarBinArray};
 use crate::arrow::wrappers::as_offset_buffer;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
-use crate::compute::cast::cast;
+use crate::compute::cast::{cast, CastFn};
+use crate::compute::compare::CompareFn;
+use crate::compute::hash::{hash_accessor, HashFn};
+use crate::compute::null::FillNullFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::{argsort_bytes, ArgSortFn, SortOptions};
+use crate::compute::string::{StringLengthFn, StringMatchFn, StringTransformFn};
 use crate::compute::take::TakeFn;
+use crate::compute::unique::{dict_encode_varbin, dict_value_counts, ValueCountsFn};
 use crate::compute::ArrayCompute;
 use crate::validity::{ArrayValidity, Validity};
 use crate::{Array, ArrayDType, IntoArray, OwnedArray, ToArray};
 
+mod cast;
+mod compare;
+mod null;
 mod slice;
+mod string;
 mod take;
 
 impl ArrayCompute for VarBinArray<'_> {
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -34,6 +48,22 @@ impl ArrayCompute for VarBinArray<'_> {
         Some(self)
     }
 
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -42,9 +72,25 @@ impl ArrayCompute for VarBinArray<'_> {
         Some(self)
     }
 
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn string_transform(&self) -> Option<&dyn StringTransformFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
 }
 
 impl AsContiguousFn for VarBinArray<'_> {
@@ -83,6 +129,12 @@ impl AsContiguousFn for VarBinArray<'_> {
     }
 }
 
+impl ArgSortFn for VarBinArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        argsort_bytes(self, options)
+    }
+}
+
 impl AsArrowArray for VarBinArray<'_> {
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
         // Ensure the offsets are either i32 or i64
@@ -148,3 +200,16 @@ impl ScalarAtFn for VarBinArray<'_> {
         }
     }
 }
+
+impl HashFn for VarBinArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        hash_accessor(self)
+    }
+}
+
+impl ValueCountsFn for VarBinArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let (codes, values) = dict_encode_varbin(self);
+        dict_value_counts(&codes.into_array(), &values.into_array())
+    }
+}
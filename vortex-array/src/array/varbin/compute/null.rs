@@ -0,0 +1,20 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::varbin::{varbin_scalar_bytes, VarBinArray};
+use crate::compute::null::FillNullFn;
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl FillNullFn for VarBinArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        let fill = varbin_scalar_bytes(fill_value)?;
+        self.with_iterator(|iter| {
+            VarBinArray::from_iter(
+                iter.map(|v| Some(v.unwrap_or(&fill))),
+                self.dtype().as_nonnullable(),
+            )
+            .into_array()
+        })
+    }
+}
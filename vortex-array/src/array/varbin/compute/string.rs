@@ -0,0 +1,33 @@
+use vortex_error::VortexResult;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::varbin::VarBinArray;
+use crate::compute::string::{
+    string_length_accessor, string_match_accessor, StringLengthFn, StringMatchFn, StringPredicate,
+    StringTransform, StringTransformFn,
+};
+use crate::{ArrayDType, IntoArray, OwnedArray};
+
+impl StringMatchFn for VarBinArray<'_> {
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+        string_match_accessor(self, predicate, self.validity())
+    }
+}
+
+impl StringLengthFn for VarBinArray<'_> {
+    fn string_length(&self) -> VortexResult<OwnedArray> {
+        string_length_accessor(self, self.dtype(), self.validity())
+    }
+}
+
+impl StringTransformFn for VarBinArray<'_> {
+    fn string_transform(&self, transform: &StringTransform) -> VortexResult<OwnedArray> {
+        self.with_iterator(|iter| {
+            VarBinArray::from_iter(
+                iter.map(|v| v.map(|v| transform.apply(v))),
+                self.dtype().clone(),
+            )
+            .into_array()
+        })
+    }
+}
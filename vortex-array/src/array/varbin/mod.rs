@@ -217,6 +217,15 @@ pub fn varbin_scalar(value: Vec<u8>, dtype: &DType) -> Scalar {
     }
 }
 
+/// The bytes of a non-null utf8 or binary scalar.
+pub fn varbin_scalar_bytes(scalar: &Scalar) -> VortexResult<Vec<u8>> {
+    match scalar {
+        Scalar::Utf8(_) => Ok(String::try_from(scalar)?.into_bytes()),
+        Scalar::Binary(_) => Vec::<u8>::try_from(scalar),
+        _ => vortex_bail!("Expected a utf8 or binary scalar, got {}", scalar.dtype()),
+    }
+}
+
 impl EncodingCompression for VarBinEncoding {}
 
 #[cfg(test)]
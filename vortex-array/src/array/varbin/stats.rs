@@ -97,7 +97,10 @@ impl<'a> VarBinAccumulator<'a> {
         }
 
         match val.cmp(self.last_value) {
-            Ordering::Less => self.is_sorted = false,
+            Ordering::Less => {
+                self.is_sorted = false;
+                self.is_strict_sorted = false;
+            }
             Ordering::Equal => {
                 self.is_strict_sorted = false;
                 return;
@@ -168,6 +171,13 @@ mod test {
         assert!(arr.statistics().compute_is_sorted().unwrap());
     }
 
+    #[test]
+    fn unsorted_stats() {
+        let arr = VarBinArray::from_vec(vec!["b", "a", "c"], DType::Utf8(Nullability::NonNullable));
+        assert!(!arr.statistics().compute_is_sorted().unwrap());
+        assert!(!arr.statistics().compute_is_strict_sorted().unwrap());
+    }
+
     #[test]
     fn some_nulls() {
         let array = VarBinArray::from_iter(
@@ -1,7 +1,6 @@
 use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
-use crate::array::primitive::PrimitiveArray;
 use crate::array::varbinview::VarBinViewArray;
 use crate::validity::ArrayValidity;
 
@@ -11,40 +10,19 @@ impl ArrayAccessor<[u8]> for VarBinViewArray<'_> {
         f: F,
     ) -> VortexResult<R> {
         let views = self.view_slice();
-        let bytes: Vec<PrimitiveArray> = (0..self.metadata().n_children)
-            .map(|i| self.bytes(i).flatten_primitive())
-            .collect::<VortexResult<Vec<_>>>()?;
+        let bytes = self.flatten_buffers()?;
         let validity = self.logical_validity().to_null_buffer()?;
 
         match validity {
             None => {
-                let mut iter = views.iter().map(|view| {
-                    if view.is_inlined() {
-                        Some(unsafe { &view.inlined.data as &[u8] })
-                    } else {
-                        let offset = unsafe { view._ref.offset as usize };
-                        let buffer_idx = unsafe { view._ref.buffer_index as usize };
-                        Some(&bytes[buffer_idx].typed_data::<u8>()[offset..offset + view.size()])
-                    }
-                });
+                let mut iter = views.iter().map(|view| Some(view.value(&bytes)));
                 Ok(f(&mut iter))
             }
             Some(validity) => {
-                let mut iter = views.iter().zip(validity.iter()).map(|(view, valid)| {
-                    if valid {
-                        if view.is_inlined() {
-                            Some(unsafe { &view.inlined.data as &[u8] })
-                        } else {
-                            let offset = unsafe { view._ref.offset as usize };
-                            let buffer_idx = unsafe { view._ref.buffer_index as usize };
-                            Some(
-                                &bytes[buffer_idx].typed_data::<u8>()[offset..offset + view.size()],
-                            )
-                        }
-                    } else {
-                        None
-                    }
-                });
+                let mut iter = views
+                    .iter()
+                    .zip(validity.iter())
+                    .map(|(view, valid)| valid.then(|| view.value(&bytes)));
                 Ok(f(&mut iter))
             }
         }
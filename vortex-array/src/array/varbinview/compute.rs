@@ -1,28 +1,63 @@
 use std::sync::Arc;
 
 use arrow_array::{ArrayRef as ArrowArrayRef, BinaryViewArray, StringViewArray};
-use arrow_buffer::Buffer as ArrowBuffer;
 use arrow_buffer::ScalarBuffer;
+use arrow_buffer::{BooleanBuffer, Buffer as ArrowBuffer};
 use itertools::Itertools;
-use vortex_dtype::DType;
+use num_traits::AsPrimitive;
 use vortex_dtype::PType;
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_dtype::{match_each_integer_ptype, DType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::array::varbin::varbin_scalar;
-use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE};
+use crate::accessor::ArrayAccessor;
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::array::varbin::{varbin_scalar, varbin_scalar_bytes};
+use crate::array::varbinview::{BinaryView, VarBinViewArray, VIEW_SIZE};
 use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::{check_utf8, CastFn};
+use crate::compute::compare::{compare_accessor, CompareFn, Operator};
+use crate::compute::hash::{hash_accessor, HashFn};
+use crate::compute::null::FillNullFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::{slice, SliceFn};
+use crate::compute::sort::{argsort_bytes, ArgSortFn, SortOptions};
+use crate::compute::string::{
+    string_length_accessor, string_match_accessor, StringLengthFn, StringMatchFn, StringPredicate,
+    StringTransform, StringTransformFn,
+};
+use crate::compute::take::TakeFn;
+use crate::compute::unique::{dict_encode_varbinview, dict_value_counts, ValueCountsFn};
 use crate::compute::ArrayCompute;
 use crate::validity::ArrayValidity;
-use crate::{ArrayDType, IntoArray, IntoArrayData, OwnedArray};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayData, OwnedArray};
 
 impl ArrayCompute for VarBinViewArray<'_> {
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
 
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -30,6 +65,32 @@ impl ArrayCompute for VarBinViewArray<'_> {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn string_transform(&self) -> Option<&dyn StringTransformFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
+}
+
+impl ArgSortFn for VarBinViewArray<'_> {
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray> {
+        argsort_bytes(self, options)
+    }
 }
 
 impl ScalarAtFn for VarBinViewArray<'_> {
@@ -95,3 +156,172 @@ impl SliceFn for VarBinViewArray<'_> {
         .into_array())
     }
 }
+
+impl TakeFn for VarBinViewArray<'_> {
+    fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
+        let indices = indices.clone().flatten_primitive()?;
+        self.with_iterator(|iter| {
+            let values = iter.collect_vec();
+            match_each_integer_ptype!(indices.ptype(), |$I| {
+                let taken = indices
+                    .typed_data::<$I>()
+                    .iter()
+                    .map(|&idx| {
+                        let idx: usize = idx.as_();
+                        values
+                            .get(idx)
+                            .copied()
+                            .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, values.len()))
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(VarBinViewArray::from_iter(taken, self.dtype().clone()).into_array())
+            })
+        })?
+    }
+}
+
+impl CastFn for VarBinViewArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        if !matches!(dtype, DType::Utf8(_) | DType::Binary(_)) {
+            vortex_bail!(MismatchedTypes: self.dtype(), dtype);
+        }
+        if matches!(dtype, DType::Utf8(_)) && !matches!(self.dtype(), DType::Utf8(_)) {
+            check_utf8(self)?;
+        }
+
+        let validity = self
+            .validity()
+            .cast_nullability(dtype.nullability(), self.len())?;
+        let data = (0..self.metadata().n_children)
+            .map(|i| self.bytes(i))
+            .collect();
+        VarBinViewArray::try_new(self.views(), data, dtype.clone(), validity)
+            .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl CompareFn for VarBinViewArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        compare_accessor(self, other, operator, self.validity())
+    }
+}
+
+impl FillNullFn for VarBinViewArray<'_> {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+        let fill = varbin_scalar_bytes(fill_value)?;
+        self.with_iterator(|iter| {
+            VarBinViewArray::from_iter(
+                iter.map(|v| Some(v.unwrap_or(&fill))),
+                self.dtype().as_nonnullable(),
+            )
+            .into_array()
+        })
+    }
+}
+
+impl StringMatchFn for VarBinViewArray<'_> {
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+        let StringPredicate::StartsWith(prefix) = predicate else {
+            return string_match_accessor(self, predicate, self.validity());
+        };
+
+        // Most values can be ruled out by the prefix stored in their view, without reading the
+        // data buffers.
+        let bytes = self.flatten_buffers()?;
+        let short = &prefix[..prefix.len().min(BinaryView::PREFIX_SIZE)];
+        let buffer = BooleanBuffer::from_iter(self.view_slice().iter().map(|view| {
+            if view.size() < prefix.len() || !view.prefix().starts_with(short) {
+                false
+            } else if prefix.len() <= BinaryView::PREFIX_SIZE {
+                true
+            } else {
+                view.value(&bytes).starts_with(prefix)
+            }
+        }));
+        BoolArray::try_new(buffer, self.validity()).map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl StringLengthFn for VarBinViewArray<'_> {
+    fn string_length(&self) -> VortexResult<OwnedArray> {
+        if matches!(self.dtype(), DType::Binary(_)) {
+            // The byte length of every value is stored in its view.
+            let lengths = self.view_slice().iter().map(|v| v.size() as u64).collect();
+            return Ok(PrimitiveArray::from_vec(lengths, self.validity())
+                .into_array_data()
+                .into_array());
+        }
+        string_length_accessor(self, self.dtype(), self.validity())
+    }
+}
+
+impl StringTransformFn for VarBinViewArray<'_> {
+    fn string_transform(&self, transform: &StringTransform) -> VortexResult<OwnedArray> {
+        self.with_iterator(|iter| {
+            VarBinViewArray::from_iter(
+                iter.map(|v| v.map(|v| transform.apply(v))),
+                self.dtype().clone(),
+            )
+            .into_array()
+        })
+    }
+}
+
+impl HashFn for VarBinViewArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        hash_accessor(self)
+    }
+}
+
+impl ValueCountsFn for VarBinViewArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        let (codes, values) = dict_encode_varbinview(self)?;
+        dict_value_counts(&codes.into_array(), &values.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::compare::{compare, Operator};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::take::take;
+    use crate::IntoArray;
+
+    #[test]
+    fn compare_views() {
+        let array = VarBinViewArray::from_iter(
+            vec![
+                Some("apple"),
+                None,
+                Some("a much longer cherry"),
+                Some("banana"),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let result =
+            BoolArray::try_from(compare(&array, &"banana".into(), Operator::Gte).unwrap()).unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, false, false, true]
+        );
+        assert!(scalar_at(result.array(), 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn take_out_of_bounds() {
+        let array = VarBinViewArray::from_iter(
+            vec![Some("a"), Some("b")],
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .into_array();
+        let taken = take(&array, &PrimitiveArray::from(vec![1u32, 0]).into_array()).unwrap();
+        assert_eq!(scalar_at(&taken, 0).unwrap(), "b".into());
+        assert!(take(&array, &PrimitiveArray::from(vec![0u32, 2]).into_array()).is_err());
+    }
+}
@@ -71,6 +71,7 @@ pub union BinaryView {
 
 impl BinaryView {
     pub const MAX_INLINED_SIZE: usize = 12;
+    pub const PREFIX_SIZE: usize = 4;
 
     #[inline]
     pub fn size(&self) -> usize {
@@ -80,6 +81,32 @@ impl BinaryView {
     pub fn is_inlined(&self) -> bool {
         unsafe { self.inlined.size <= Self::MAX_INLINED_SIZE as u32 }
     }
+
+    /// The first bytes of the value, up to [`Self::PREFIX_SIZE`] of them, which are stored in
+    /// the view itself.
+    pub fn prefix(&self) -> &[u8] {
+        if self.is_inlined() {
+            unsafe { &self.inlined.data[..self.size().min(Self::PREFIX_SIZE)] }
+        } else {
+            unsafe { &self._ref.prefix }
+        }
+    }
+
+    /// The raw bits of the view, which for inlined values are their size and zero padded bytes.
+    pub(crate) fn as_u128(&self) -> u128 {
+        unsafe { mem::transmute::<BinaryView, u128>(*self) }
+    }
+
+    /// The value of the view, given the flattened data buffers of its array.
+    pub(crate) fn value<'a>(&'a self, buffers: &'a [PrimitiveArray]) -> &'a [u8] {
+        if self.is_inlined() {
+            unsafe { &self.inlined.data[..self.size()] }
+        } else {
+            let offset = unsafe { self._ref.offset as usize };
+            let buffer_idx = unsafe { self._ref.buffer_index as usize };
+            &buffers[buffer_idx].typed_data::<u8>()[offset..offset + self.size()]
+        }
+    }
 }
 
 impl Debug for BinaryView {
@@ -144,7 +171,7 @@ impl VarBinViewArray<'_> {
         Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
     }
 
-    fn view_slice(&self) -> &[BinaryView] {
+    pub(crate) fn view_slice(&self) -> &[BinaryView] {
         unsafe {
             slice::from_raw_parts(
                 PrimitiveArray::try_from(self.views())
@@ -172,6 +199,13 @@ impl VarBinViewArray<'_> {
             .expect("Missing data buffer")
     }
 
+    /// The data buffers of the array, flattened such that views can refer to them.
+    pub(crate) fn flatten_buffers(&self) -> VortexResult<Vec<PrimitiveArray>> {
+        (0..self.metadata().n_children)
+            .map(|i| self.bytes(i).flatten_primitive())
+            .collect()
+    }
+
     pub fn validity(&self) -> Validity {
         self.metadata().validity.to_validity(
             self.array()
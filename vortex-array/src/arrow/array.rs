@@ -1,15 +1,16 @@
 use arrow_array::array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
-    GenericByteArray, NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
-    StructArray as ArrowStructArray,
+    GenericByteArray, GenericListArray, NullArray as ArrowNullArray,
+    PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
 };
 use arrow_array::array::{ArrowPrimitiveType, OffsetSizeTrait};
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Time32MillisecondType,
-    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType, DurationSecondType,
+    Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
 };
 use arrow_array::types::{
     Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
@@ -17,16 +18,19 @@ use arrow_array::types::{
 };
 use arrow_array::{BinaryViewArray, GenericByteViewArray, StringViewArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
-use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
+use arrow_buffer::{i256, ArrowNativeType, Buffer, ScalarBuffer};
 use arrow_schema::{DataType, TimeUnit};
 use itertools::Itertools;
-use vortex_dtype::DType;
 use vortex_dtype::NativePType;
+use vortex_dtype::{DType, DecimalDType};
+use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::NullScalar;
 
 use crate::array::bool::BoolArray;
 use crate::array::constant::ConstantArray;
-use crate::array::datetime::LocalDateTimeArray;
+use crate::array::datetime::{InstantArray, LocalDateTimeArray};
+use crate::array::decimal::DecimalArray;
+use crate::array::list::ListArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::r#struct::StructArray;
 use crate::array::varbin::VarBinArray;
@@ -96,14 +100,17 @@ where
             return arr;
         }
 
-        match T::DATA_TYPE {
+        // The timezone of a timestamp is only held by the data type of the array itself.
+        match value.data_type() {
             DataType::Timestamp(time_unit, tz) => match tz {
                 // A timestamp with no timezone is the equivalent of an "unknown" timezone.
                 // Therefore, we must treat it as a LocalDateTime and not an Instant.
-                None => LocalDateTimeArray::try_new((&time_unit).into(), arr.into_array())
+                None => LocalDateTimeArray::try_new(time_unit.into(), arr.into_array())
                     .expect("Invalid LocalDateTimeArray")
                     .into_array_data(),
-                Some(_tz) => todo!(),
+                Some(tz) => InstantArray::try_new(time_unit.into(), tz.clone(), arr.into_array())
+                    .expect("Invalid InstantArray")
+                    .into_array_data(),
             },
             DataType::Date32 => todo!(),
             DataType::Date64 => todo!(),
@@ -194,6 +201,63 @@ impl FromArrowArray<&ArrowStructArray> for ArrayData {
     }
 }
 
+impl<O: OffsetSizeTrait + NativePType> FromArrowArray<&GenericListArray<O>> for ArrayData {
+    fn from_arrow(value: &GenericListArray<O>, nullable: bool) -> Self {
+        let elements_nullable = match value.data_type() {
+            DataType::List(field) | DataType::LargeList(field) => field.is_nullable(),
+            _ => panic!("Invalid data type for ListArray"),
+        };
+        ListArray::try_new(
+            value.offsets().clone().into_array_data().into_array(),
+            ArrayData::from_arrow(value.values().clone(), elements_nullable).into_array(),
+            nullable.into(),
+            nulls(value.nulls(), nullable),
+        )
+        .unwrap()
+        .into_array_data()
+    }
+}
+
+/// Arrow decimals are primitive arrays, so they cannot have their own `FromArrowArray` impl.
+fn decimal128_from_arrow(
+    precision: u8,
+    scale: i8,
+    values: &[i128],
+    nulls: Option<&NullBuffer>,
+    nullable: bool,
+) -> VortexResult<ArrayData> {
+    let decimal = DecimalDType::try_new(precision, scale)?;
+    DecimalArray::from_values(decimal, values.to_vec(), self::nulls(nulls, nullable))
+        .map(|a| a.into_array_data())
+}
+
+/// Decimal256 values are kept at their full width if they may not fit into an i128, otherwise
+/// this returns an error if a value does not fit.
+fn decimal256_from_arrow(
+    precision: u8,
+    scale: i8,
+    values: &[i256],
+    nulls: Option<&NullBuffer>,
+    nullable: bool,
+) -> VortexResult<ArrayData> {
+    let decimal = DecimalDType::try_new(precision, scale)?;
+    let validity = self::nulls(nulls, nullable);
+    let array = if decimal.precision() <= DecimalDType::MAX_I128_PRECISION {
+        let values = values
+            .iter()
+            .map(|v| {
+                v.to_i128()
+                    .ok_or_else(|| vortex_err!("Value {} does not fit into a {}", v, decimal))
+            })
+            .try_collect()?;
+        DecimalArray::from_values(decimal, values, validity)?
+    } else {
+        let bytes = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        DecimalArray::from_le_bytes(decimal, bytes, validity)?
+    };
+    Ok(array.into_array_data())
+}
+
 impl FromArrowArray<&ArrowNullArray> for ArrayData {
     fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
         assert!(nullable);
@@ -252,6 +316,30 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                 nullable,
             ),
             DataType::Struct(_) => ArrayData::from_arrow(array.as_struct(), nullable),
+            DataType::Decimal128(precision, scale) => {
+                let decimals = array.as_primitive::<Decimal128Type>();
+                decimal128_from_arrow(
+                    *precision,
+                    *scale,
+                    decimals.values(),
+                    decimals.nulls(),
+                    nullable,
+                )
+                .unwrap()
+            }
+            DataType::Decimal256(precision, scale) => {
+                let decimals = array.as_primitive::<Decimal256Type>();
+                decimal256_from_arrow(
+                    *precision,
+                    *scale,
+                    decimals.values(),
+                    decimals.nulls(),
+                    nullable,
+                )
+                .unwrap()
+            }
+            DataType::List(_) => ArrayData::from_arrow(array.as_list::<i32>(), nullable),
+            DataType::LargeList(_) => ArrayData::from_arrow(array.as_list::<i64>(), nullable),
             DataType::Null => ArrayData::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 TimeUnit::Second => {
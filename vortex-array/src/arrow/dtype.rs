@@ -1,12 +1,12 @@
 use arrow_schema::TimeUnit as ArrowTimeUnit;
 use arrow_schema::{DataType, Field, SchemaRef};
 use itertools::Itertools;
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{DType, DecimalDType, Nullability};
 use vortex_dtype::{PType, StructDType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::array::datetime::{LocalDateTimeArray, TimeUnit};
-use crate::arrow::{FromArrowType, TryFromArrowType};
+use crate::array::datetime::{InstantArray, LocalDateTimeArray, TimeUnit};
+use crate::arrow::TryFromArrowType;
 
 impl TryFromArrowType<&DataType> for PType {
     fn try_from_arrow(value: &DataType) -> VortexResult<Self> {
@@ -36,9 +36,9 @@ impl TryFromArrowType<&DataType> for PType {
     }
 }
 
-impl FromArrowType<SchemaRef> for DType {
-    fn from_arrow(value: SchemaRef) -> Self {
-        DType::Struct(
+impl TryFromArrowType<SchemaRef> for DType {
+    fn try_from_arrow(value: SchemaRef) -> VortexResult<Self> {
+        Ok(DType::Struct(
             StructDType::new(
                 value
                     .fields()
@@ -49,39 +49,45 @@ impl FromArrowType<SchemaRef> for DType {
                 value
                     .fields()
                     .iter()
-                    .map(|f| DType::from_arrow(f.as_ref()))
-                    .collect_vec(),
+                    .map(|f| DType::try_from_arrow(f.as_ref()))
+                    .try_collect()?,
             ),
             Nullability::NonNullable,
-        )
+        ))
     }
 }
 
-impl FromArrowType<&Field> for DType {
-    fn from_arrow(field: &Field) -> Self {
+impl TryFromArrowType<&Field> for DType {
+    fn try_from_arrow(field: &Field) -> VortexResult<Self> {
         use vortex_dtype::DType::*;
 
         let nullability: Nullability = field.is_nullable().into();
 
-        if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
-            return Primitive(ptype, nullability);
+        // Timestamps are stored as i64, but are represented by a datetime extension dtype.
+        if !matches!(field.data_type(), DataType::Timestamp(..)) {
+            if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
+                return Ok(Primitive(ptype, nullability));
+            }
         }
 
-        match field.data_type() {
+        Ok(match field.data_type() {
             DataType::Null => Null,
             DataType::Boolean => Bool(nullability),
             DataType::Utf8 | DataType::LargeUtf8 => Utf8(nullability),
             DataType::Binary | DataType::LargeBinary => Binary(nullability),
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                DecimalDType::try_new(*precision, *scale)?.dtype(nullability)
+            }
             DataType::Timestamp(time_unit, tz) => match tz {
                 None => Extension(LocalDateTimeArray::ext_dtype(time_unit.into()), nullability),
-                Some(_) => unimplemented!("Timezone not yet supported"),
+                Some(tz) => Extension(InstantArray::ext_dtype(time_unit.into(), tz), nullability),
             },
             // DataType::Date32 => localdate(IntWidth::_32, nullability),
             // DataType::Date64 => localdate(IntWidth::_64, nullability),
             // DataType::Time32(u) => localtime(u.into(), IntWidth::_32, nullability),
             // DataType::Time64(u) => localtime(u.into(), IntWidth::_64, nullability),
             DataType::List(e) | DataType::LargeList(e) => {
-                List(Box::new(DType::from_arrow(e.as_ref())), nullability)
+                List(Box::new(DType::try_from_arrow(e.as_ref())?), nullability)
             }
             DataType::Struct(f) => Struct(
                 StructDType::new(
@@ -90,13 +96,13 @@ impl FromArrowType<&Field> for DType {
                         .collect_vec()
                         .into(),
                     f.iter()
-                        .map(|f| DType::from_arrow(f.as_ref()))
-                        .collect_vec(),
+                        .map(|f| DType::try_from_arrow(f.as_ref()))
+                        .try_collect()?,
                 ),
                 nullability,
             ),
-            _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
-        }
+            _ => vortex_bail!("Arrow data type not yet supported: {:?}", field.data_type()),
+        })
     }
 }
 
@@ -6,6 +6,7 @@ use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::chunked::{Chunked, ChunkedArray};
 use crate::array::constant::{Constant, ConstantArray};
+use crate::array::list::{List, ListArray};
 use crate::array::r#struct::{Struct, StructArray};
 use crate::compute::scalar_at::scalar_at;
 use crate::compute::slice::slice;
@@ -219,6 +220,17 @@ impl<'a> Compressor<'a> {
                 )?
                 .into_array())
             }
+            List::ID => {
+                // For list arrays, we compress the offsets and elements individually
+                let list = ListArray::try_from(arr)?;
+                let offsets = self.compress_array(&list.offsets())?;
+                let elements = self.compress_array(&list.elements())?;
+                let validity = self.compress_validity(list.validity())?;
+                Ok(
+                    ListArray::try_new(offsets, elements, list.dtype().nullability(), validity)?
+                        .into_array(),
+                )
+            }
             _ => {
                 // Otherwise, we run sampled compression over pluggable encodings
                 let sampled = sampled_compression(arr, self)?;
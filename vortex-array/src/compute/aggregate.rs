@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+
+use log::info;
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::compute::arithmetic::{scalar_arithmetic, BinaryOperator, OverflowMode};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{Array, ArrayDType, IntoArray};
+
+pub trait SumFn {
+    /// The sum of the non-null values, see [`sum`].
+    fn sum(&self) -> VortexResult<Scalar>;
+}
+
+pub trait MinMaxFn {
+    /// The smallest and largest non-null values, see [`min_max`].
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)>;
+}
+
+pub trait CountDistinctFn {
+    /// An upper bound on the number of distinct non-null values, see [`count_distinct`].
+    fn count_distinct(&self) -> VortexResult<usize>;
+}
+
+/// The sum of the non-null values of a primitive array.
+///
+/// Integers are summed as i64 or u64 and floats as f64, failing if an integer sum overflows. The
+/// result is null if there are no non-null values.
+pub fn sum(array: &Array) -> VortexResult<Scalar> {
+    let ptype = primitive_ptype(array, "sum")?;
+    if array.is_empty() {
+        return Ok(PrimitiveScalar::none_from_ptype(sum_ptype(ptype)).into());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.sum() {
+            return f.sum();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("SumFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.sum()
+                .map(|f| f.sum())
+                .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "sum", array.encoding().id())))
+        })
+    })
+}
+
+/// The smallest and largest non-null values of a primitive array as nullable scalars, which are
+/// both null if there are no non-null values.
+///
+/// NaN values are ignored.
+pub fn min_max(array: &Array) -> VortexResult<(Scalar, Scalar)> {
+    let ptype = primitive_ptype(array, "min_max")?;
+    if array.is_empty() {
+        let null: Scalar = PrimitiveScalar::none_from_ptype(ptype).into();
+        return Ok((null.clone(), null));
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.min_max() {
+            return f.min_max();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("MinMaxFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.min_max().map(|f| f.min_max()).unwrap_or_else(|| {
+                Err(vortex_err!(NotImplemented: "min_max", array.encoding().id()))
+            })
+        })
+    })
+}
+
+/// The number of non-null values of the array.
+pub fn count(array: &Array) -> VortexResult<usize> {
+    Ok(match array.with_dyn(|a| a.logical_validity()) {
+        LogicalValidity::AllValid(len) => len,
+        LogicalValidity::AllInvalid(_) => 0,
+        LogicalValidity::Array(a) => a
+            .into_array()
+            .flatten_bool()?
+            .boolean_buffer()
+            .count_set_bits(),
+    })
+}
+
+/// The mean of the non-null values of a primitive array as a nullable f64, which is null if there
+/// are no non-null values.
+pub fn mean(array: &Array) -> VortexResult<Scalar> {
+    let total = sum(array)?;
+    let count = count(array)?;
+    if total.is_null() || count == 0 {
+        return Ok(PrimitiveScalar::none::<f64>().into());
+    }
+    let total = match_each_native_ptype!(sum_ptype(primitive_ptype(array, "mean")?), |$T| {
+        ToPrimitive::to_f64(&<$T>::try_from(&total)?)
+    });
+    Ok(PrimitiveScalar::nullable(total.map(|t| t / count as f64)).into())
+}
+
+/// An estimate of the number of distinct non-null values of the array.
+///
+/// The estimate is exact for uncompressed arrays, but compressed encodings may return an upper
+/// bound instead of decompressing. NaN values with different bit patterns are counted separately.
+pub fn count_distinct(array: &Array) -> VortexResult<usize> {
+    if array.is_empty() {
+        return Ok(0);
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.count_distinct() {
+            return f.count_distinct();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("CountDistinctFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.count_distinct()
+                .map(|f| f.count_distinct())
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(
+                        NotImplemented: "count_distinct",
+                        array.encoding().id()
+                    ))
+                })
+        })
+    })
+}
+
+/// The ptype that values of the given ptype are summed as.
+pub fn sum_ptype(ptype: PType) -> PType {
+    if ptype.is_float() {
+        PType::F64
+    } else if ptype.is_signed_int() {
+        PType::I64
+    } else {
+        PType::U64
+    }
+}
+
+/// The sum of a primitive scalar repeated `count` times, see [`sum`].
+pub fn sum_repeated(value: &Scalar, count: usize) -> VortexResult<Scalar> {
+    let ptype = PType::try_from(value.dtype())?;
+    let mut accumulator = SumAccumulator::new(ptype);
+    if !value.is_null() && count > 0 {
+        match_each_native_ptype!(ptype, |$T| {
+            accumulator.add(<$T>::try_from(value)?, count as u64)?;
+        })
+    }
+    Ok(accumulator.finish())
+}
+
+/// The sum of the non-null values of a primitive array, each counted as often as its weight.
+///
+/// This is the sum of any array whose values are repeated, e.g. the runs of a run-end encoding.
+pub fn weighted_sum(values: &Array, weights: &[u64]) -> VortexResult<Scalar> {
+    if values.len() != weights.len() {
+        vortex_bail!("Expected {} weights, got {}", values.len(), weights.len());
+    }
+    let values = values.clone().flatten_primitive()?;
+    let validity = values.logical_validity().to_null_buffer()?;
+    let mut accumulator = SumAccumulator::new(values.ptype());
+    match_each_native_ptype!(values.ptype(), |$T| {
+        for (i, (&v, &w)) in values.typed_data::<$T>().iter().zip(weights).enumerate() {
+            if w > 0 && validity.as_ref().map_or(true, |n| n.is_valid(i)) {
+                accumulator.add(v, w)?;
+            }
+        }
+    });
+    Ok(accumulator.finish())
+}
+
+/// Add up the sums of several parts of an array of the given ptype, ignoring any null sums.
+pub fn merge_sums(ptype: PType, sums: impl IntoIterator<Item = Scalar>) -> VortexResult<Scalar> {
+    let mut total: Scalar = PrimitiveScalar::none_from_ptype(sum_ptype(ptype)).into();
+    for sum in sums.into_iter().filter(|s| !s.is_null()) {
+        total = if total.is_null() {
+            sum
+        } else {
+            scalar_arithmetic(&total, &sum, BinaryOperator::Add, OverflowMode::Checked)?
+        };
+    }
+    Ok(total)
+}
+
+/// Combine the minima and maxima of several parts of an array of the given ptype, ignoring any
+/// null scalars.
+pub fn merge_min_max(
+    ptype: PType,
+    parts: impl IntoIterator<Item = (Scalar, Scalar)>,
+) -> VortexResult<(Scalar, Scalar)> {
+    match_each_native_ptype!(ptype, |$T| {
+        let mut min: Option<$T> = None;
+        let mut max: Option<$T> = None;
+        for (part_min, part_max) in parts {
+            if !part_min.is_null() {
+                let v = <$T>::try_from(&part_min)?;
+                min = Some(min.map_or(v, |m| if v < m { v } else { m }));
+            }
+            if !part_max.is_null() {
+                let v = <$T>::try_from(&part_max)?;
+                max = Some(max.map_or(v, |m| if v > m { v } else { m }));
+            }
+        }
+        Ok((PrimitiveScalar::nullable(min).into(), PrimitiveScalar::nullable(max).into()))
+    })
+}
+
+/// The number of distinct byte strings, e.g. the values of a primitive array in native byte order.
+pub fn count_distinct_bytes<'a>(values: impl IntoIterator<Item = &'a [u8]>) -> usize {
+    values.into_iter().collect::<HashSet<_>>().len()
+}
+
+fn primitive_ptype(array: &Array, name: &str) -> VortexResult<PType> {
+    match array.dtype() {
+        DType::Primitive(ptype, _) => Ok(*ptype),
+        _ => vortex_bail!(
+            "{} is only supported for primitive arrays, got {}",
+            name,
+            array.dtype()
+        ),
+    }
+}
+
+/// Sums values in the widened ptype of [`sum_ptype`].
+pub(crate) struct SumAccumulator {
+    ptype: PType,
+    sum: Sum,
+    is_empty: bool,
+}
+
+enum Sum {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+}
+
+impl SumAccumulator {
+    pub(crate) fn new(ptype: PType) -> Self {
+        let sum = match sum_ptype(ptype) {
+            PType::F64 => Sum::Float(0.0),
+            PType::I64 => Sum::Signed(0),
+            _ => Sum::Unsigned(0),
+        };
+        Self {
+            ptype,
+            sum,
+            is_empty: true,
+        }
+    }
+
+    /// Add the value `weight` times.
+    pub(crate) fn add<T: NativePType>(&mut self, value: T, weight: u64) -> VortexResult<()> {
+        let overflow = || vortex_err!("Sum of {} values overflowed", self.ptype);
+        self.sum = match self.sum {
+            Sum::Signed(s) => Sum::Signed(
+                value
+                    .to_i64()
+                    .zip(i64::try_from(weight).ok())
+                    .and_then(|(v, w)| v.checked_mul(w))
+                    .and_then(|v| s.checked_add(v))
+                    .ok_or_else(overflow)?,
+            ),
+            Sum::Unsigned(s) => Sum::Unsigned(
+                value
+                    .to_u64()
+                    .and_then(|v| v.checked_mul(weight))
+                    .and_then(|v| s.checked_add(v))
+                    .ok_or_else(overflow)?,
+            ),
+            Sum::Float(s) => Sum::Float(s + value.to_f64().ok_or_else(overflow)? * weight as f64),
+        };
+        self.is_empty = false;
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Scalar {
+        if self.is_empty {
+            return PrimitiveScalar::none_from_ptype(sum_ptype(self.ptype)).into();
+        }
+        match self.sum {
+            Sum::Signed(s) => PrimitiveScalar::nullable(Some(s)),
+            Sum::Unsigned(s) => PrimitiveScalar::nullable(Some(s)),
+            Sum::Float(s) => PrimitiveScalar::nullable(Some(s)),
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::constant::ConstantArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::aggregate::{count, count_distinct, mean, min_max, sum};
+    use crate::IntoArray;
+
+    #[test]
+    fn aggregate_constant() {
+        let array = ConstantArray::new(-3i16, 1000).into_array();
+        assert_eq!(sum(&array).unwrap(), Some(-3000i64).into());
+        assert_eq!(mean(&array).unwrap(), Some(-3f64).into());
+        assert_eq!(
+            min_max(&array).unwrap(),
+            (Some(-3i16).into(), Some(-3i16).into())
+        );
+        assert_eq!(count_distinct(&array).unwrap(), 1);
+
+        let nulls = ConstantArray::new(
+            Scalar::null(&DType::Primitive(PType::U8, Nullability::Nullable)),
+            10,
+        )
+        .into_array();
+        assert!(sum(&nulls).unwrap().is_null());
+        assert_eq!(count(&nulls).unwrap(), 0);
+        assert_eq!(count_distinct(&nulls).unwrap(), 0);
+    }
+
+    #[test]
+    fn aggregate_non_primitive() {
+        let array = VarBinArray::from(vec!["a", "b"]).into_array();
+        assert_eq!(count(&array).unwrap(), 2);
+        assert!(sum(&array).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+use std::fmt::{Display, Formatter};
+
+use log::info;
+use vortex_dtype::half::f16;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::constant::ConstantArray;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinaryOperator {
+    /// Whether `a op b == b op a` for all values.
+    pub fn is_commutative(self) -> bool {
+        matches!(self, BinaryOperator::Add | BinaryOperator::Mul)
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOperator::Add => write!(f, "+"),
+            BinaryOperator::Sub => write!(f, "-"),
+            BinaryOperator::Mul => write!(f, "*"),
+            BinaryOperator::Div => write!(f, "/"),
+            BinaryOperator::Rem => write!(f, "%"),
+        }
+    }
+}
+
+/// How integer operations behave when the result does not fit into the ptype.
+///
+/// Floating point operations follow IEEE 754 regardless of the mode, and integer division by zero
+/// is always an error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// Fail the whole operation.
+    #[default]
+    Checked,
+    /// Wrap around at the boundary of the ptype.
+    Wrapping,
+    /// Clamp to the minimum or maximum value of the ptype.
+    Saturating,
+}
+
+/// A native type that supports all binary operators.
+pub trait PrimitiveArithmetic: NativePType {
+    fn apply(self, rhs: Self, operator: BinaryOperator, mode: OverflowMode) -> VortexResult<Self>;
+}
+
+macro_rules! integer_arithmetic {
+    ($T:ty) => {
+        impl PrimitiveArithmetic for $T {
+            fn apply(
+                self,
+                rhs: Self,
+                operator: BinaryOperator,
+                mode: OverflowMode,
+            ) -> VortexResult<Self> {
+                use BinaryOperator::*;
+                use OverflowMode::*;
+
+                if matches!(operator, Div | Rem) && rhs == 0 {
+                    vortex_bail!("Division by zero: {} {} {}", self, operator, rhs)
+                }
+                let result = match (operator, mode) {
+                    (Add, Checked) => self.checked_add(rhs),
+                    (Add, Wrapping) => Some(self.wrapping_add(rhs)),
+                    (Add, Saturating) => Some(self.saturating_add(rhs)),
+                    (Sub, Checked) => self.checked_sub(rhs),
+                    (Sub, Wrapping) => Some(self.wrapping_sub(rhs)),
+                    (Sub, Saturating) => Some(self.saturating_sub(rhs)),
+                    (Mul, Checked) => self.checked_mul(rhs),
+                    (Mul, Wrapping) => Some(self.wrapping_mul(rhs)),
+                    (Mul, Saturating) => Some(self.saturating_mul(rhs)),
+                    (Div, Checked) => self.checked_div(rhs),
+                    (Div, Wrapping) => Some(self.wrapping_div(rhs)),
+                    (Div, Saturating) => Some(self.saturating_div(rhs)),
+                    (Rem, Checked) => self.checked_rem(rhs),
+                    // The remainder is always in range, only MIN % -1 overflows while computing it.
+                    (Rem, Wrapping | Saturating) => Some(self.wrapping_rem(rhs)),
+                };
+                result.ok_or_else(|| vortex_err!("Integer overflow: {} {} {}", self, operator, rhs))
+            }
+        }
+    };
+}
+
+integer_arithmetic!(u8);
+integer_arithmetic!(u16);
+integer_arithmetic!(u32);
+integer_arithmetic!(u64);
+integer_arithmetic!(i8);
+integer_arithmetic!(i16);
+integer_arithmetic!(i32);
+integer_arithmetic!(i64);
+
+macro_rules! float_arithmetic {
+    ($T:ty) => {
+        impl PrimitiveArithmetic for $T {
+            fn apply(
+                self,
+                rhs: Self,
+                operator: BinaryOperator,
+                _mode: OverflowMode,
+            ) -> VortexResult<Self> {
+                Ok(match operator {
+                    BinaryOperator::Add => self + rhs,
+                    BinaryOperator::Sub => self - rhs,
+                    BinaryOperator::Mul => self * rhs,
+                    BinaryOperator::Div => self / rhs,
+                    BinaryOperator::Rem => self % rhs,
+                })
+            }
+        }
+    };
+}
+
+float_arithmetic!(f16);
+float_arithmetic!(f32);
+float_arithmetic!(f64);
+
+pub trait ArithmeticScalarFn {
+    /// Apply the operator to each value of the array and the scalar, which is never null and
+    /// has the same ptype as the array. The result has the dtype of the array.
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray>;
+}
+
+pub trait ArithmeticFn {
+    /// Apply the operator to each pair of values of the two arrays, which are guaranteed to have
+    /// the same length and ptype.
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray>;
+}
+
+/// Evaluate `array <operator> other` for every value of the array.
+///
+/// The result is null wherever the array is null, and entirely null if the scalar is null.
+pub fn arithmetic_scalar(
+    array: &Array,
+    other: &Scalar,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<OwnedArray> {
+    check_ptypes(array.dtype(), other.dtype())?;
+    if other.is_null() {
+        return Ok(
+            ConstantArray::new(Scalar::null(&array.dtype().as_nullable()), array.len())
+                .into_array(),
+        );
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.arithmetic_scalar() {
+            return f.arithmetic_scalar(other, operator, mode);
+        }
+
+        // Otherwise, flatten and try again.
+        info!(
+            "ArithmeticScalarFn not implemented for {}, flattening",
+            array
+        );
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.arithmetic_scalar()
+                .map(|f| f.arithmetic_scalar(other, operator, mode))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(
+                        NotImplemented: "arithmetic_scalar",
+                        array.encoding().id()
+                    ))
+                })
+        })
+    })
+}
+
+/// Evaluate `lhs <operator> rhs` for every pair of values of the two arrays.
+///
+/// The result is null wherever either of the arrays is null.
+pub fn arithmetic(
+    lhs: &Array,
+    rhs: &Array,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<OwnedArray> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Arrays of length {} and {} must have the same length",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+    check_ptypes(lhs.dtype(), rhs.dtype())?;
+
+    // Operations against a constant only need to be evaluated once per value, provided that the
+    // constant does not make the result nullable.
+    if let Ok(constant) = ConstantArray::try_from(rhs) {
+        if lhs.dtype().is_nullable() || !rhs.dtype().is_nullable() {
+            return arithmetic_scalar(lhs, constant.scalar(), operator, mode);
+        }
+    }
+    if let Ok(constant) = ConstantArray::try_from(lhs) {
+        if operator.is_commutative() && (rhs.dtype().is_nullable() || !lhs.dtype().is_nullable()) {
+            return arithmetic_scalar(rhs, constant.scalar(), operator, mode);
+        }
+    }
+
+    lhs.with_dyn(|a| {
+        if let Some(f) = a.arithmetic() {
+            return f.arithmetic(rhs, operator, mode);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("ArithmeticFn not implemented for {}, flattening", lhs);
+        lhs.clone().flatten()?.into_array().with_dyn(|a| {
+            a.arithmetic()
+                .map(|f| f.arithmetic(rhs, operator, mode))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "arithmetic", lhs.encoding().id()))
+                })
+        })
+    })
+}
+
+/// Apply the operator to two primitive scalars of the same ptype, returning a scalar with the
+/// nullability of the left hand side. The result is null if either scalar is null.
+pub fn scalar_arithmetic(
+    lhs: &Scalar,
+    rhs: &Scalar,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<Scalar> {
+    check_ptypes(lhs.dtype(), rhs.dtype())?;
+    let ptype = lhs.dtype().try_into()?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(PrimitiveScalar::none_from_ptype(ptype).into());
+    }
+    match_each_native_ptype!(ptype, |$T| {
+        let result = <$T>::try_from(lhs)?.apply(<$T>::try_from(rhs)?, operator, mode)?;
+        Ok(PrimitiveScalar::try_new(Some(result), lhs.dtype().nullability())?.into())
+    })
+}
+
+fn check_ptypes(lhs: &DType, rhs: &DType) -> VortexResult<()> {
+    match (lhs, rhs) {
+        (DType::Primitive(l, _), DType::Primitive(r, _)) if l == r => Ok(()),
+        (DType::Primitive(..), DType::Primitive(..)) => {
+            vortex_bail!(MismatchedTypes: lhs, rhs)
+        }
+        _ => vortex_bail!(
+            "Arithmetic is only supported for primitive types, got {}",
+            lhs
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::arithmetic::{arithmetic, arithmetic_scalar, BinaryOperator, OverflowMode};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{ArrayDType, IntoArray};
+
+    fn apply(lhs: Vec<i32>, rhs: Vec<i32>, operator: BinaryOperator) -> Vec<i32> {
+        arithmetic(
+            &lhs.into_array(),
+            &rhs.into_array(),
+            operator,
+            OverflowMode::Checked,
+        )
+        .unwrap()
+        .flatten_primitive()
+        .unwrap()
+        .typed_data::<i32>()
+        .to_vec()
+    }
+
+    #[test]
+    fn array_operators() {
+        let lhs = vec![7, -7, 12];
+        let rhs = vec![2, 2, -5];
+        assert_eq!(
+            apply(lhs.clone(), rhs.clone(), BinaryOperator::Add),
+            [9, -5, 7]
+        );
+        assert_eq!(
+            apply(lhs.clone(), rhs.clone(), BinaryOperator::Sub),
+            [5, -9, 17]
+        );
+        assert_eq!(
+            apply(lhs.clone(), rhs.clone(), BinaryOperator::Mul),
+            [14, -14, -60]
+        );
+        assert_eq!(
+            apply(lhs.clone(), rhs.clone(), BinaryOperator::Div),
+            [3, -3, -2]
+        );
+        assert_eq!(apply(lhs, rhs, BinaryOperator::Rem), [1, -1, 2]);
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let values = vec![250u8, 3].into_array();
+        let other: Scalar = 10u8.into();
+        let add = |mode| arithmetic_scalar(&values, &other, BinaryOperator::Add, mode);
+        assert!(add(OverflowMode::Checked).is_err());
+        let wrapped = add(OverflowMode::Wrapping)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(wrapped.typed_data::<u8>(), [4, 13]);
+        let saturated = add(OverflowMode::Saturating)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(saturated.typed_data::<u8>(), [255, 13]);
+    }
+
+    #[test]
+    fn divide_by_zero() {
+        let values = vec![1i64, 2].into_array();
+        let zeros = vec![1i64, 0].into_array();
+        assert!(arithmetic(&values, &zeros, BinaryOperator::Div, OverflowMode::Wrapping).is_err());
+
+        // Nulls are never evaluated.
+        let zeros = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None]).into_array();
+        let result =
+            arithmetic(&values, &zeros, BinaryOperator::Div, OverflowMode::Checked).unwrap();
+        assert_eq!(scalar_at(&result, 0).unwrap(), Some(1i64).into());
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn null_propagation() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(1u32), None, Some(3)]).into_array();
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(1u32), Some(2), None]).into_array();
+        let result = arithmetic(&lhs, &rhs, BinaryOperator::Mul, OverflowMode::Checked).unwrap();
+        assert_eq!(scalar_at(&result, 0).unwrap(), Some(1u32).into());
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+        assert!(scalar_at(&result, 2).unwrap().is_null());
+
+        let result = arithmetic_scalar(
+            &vec![1u32, 2].into_array(),
+            &Scalar::null(&DType::Primitive(PType::U32, Nullability::Nullable)),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert!(scalar_at(&result, 0).unwrap().is_null());
+    }
+
+    #[test]
+    fn constant_operands() {
+        let constant = ConstantArray::new(10i32, 3).into_array();
+        let values = vec![1i32, 2, 3].into_array();
+        let result = arithmetic(
+            &constant,
+            &values,
+            BinaryOperator::Sub,
+            OverflowMode::Checked,
+        )
+        .unwrap()
+        .flatten_primitive()
+        .unwrap();
+        assert_eq!(result.typed_data::<i32>(), [9, 8, 7]);
+
+        let result = arithmetic(
+            &values,
+            &constant,
+            BinaryOperator::Mul,
+            OverflowMode::Checked,
+        )
+        .unwrap()
+        .flatten_primitive()
+        .unwrap();
+        assert_eq!(result.typed_data::<i32>(), [10, 20, 30]);
+
+        let result = arithmetic_scalar(
+            &constant,
+            &2i32.into(),
+            BinaryOperator::Div,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert!(ConstantArray::try_from(&result).is_ok());
+        assert_eq!(scalar_at(&result, 2).unwrap(), 5i32.into());
+    }
+
+    #[test]
+    fn chunked_operands() {
+        let chunks = vec![vec![1u16, 2].into_array(), vec![3u16].into_array()];
+        let dtype = chunks[0].dtype().clone();
+        let chunked = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        let result = arithmetic(
+            &chunked,
+            &vec![10u16, 20, 30].into_array(),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        let values = ChunkedArray::try_from(result)
+            .unwrap()
+            .chunks()
+            .flat_map(|c| c.flatten_primitive().unwrap().typed_data::<u16>().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, [11, 22, 33]);
+    }
+
+    #[test]
+    fn mismatched_ptypes() {
+        let values = vec![1u32, 2].into_array();
+        assert!(arithmetic_scalar(
+            &values,
+            &1i32.into(),
+            BinaryOperator::Add,
+            OverflowMode::Checked
+        )
+        .is_err());
+    }
+}
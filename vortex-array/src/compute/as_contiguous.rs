@@ -1,7 +1,9 @@
 use itertools::Itertools;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::{Array, ArrayDType, OwnedArray};
+use crate::accessor::ArrayAccessor;
+use crate::array::varbin::VarBinArray;
+use crate::{Array, ArrayDType, Flattened, IntoArray, OwnedArray, ToStatic};
 
 pub trait AsContiguousFn {
     fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<OwnedArray>;
@@ -34,3 +36,19 @@ pub fn as_contiguous(arrays: &[Array]) -> VortexResult<OwnedArray> {
             })
     })
 }
+
+/// Flatten the array into the single encoding that every array of its dtype flattens to, so that
+/// arrays of different encodings can be concatenated.
+pub(crate) fn flatten_contiguous(array: &Array) -> VortexResult<OwnedArray> {
+    match array.clone().flatten()? {
+        Flattened::Chunked(chunked) => as_contiguous(
+            &chunked
+                .chunks()
+                .map(|chunk| flatten_contiguous(&chunk))
+                .collect::<VortexResult<Vec<_>>>()?,
+        ),
+        Flattened::VarBinView(view) => view
+            .with_iterator(|iter| VarBinArray::from_iter(iter, view.dtype().clone()).into_array()),
+        flattened => Ok(flattened.into_array().to_static()),
+    }
+}
@@ -0,0 +1,314 @@
+use std::fmt::{Display, Formatter};
+
+use log::info;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::constant::ConstantArray;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
+
+/// A binary boolean operator with Kleene (three-valued) null semantics.
+///
+/// A null operand is treated as an unknown value, so the result is only null if it depends on it,
+/// e.g. `false AND null` is `false` but `true AND null` is null.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOperator {
+    And,
+    Or,
+    /// `lhs AND NOT rhs`
+    AndNot,
+    Xor,
+}
+
+impl BooleanOperator {
+    /// Whether `a op b == b op a` for all values.
+    pub fn is_commutative(self) -> bool {
+        !matches!(self, BooleanOperator::AndNot)
+    }
+
+    /// Apply the operator to two values, where `None` is null.
+    pub fn apply(self, lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+        match self {
+            BooleanOperator::And => match (lhs, rhs) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            BooleanOperator::Or => match (lhs, rhs) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            BooleanOperator::AndNot => BooleanOperator::And.apply(lhs, rhs.map(|r| !r)),
+            BooleanOperator::Xor => lhs.zip(rhs).map(|(l, r)| l ^ r),
+        }
+    }
+}
+
+impl Display for BooleanOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BooleanOperator::And => write!(f, "and"),
+            BooleanOperator::Or => write!(f, "or"),
+            BooleanOperator::AndNot => write!(f, "and not"),
+            BooleanOperator::Xor => write!(f, "xor"),
+        }
+    }
+}
+
+pub trait BooleanFn {
+    /// Apply the operator to each pair of values of the two boolean arrays, which are guaranteed
+    /// to have the same length. The result is nullable if either of the arrays is nullable.
+    fn boolean(&self, other: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray>;
+}
+
+pub trait NotFn {
+    /// Negate each value of the boolean array, keeping its validity.
+    fn not(&self) -> VortexResult<OwnedArray>;
+}
+
+/// Evaluate `lhs AND rhs` with Kleene null semantics, see [`BooleanOperator`].
+pub fn and(lhs: &Array, rhs: &Array) -> VortexResult<OwnedArray> {
+    boolean(lhs, rhs, BooleanOperator::And)
+}
+
+/// Evaluate `lhs OR rhs` with Kleene null semantics, see [`BooleanOperator`].
+pub fn or(lhs: &Array, rhs: &Array) -> VortexResult<OwnedArray> {
+    boolean(lhs, rhs, BooleanOperator::Or)
+}
+
+/// Evaluate `lhs AND NOT rhs` with Kleene null semantics, see [`BooleanOperator`].
+pub fn and_not(lhs: &Array, rhs: &Array) -> VortexResult<OwnedArray> {
+    boolean(lhs, rhs, BooleanOperator::AndNot)
+}
+
+/// Evaluate `lhs XOR rhs`, which is null wherever either of the arrays is null.
+pub fn xor(lhs: &Array, rhs: &Array) -> VortexResult<OwnedArray> {
+    boolean(lhs, rhs, BooleanOperator::Xor)
+}
+
+/// Evaluate `lhs <operator> rhs` for every pair of values of the two boolean arrays.
+pub fn boolean(lhs: &Array, rhs: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Arrays of length {} and {} must have the same length",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+    check_bool(lhs.dtype())?;
+    check_bool(rhs.dtype())?;
+
+    // Constants are handled as the left hand side, where they can often decide the result without
+    // looking at the other array.
+    if operator.is_commutative()
+        && ConstantArray::try_from(rhs).is_ok()
+        && ConstantArray::try_from(lhs).is_err()
+    {
+        return boolean(rhs, lhs, operator);
+    }
+
+    lhs.with_dyn(|a| {
+        if let Some(f) = a.boolean() {
+            return f.boolean(rhs, operator);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("BooleanFn not implemented for {}, flattening", lhs);
+        lhs.clone().flatten()?.into_array().with_dyn(|a| {
+            a.boolean()
+                .map(|f| f.boolean(rhs, operator))
+                .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "boolean", lhs.encoding().id())))
+        })
+    })
+}
+
+/// Negate every value of the boolean array, which stays null wherever it is null.
+pub fn not(array: &Array) -> VortexResult<OwnedArray> {
+    check_bool(array.dtype())?;
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.not() {
+            return f.not();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("NotFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.not()
+                .map(|f| f.not())
+                .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "not", array.encoding().id())))
+        })
+    })
+}
+
+/// The value of a boolean scalar, or `None` if it is null.
+pub fn scalar_bool(scalar: &Scalar) -> VortexResult<Option<bool>> {
+    if scalar.is_null() {
+        check_bool(scalar.dtype())?;
+        Ok(None)
+    } else {
+        bool::try_from(scalar).map(Some)
+    }
+}
+
+fn check_bool(dtype: &DType) -> VortexResult<()> {
+    match dtype {
+        DType::Bool(_) => Ok(()),
+        _ => vortex_bail!(
+            "Boolean operators are only supported for booleans, got {}",
+            dtype
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::ConstantArray;
+    use crate::compute::boolean::{and, and_not, boolean, not, or, xor, BooleanOperator};
+    use crate::compute::scalar_at::scalar_values;
+    use crate::{ArrayDType, IntoArray};
+
+    const VALUES: [Option<bool>; 3] = [Some(true), Some(false), None];
+
+    #[test]
+    fn kleene_truth_tables() {
+        // Every combination of true, false and null on both sides.
+        let (lhs, rhs): (Vec<_>, Vec<_>) = VALUES
+            .iter()
+            .cartesian_product(VALUES.iter())
+            .map(|(l, r)| (*l, *r))
+            .unzip();
+        let lhs_array = BoolArray::from_iter(lhs.clone()).into_array();
+        let rhs_array = BoolArray::from_iter(rhs.clone()).into_array();
+
+        let t = Some(true);
+        let f = Some(false);
+        let n = None;
+        assert_eq!(
+            scalar_values::<bool>(&and(&lhs_array, &rhs_array).unwrap()).unwrap(),
+            [t, f, n, f, f, f, n, f, n]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&or(&lhs_array, &rhs_array).unwrap()).unwrap(),
+            [t, t, t, t, f, n, t, n, n]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&and_not(&lhs_array, &rhs_array).unwrap()).unwrap(),
+            [f, t, n, f, f, f, f, n, n]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&xor(&lhs_array, &rhs_array).unwrap()).unwrap(),
+            [f, t, n, t, f, n, n, n, n]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&not(&lhs_array).unwrap()).unwrap(),
+            [f, f, f, t, t, t, n, n, n]
+        );
+
+        for operator in [
+            BooleanOperator::And,
+            BooleanOperator::Or,
+            BooleanOperator::AndNot,
+            BooleanOperator::Xor,
+        ] {
+            let expected = lhs
+                .iter()
+                .zip(&rhs)
+                .map(|(l, r)| operator.apply(*l, *r))
+                .collect_vec();
+            assert_eq!(
+                scalar_values::<bool>(&boolean(&lhs_array, &rhs_array, operator).unwrap()).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn non_nullable() {
+        let lhs = BoolArray::from(vec![true, true, false, false]).into_array();
+        let rhs = BoolArray::from(vec![true, false, true, false]).into_array();
+        let result = and_not(&lhs, &rhs).unwrap();
+        assert!(!result.dtype().is_nullable());
+        assert_eq!(
+            result
+                .flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect_vec(),
+            [false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn constant_operands() {
+        let values = BoolArray::from_iter(VALUES).into_array();
+        let falsy = ConstantArray::new(false, 3).into_array();
+        let truthy = ConstantArray::new(true, 3).into_array();
+        let null =
+            ConstantArray::new(Scalar::null(&DType::Bool(Nullability::Nullable)), 3).into_array();
+
+        let result = and(&values, &falsy).unwrap();
+        assert!(ConstantArray::try_from(&result).is_ok());
+        assert_eq!(scalar_values::<bool>(&result).unwrap(), [Some(false); 3]);
+        assert_eq!(
+            scalar_values::<bool>(&or(&truthy, &values).unwrap()).unwrap(),
+            [Some(true); 3]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&and(&values, &truthy).unwrap()).unwrap(),
+            [Some(true), Some(false), None]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&and_not(&values, &null).unwrap()).unwrap(),
+            [None, Some(false), None]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&xor(&truthy, &falsy).unwrap()).unwrap(),
+            [Some(true); 3]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&not(&null).unwrap()).unwrap(),
+            [None; 3]
+        );
+    }
+
+    #[test]
+    fn chunked_operands() {
+        let lhs = ChunkedArray::try_new(
+            vec![
+                BoolArray::from(vec![true, false]).into_array(),
+                BoolArray::from(vec![true]).into_array(),
+            ],
+            DType::Bool(Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        let rhs = BoolArray::from_iter([Some(false), None, None]).into_array();
+        let result = or(&lhs, &rhs).unwrap();
+        assert!(result.dtype().is_nullable());
+        assert_eq!(
+            scalar_values::<bool>(&result).unwrap(),
+            [Some(true), None, Some(true)]
+        );
+        assert_eq!(
+            scalar_values::<bool>(&not(&lhs).unwrap()).unwrap(),
+            [Some(false), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn mismatched_arrays() {
+        let bools = BoolArray::from(vec![true, false]).into_array();
+        assert!(and(&bools, &vec![1u8, 2].into_array()).is_err());
+        assert!(and(&bools, &BoolArray::from(vec![true]).into_array()).is_err());
+    }
+}
@@ -1,21 +1,137 @@
+use log::info;
 use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::{Array, ArrayDType, OwnedArray, ToStatic};
+use crate::accessor::ArrayAccessor;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray, ToStatic};
 
 pub trait CastFn {
+    /// Cast the array to the dtype, failing if the array has nulls and the dtype is non-nullable.
     fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray>;
 }
 
+/// Cast the array to another dtype.
+///
+/// Numbers can be cast to other numbers, booleans and, for integers, utf8 strings. Booleans cast
+/// to numbers as 0 and 1, and utf8 and binary arrays cast to each other, where binary values must
+/// be valid UTF-8. Any dtype can be cast to a different nullability, as long as an array that
+/// becomes non-nullable has no nulls.
 pub fn cast(array: &Array, dtype: &DType) -> VortexResult<OwnedArray> {
     if array.dtype() == dtype {
         return Ok(array.to_static());
     }
 
-    // TODO(ngates): check for null_count if dtype is non-nullable
     array.with_dyn(|a| {
-        a.cast()
-            .map(|f| f.cast(dtype))
-            .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "cast", array.encoding().id())))
+        if let Some(f) = a.cast() {
+            return f.cast(dtype);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("CastFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.cast()
+                .map(|f| f.cast(dtype))
+                .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "cast", array.encoding().id())))
+        })
     })
 }
+
+/// Check that every value of a binary array is valid UTF-8, so that it can be cast to utf8.
+pub(crate) fn check_utf8(array: &impl ArrayAccessor<[u8]>) -> VortexResult<()> {
+    array.with_iterator(|iter| {
+        for value in iter.flatten() {
+            if std::str::from_utf8(value).is_err() {
+                vortex_bail!(ComputeError: "Binary value is not valid UTF-8");
+            }
+        }
+        Ok(())
+    })?
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::sparse::SparseArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::cast::cast;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn cast_strings() {
+        let binary = DType::Binary(Nullability::Nullable);
+        let utf8 = DType::Utf8(Nullability::NonNullable);
+        let varbin =
+            VarBinArray::from_iter(vec![Some("a"), Some("bc")], binary.clone()).into_array();
+        let view =
+            VarBinViewArray::from_iter(vec![Some("a"), Some("bc")], binary.clone()).into_array();
+        for array in [varbin, view] {
+            let strings = cast(&array, &utf8).unwrap();
+            assert_eq!(strings.dtype(), &utf8);
+            assert_eq!(
+                String::try_from(scalar_at(&strings, 1).unwrap()).unwrap(),
+                "bc"
+            );
+            assert_eq!(cast(&strings, &binary).unwrap().dtype(), &binary);
+        }
+
+        let invalid = VarBinArray::from_iter(vec![Some(vec![0xffu8])], binary).into_array();
+        assert!(cast(&invalid, &utf8).is_err());
+        let nulls =
+            VarBinArray::from_iter(vec![Some("a"), None], DType::Utf8(Nullability::Nullable))
+                .into_array();
+        assert!(cast(&nulls, &utf8).is_err());
+    }
+
+    #[test]
+    fn cast_bools() {
+        let bools = BoolArray::from_iter([Some(true), None, Some(false)]).into_array();
+        let ints = cast(&bools, &DType::Primitive(PType::I32, Nullability::Nullable)).unwrap();
+        assert_eq!(i32::try_from(scalar_at(&ints, 0).unwrap()).unwrap(), 1);
+        assert!(scalar_at(&ints, 1).unwrap().is_null());
+        assert_eq!(i32::try_from(scalar_at(&ints, 2).unwrap()).unwrap(), 0);
+        assert!(cast(&bools, &DType::Bool(Nullability::NonNullable)).is_err());
+    }
+
+    #[test]
+    fn cast_pushdown() {
+        let dtype = DType::Primitive(PType::I64, Nullability::NonNullable);
+        let constant = cast(&ConstantArray::new(5u8, 3).into_array(), &dtype).unwrap();
+        assert!(ConstantArray::try_from(&constant).is_ok());
+        assert_eq!(i64::try_from(scalar_at(&constant, 2).unwrap()).unwrap(), 5);
+
+        let chunked = ChunkedArray::try_new(
+            vec![
+                vec![1u8, 2].into_array(),
+                ConstantArray::new(3u8, 1).into_array(),
+            ],
+            DType::Primitive(PType::U8, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        let chunked = cast(&chunked, &dtype).unwrap();
+        assert!(ChunkedArray::try_from(&chunked).is_ok());
+        assert_eq!(i64::try_from(scalar_at(&chunked, 2).unwrap()).unwrap(), 3);
+
+        let sparse = SparseArray::new(
+            vec![1u64].into_array(),
+            vec![7u8].into_array(),
+            3,
+            Scalar::from(0u8),
+        )
+        .into_array();
+        let sparse = cast(&sparse, &dtype).unwrap();
+        assert!(SparseArray::try_from(&sparse).is_ok());
+        assert_eq!(i64::try_from(scalar_at(&sparse, 1).unwrap()).unwrap(), 7);
+        assert_eq!(i64::try_from(scalar_at(&sparse, 2).unwrap()).unwrap(), 0);
+
+        let null = ConstantArray::new(Scalar::null(&dtype.as_nullable()), 2).into_array();
+        assert!(cast(&null, &dtype).is_err());
+    }
+}
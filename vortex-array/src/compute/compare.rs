@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use arrow_buffer::BooleanBuffer;
+use log::info;
+use vortex_dtype::{match_each_integer_ptype, DType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{PScalar, Scalar};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::bool::BoolArray;
+use crate::array::constant::ConstantArray;
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl Operator {
+    /// The operator with its operands swapped, such that `a op b == b op.swap() a`.
+    pub fn swap(self) -> Self {
+        match self {
+            Operator::Eq => Operator::Eq,
+            Operator::NotEq => Operator::NotEq,
+            Operator::Gt => Operator::Lt,
+            Operator::Gte => Operator::Lte,
+            Operator::Lt => Operator::Gt,
+            Operator::Lte => Operator::Gte,
+        }
+    }
+
+    pub fn to_predicate<T: PartialOrd + ?Sized>(self) -> fn(&T, &T) -> bool {
+        match self {
+            Operator::Eq => PartialEq::eq,
+            Operator::NotEq => PartialEq::ne,
+            Operator::Gt => PartialOrd::gt,
+            Operator::Gte => PartialOrd::ge,
+            Operator::Lt => PartialOrd::lt,
+            Operator::Lte => PartialOrd::le,
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operator::Eq => write!(f, "="),
+            Operator::NotEq => write!(f, "!="),
+            Operator::Gt => write!(f, ">"),
+            Operator::Gte => write!(f, ">="),
+            Operator::Lt => write!(f, "<"),
+            Operator::Lte => write!(f, "<="),
+        }
+    }
+}
+
+pub trait CompareFn {
+    /// Compare each value of the array against the scalar, which is never null.
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray>;
+}
+
+/// Evaluate `array <operator> other` for every value of the array, returning a boolean array.
+///
+/// The result is null wherever the array is null, and entirely null if the scalar is null.
+pub fn compare(array: &Array, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+    if other.is_null() {
+        return Ok(ConstantArray::new(
+            Scalar::null(&DType::Bool(Nullability::Nullable)),
+            array.len(),
+        )
+        .into_array());
+    }
+    // Primitive scalars are cast to the ptype of the array, e.g. to compare i32 values with an i64.
+    // Casting into an integer ptype would wrap or truncate scalars that it cannot represent.
+    let (other, operator) = match array.dtype() {
+        DType::Primitive(ptype, _) if ptype.is_int() => {
+            match narrow_to_integer(*ptype, other, operator) {
+                Narrowed::Scalar(narrowed, operator) => (narrowed.cast(array.dtype())?, operator),
+                Narrowed::Constant(result) => {
+                    let validity = array.with_dyn(|a| a.logical_validity()).into_validity();
+                    return Ok(
+                        BoolArray::from_vec(vec![result; array.len()], validity).into_array()
+                    );
+                }
+            }
+        }
+        DType::Primitive(..) => (other.cast(array.dtype())?, operator),
+        _ => (other.clone(), operator),
+    };
+
+    array.with_dyn(|a| {
+        if let Some(c) = a.compare() {
+            return c.compare(&other, operator);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("CompareFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.compare()
+                .map(|c| c.compare(&other, operator))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(
+                        NotImplemented: "compare",
+                        array.encoding().id()
+                    ))
+                })
+        })
+    })
+}
+
+/// Compare every value of the accessor against a utf8 or binary scalar, where the validity is that
+/// of the array.
+pub(crate) fn compare_accessor(
+    array: &impl ArrayAccessor<[u8]>,
+    other: &Scalar,
+    operator: Operator,
+    validity: Validity,
+) -> VortexResult<OwnedArray> {
+    let value: &[u8] = match other {
+        Scalar::Utf8(s) => s.value().map(|s| s.as_bytes()),
+        Scalar::Binary(b) => b.value().map(|b| b.as_slice()),
+        _ => vortex_bail!(MismatchedTypes: "utf8 or binary", other.dtype()),
+    }
+    .unwrap_or_default();
+    let predicate = operator.to_predicate::<[u8]>();
+
+    let buffer = array.with_iterator(|iter| {
+        BooleanBuffer::from_iter(iter.map(|v| v.map(|v| predicate(v, value)).unwrap_or(false)))
+    })?;
+    BoolArray::try_new(buffer, validity).map(|a| a.into_array())
+}
+
+enum Narrowed {
+    /// An equivalent comparison against a scalar that the integer ptype can represent.
+    Scalar(Scalar, Operator),
+    /// The result of the comparison for every value of the integer ptype.
+    Constant(bool),
+}
+
+/// Rewrite a comparison against a primitive scalar such that the scalar can be cast to the
+/// integer ptype losslessly, or resolve it if the scalar lies outside the range of the ptype.
+fn narrow_to_integer(ptype: PType, other: &Scalar, operator: Operator) -> Narrowed {
+    let value = match other {
+        Scalar::Primitive(p) => p.value(),
+        _ => None,
+    };
+    let Some(value) = value else {
+        return Narrowed::Scalar(other.clone(), operator);
+    };
+
+    let (value, operator) = match value {
+        PScalar::U8(v) => (v as i128, operator),
+        PScalar::U16(v) => (v as i128, operator),
+        PScalar::U32(v) => (v as i128, operator),
+        PScalar::U64(v) => (v as i128, operator),
+        PScalar::I8(v) => (v as i128, operator),
+        PScalar::I16(v) => (v as i128, operator),
+        PScalar::I32(v) => (v as i128, operator),
+        PScalar::I64(v) => (v as i128, operator),
+        PScalar::F16(_) | PScalar::F32(_) | PScalar::F64(_) => {
+            let v = match value {
+                PScalar::F16(v) => v.to_f64(),
+                PScalar::F32(v) => v as f64,
+                PScalar::F64(v) => v,
+                _ => unreachable!(),
+            };
+            if v.is_nan() {
+                return Narrowed::Constant(operator == Operator::NotEq);
+            }
+            // No integer is equal to a fractional value, so order against the integer on the
+            // other side of it instead. Infinite values saturate to out of range integers.
+            match operator {
+                _ if v.is_infinite() || v.fract() == 0.0 => (v as i128, operator),
+                Operator::Eq => return Narrowed::Constant(false),
+                Operator::NotEq => return Narrowed::Constant(true),
+                Operator::Gt | Operator::Gte => (v.floor() as i128, Operator::Gt),
+                Operator::Lt | Operator::Lte => (v.ceil() as i128, Operator::Lt),
+            }
+        }
+    };
+
+    let (min, max) = match_each_integer_ptype!(ptype, |$T| (<$T>::MIN as i128, <$T>::MAX as i128));
+    // Every value of the ptype compares the same against a scalar outside of its range.
+    let ordering = if value < min {
+        Ordering::Greater
+    } else if value > max {
+        Ordering::Less
+    } else {
+        let narrowed: Scalar = match_each_integer_ptype!(ptype, |$T| (value as $T).into());
+        return Narrowed::Scalar(narrowed, operator);
+    };
+    Narrowed::Constant(operator.to_predicate()(&ordering, &Ordering::Equal))
+}
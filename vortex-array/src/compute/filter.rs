@@ -0,0 +1,88 @@
+use arrow_buffer::BooleanBuffer;
+use itertools::Itertools;
+use log::info;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::bool::{BoolArray, OwnedBoolArray};
+use crate::array::primitive::{OwnedPrimitiveArray, PrimitiveArray};
+use crate::compute::take::take;
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{Array, ArrayTrait, IntoArray, OwnedArray};
+
+pub trait FilterFn {
+    /// Keep the values where the mask is true.
+    ///
+    /// The mask is guaranteed to have the same length as the array and to contain no nulls.
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray>;
+}
+
+/// Return a new array holding only the values of the array where the mask is true.
+///
+/// Null values in the mask are treated as false.
+pub fn filter(array: &Array, mask: &BoolArray) -> VortexResult<OwnedArray> {
+    if mask.len() != array.len() {
+        vortex_bail!(
+            "Filter mask of length {} does not match array of length {}",
+            mask.len(),
+            array.len()
+        );
+    }
+    let mask = selection_mask(mask)?;
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.filter() {
+            return f.filter(&mask);
+        }
+
+        // Otherwise, take the selected indices.
+        info!("FilterFn not implemented for {}, taking indices", array);
+        take(array, &filter_indices(&mask).into_array())
+    })
+}
+
+/// The indices of the set values of a mask.
+pub fn filter_indices(mask: &BoolArray) -> OwnedPrimitiveArray {
+    PrimitiveArray::from(
+        mask.boolean_buffer()
+            .set_indices()
+            .map(|i| i as u64)
+            .collect_vec(),
+    )
+}
+
+/// A non-nullable mask that is only set where the given mask is both valid and true.
+fn selection_mask(mask: &BoolArray) -> VortexResult<OwnedBoolArray> {
+    let buffer = mask.boolean_buffer();
+    Ok(match mask.logical_validity() {
+        LogicalValidity::AllValid(_) => BoolArray::from(buffer),
+        LogicalValidity::AllInvalid(len) => BoolArray::from(BooleanBuffer::new_unset(len)),
+        LogicalValidity::Array(a) => {
+            BoolArray::from(&buffer & &a.into_array().flatten_bool()?.boolean_buffer())
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::IntoArray;
+
+    #[test]
+    fn filter_nullable_mask() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3), Some(4)]);
+        let mask = BoolArray::from_iter([Some(true), Some(true), None, Some(false)]);
+        let filtered = filter(&array.into_array(), &mask).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(scalar_at(&filtered, 0).unwrap(), Some(1).into());
+        assert!(scalar_at(&filtered, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn filter_mismatched_length() {
+        let array = PrimitiveArray::from(vec![1, 2, 3]).into_array();
+        assert!(filter(&array, &BoolArray::from(vec![true])).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use ahash::RandomState;
+use log::info;
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{AsBytes, Scalar};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::primitive::PrimitiveArray;
+use crate::{Array, IntoArray, OwnedArray};
+
+/// Fixed seeds, so that equal values hash the same in every array of the process.
+static HASHER: RandomState = RandomState::with_seeds(
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+);
+
+/// The hash of every null value.
+pub const NULL_HASH: u64 = 0;
+
+pub trait HashFn {
+    /// The hash of every value of the array, see [`hash`].
+    fn hash(&self) -> VortexResult<OwnedArray>;
+}
+
+/// A non-nullable u64 array with the hash of every value of the array, for use in joins and
+/// group-bys.
+///
+/// Equal values have equal hashes regardless of how the arrays are encoded. Values are hashed by
+/// their bytes, struct rows by the hashes of their fields, and null values hash to [`NULL_HASH`].
+/// The hashes may differ between platforms and versions, so they must not be persisted.
+pub fn hash(array: &Array) -> VortexResult<OwnedArray> {
+    array.with_dyn(|a| {
+        if let Some(f) = a.hash() {
+            return f.hash();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("HashFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.hash()
+                .map(|f| f.hash())
+                .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "hash", array.encoding().id())))
+        })
+    })
+}
+
+/// The hash of a non-null value with the given bytes.
+pub fn hash_bytes(bytes: &[u8]) -> u64 {
+    HASHER.hash_one(bytes)
+}
+
+/// The hash of a boolean, primitive, utf8 or binary scalar, which is the same as its hash in any
+/// array.
+pub fn hash_scalar(scalar: &Scalar) -> VortexResult<u64> {
+    if scalar.is_null() {
+        return Ok(NULL_HASH);
+    }
+    Ok(match scalar {
+        Scalar::Bool(_) => hash_bytes(&[bool::try_from(scalar)? as u8]),
+        Scalar::Primitive(p) => match_each_native_ptype!(p.ptype(), |$T| {
+            hash_bytes(<$T>::try_from(scalar)?.as_bytes())
+        }),
+        Scalar::Utf8(_) => hash_bytes(String::try_from(scalar)?.as_bytes()),
+        Scalar::Binary(_) => hash_bytes(&Vec::<u8>::try_from(scalar)?),
+        _ => vortex_bail!("Cannot hash a scalar of type {}", scalar.dtype()),
+    })
+}
+
+/// Hash the values of an array at every valid index.
+pub(crate) fn hash_valid(
+    len: usize,
+    is_valid: impl Fn(usize) -> bool,
+    hash_at: impl Fn(usize) -> u64,
+) -> OwnedArray {
+    PrimitiveArray::from(
+        (0..len)
+            .map(|i| if is_valid(i) { hash_at(i) } else { NULL_HASH })
+            .collect::<Vec<_>>(),
+    )
+    .into_array()
+}
+
+/// Hash the values of an array of byte strings.
+pub(crate) fn hash_accessor(array: &impl ArrayAccessor<[u8]>) -> VortexResult<OwnedArray> {
+    array.with_iterator(|iter| {
+        PrimitiveArray::from(
+            iter.map(|v| v.map_or(NULL_HASH, hash_bytes))
+                .collect::<Vec<_>>(),
+        )
+        .into_array()
+    })
+}
+
+/// Combine the hashes of two values, e.g. the fields of a row, in an order dependent way.
+pub fn combine_hashes(hash: u64, other: u64) -> u64 {
+    HASHER.hash_one((hash, other))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::r#struct::StructArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::hash::{hash, hash_scalar, NULL_HASH};
+    use crate::validity::Validity;
+    use crate::{Array, IntoArray};
+
+    fn hashes(array: &Array) -> Vec<u64> {
+        hash(array)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<u64>()
+            .to_vec()
+    }
+
+    #[test]
+    fn hash_equal_values() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(5i64), None, Some(7), Some(5)])
+            .into_array();
+        let h = hashes(&values);
+        assert_eq!(h[0], h[3]);
+        assert_ne!(h[0], h[2]);
+        assert_eq!(h[1], NULL_HASH);
+        assert_eq!(h[0], hash_scalar(&5i64.into()).unwrap());
+
+        let constant = ConstantArray::new(7i64, 2).into_array();
+        assert_eq!(hashes(&constant), [h[2], h[2]]);
+
+        let chunked = ChunkedArray::try_new(
+            vec![
+                vec![7i64].into_array(),
+                ConstantArray::new(5i64, 1).into_array(),
+            ],
+            DType::Primitive(vortex_dtype::PType::I64, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(hashes(&chunked), [h[2], h[0]]);
+    }
+
+    #[test]
+    fn hash_strings_and_bools() {
+        let values = vec![Some("a"), None, Some("a long string value"), Some("a")];
+        let dtype = DType::Utf8(Nullability::Nullable);
+        let varbin = hashes(&VarBinArray::from_iter(values.clone(), dtype.clone()).into_array());
+        let view = hashes(&VarBinViewArray::from_iter(values, dtype).into_array());
+        assert_eq!(varbin, view);
+        assert_eq!(varbin[0], varbin[3]);
+        assert_eq!(varbin[1], NULL_HASH);
+        assert_eq!(
+            varbin[2],
+            hash_scalar(&"a long string value".into()).unwrap()
+        );
+
+        let bools = hashes(&BoolArray::from(vec![true, false, true]).into_array());
+        assert_eq!(bools[0], bools[2]);
+        assert_ne!(bools[0], bools[1]);
+        assert_eq!(bools[1], hash_scalar(&false.into()).unwrap());
+    }
+
+    #[test]
+    fn hash_struct_rows() {
+        let array = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                vec![1u8, 2, 1, 1].into_array(),
+                VarBinArray::from(vec!["x", "x", "x", "y"]).into_array(),
+            ],
+            4,
+            Validity::Array(BoolArray::from(vec![true, true, true, false]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+        let h = hashes(&array);
+        assert_eq!(h[0], h[2]);
+        assert_ne!(h[0], h[1]);
+        assert_eq!(h[3], NULL_HASH);
+        assert_eq!(
+            hash_scalar(&Scalar::null(&DType::Bool(Nullability::Nullable))).unwrap(),
+            NULL_HASH
+        );
+    }
+}
@@ -1,27 +1,57 @@
+use aggregate::{CountDistinctFn, MinMaxFn, SumFn};
+use arithmetic::{ArithmeticFn, ArithmeticScalarFn};
 use as_arrow::AsArrowArray;
 use as_contiguous::AsContiguousFn;
+use boolean::{BooleanFn, NotFn};
 use cast::CastFn;
+use compare::CompareFn;
 use fill::FillForwardFn;
+use filter::FilterFn;
+use hash::HashFn;
+use null::{FillNullFn, IsNullFn};
 use patch::PatchFn;
 use scalar_at::ScalarAtFn;
 use search_sorted::SearchSortedFn;
 use slice::SliceFn;
+use sort::ArgSortFn;
+use string::{StringLengthFn, StringMatchFn, StringTransformFn};
 use take::TakeFn;
+use unique::ValueCountsFn;
 
-use crate::compute::scalar_subtract::SubtractScalarFn;
-
+pub mod aggregate;
+pub mod arithmetic;
 pub mod as_arrow;
 pub mod as_contiguous;
+pub mod boolean;
 pub mod cast;
+pub mod compare;
 pub mod fill;
+pub mod filter;
+pub mod hash;
+pub mod null;
 pub mod patch;
 pub mod scalar_at;
 pub mod scalar_subtract;
 pub mod search_sorted;
 pub mod slice;
+pub mod sort;
+pub mod string;
 pub mod take;
+pub mod unique;
 
 pub trait ArrayCompute {
+    fn arithmetic(&self) -> Option<&dyn ArithmeticFn> {
+        None
+    }
+
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        None
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgSortFn> {
+        None
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         None
     }
@@ -30,23 +60,55 @@ pub trait ArrayCompute {
         None
     }
 
+    fn boolean(&self) -> Option<&dyn BooleanFn> {
+        None
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         None
     }
 
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        None
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        None
+    }
+
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         None
     }
 
-    fn patch(&self) -> Option<&dyn PatchFn> {
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
         None
     }
 
-    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        None
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        None
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
         None
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        None
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        None
+    }
+
+    fn patch(&self) -> Option<&dyn PatchFn> {
+        None
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         None
     }
 
@@ -58,7 +120,27 @@ pub trait ArrayCompute {
         None
     }
 
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        None
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        None
+    }
+
+    fn string_transform(&self) -> Option<&dyn StringTransformFn> {
+        None
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        None
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         None
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        None
+    }
 }
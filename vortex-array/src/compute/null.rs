@@ -0,0 +1,329 @@
+use itertools::Itertools;
+use log::info;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::constant::ConstantArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::as_contiguous::{as_contiguous, flatten_contiguous};
+use crate::compute::boolean::not;
+use crate::compute::cast::cast;
+use crate::compute::take::take;
+use crate::validity::LogicalValidity;
+use crate::{Array, ArrayDType, IntoArray, OwnedArray, ToStatic};
+
+pub trait IsNullFn {
+    /// A non-nullable boolean array that is true wherever the array is null, see [`is_null`].
+    fn is_null(&self) -> VortexResult<OwnedArray>;
+}
+
+pub trait FillNullFn {
+    /// Replace the null values of the array with the fill value, which is guaranteed to be a
+    /// non-null scalar of the array's non-nullable dtype.
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<OwnedArray>;
+}
+
+/// A non-nullable boolean array that is true wherever the array is null.
+///
+/// Arrays without a kernel are answered from their logical validity, so the data is never
+/// decompressed.
+pub fn is_null(array: &Array) -> VortexResult<OwnedArray> {
+    if !array.dtype().is_nullable() {
+        return Ok(ConstantArray::new(false, array.len()).into_array());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.is_null() {
+            return f.is_null();
+        }
+
+        match a.logical_validity() {
+            LogicalValidity::AllValid(len) => Ok(ConstantArray::new(false, len).into_array()),
+            LogicalValidity::AllInvalid(len) => Ok(ConstantArray::new(true, len).into_array()),
+            LogicalValidity::Array(validity) => not(&validity.into_array()),
+        }
+    })
+}
+
+/// A non-nullable boolean array that is true wherever the array is not null, which is its
+/// logical validity.
+pub fn is_not_null(array: &Array) -> VortexResult<OwnedArray> {
+    if !array.dtype().is_nullable() {
+        return Ok(ConstantArray::new(true, array.len()).into_array());
+    }
+
+    array.with_dyn(|a| match a.logical_validity() {
+        LogicalValidity::AllValid(len) => Ok(ConstantArray::new(true, len).into_array()),
+        LogicalValidity::AllInvalid(len) => Ok(ConstantArray::new(false, len).into_array()),
+        LogicalValidity::Array(validity) => Ok(validity.into_array()),
+    })
+}
+
+/// Replace every null value of the array with the fill value.
+///
+/// The fill value must be a non-null scalar of the array's dtype, and the result has no nulls.
+pub fn fill_null(array: &Array, fill_value: &Scalar) -> VortexResult<OwnedArray> {
+    if fill_value.is_null() || fill_value.dtype() != &array.dtype().as_nonnullable() {
+        vortex_bail!(
+            "Cannot fill the nulls of an array of {} with {}",
+            array.dtype(),
+            fill_value
+        );
+    }
+    if !array.dtype().is_nullable() {
+        return Ok(array.to_static());
+    }
+
+    array.with_dyn(|a| {
+        if a.logical_validity().all_invalid() {
+            return Ok(ConstantArray::new(fill_value.clone(), array.len()).into_array());
+        }
+
+        if let Some(f) = a.fill_null() {
+            return f.fill_null(fill_value);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("FillNullFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.fill_null()
+                .map(|f| f.fill_null(fill_value))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "fill_null", array.encoding().id()))
+                })
+        })
+    })
+}
+
+/// The first non-null value of the arrays at every index.
+///
+/// The arrays must have the same length and dtype, up to nullability. Later arrays are only
+/// looked at where all earlier arrays are null, and a constant array ends the search by filling
+/// the remaining nulls. The result is nullable if the first array is.
+pub fn coalesce(arrays: &[Array]) -> VortexResult<OwnedArray> {
+    let Some((first, rest)) = arrays.split_first() else {
+        vortex_bail!("Cannot coalesce zero arrays")
+    };
+    if !arrays.iter().map(|a| a.len()).all_equal() {
+        vortex_bail!("Cannot coalesce arrays of different lengths");
+    }
+    if let Some(other) = rest
+        .iter()
+        .find(|a| !a.dtype().eq_ignore_nullability(first.dtype()))
+    {
+        vortex_bail!(
+            "Cannot coalesce arrays of {} and {}",
+            first.dtype(),
+            other.dtype()
+        );
+    }
+
+    let mut result = first.to_static();
+    for array in rest {
+        let validity = result.with_dyn(|a| a.logical_validity());
+        if validity.all_valid() {
+            break;
+        }
+        if array.with_dyn(|a| a.logical_validity()).all_invalid() {
+            continue;
+        }
+        if let Ok(constant) = ConstantArray::try_from(array) {
+            let fill_value = constant.scalar().clone();
+            if fill_value.dtype().is_nullable() {
+                // A nullable scalar can't fill the nulls, so pick it at every null index instead.
+                result = select_valid(&result, validity, array)?;
+            } else {
+                result = fill_null(&result, &fill_value)?;
+            }
+            break;
+        }
+        result = select_valid(&result, validity, array)?;
+    }
+    Ok(result)
+}
+
+/// Take the values of the array where it is valid, and the values of the other array elsewhere.
+fn select_valid(
+    array: &Array,
+    validity: LogicalValidity,
+    other: &Array,
+) -> VortexResult<OwnedArray> {
+    let len = array.len() as u64;
+    let valid = validity.to_present_null_buffer()?;
+    let indices = PrimitiveArray::from(
+        valid
+            .iter()
+            .zip(0..len)
+            .map(|(is_valid, i)| if is_valid { i } else { len + i })
+            .collect_vec(),
+    );
+    // The other array may differ in nullability and encoding, so bring both to the array's dtype
+    // and the same encoding before concatenating them.
+    let other = cast(other, array.dtype())?;
+    let both = as_contiguous(&[flatten_contiguous(array)?, flatten_contiguous(&other)?])?;
+    take(&both, indices.array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::sparse::SparseArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::null::{coalesce, fill_null, is_not_null, is_null};
+    use crate::compute::scalar_at::{scalar_at, scalar_values};
+    use crate::{Array, ArrayDType, IntoArray};
+
+    fn bools(array: &Array) -> Vec<bool> {
+        array
+            .clone()
+            .flatten_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn is_null_masks() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array();
+        assert_eq!(bools(&is_null(&array).unwrap()), [false, true, false]);
+        assert_eq!(bools(&is_not_null(&array).unwrap()), [true, false, true]);
+        assert!(!is_null(&array).unwrap().dtype().is_nullable());
+
+        let non_nullable = vec![1i32, 2].into_array();
+        assert!(ConstantArray::try_from(&is_null(&non_nullable).unwrap()).is_ok());
+
+        let null =
+            ConstantArray::new(Scalar::null(&DType::Bool(Nullability::Nullable)), 2).into_array();
+        assert_eq!(bools(&is_null(&null).unwrap()), [true, true]);
+    }
+
+    #[test]
+    fn is_null_sparse() {
+        let fill = Scalar::null(&DType::Primitive(
+            vortex_dtype::PType::I32,
+            Nullability::Nullable,
+        ));
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array();
+        let sparse = SparseArray::new(vec![1u64, 3].into_array(), values, 5, fill).into_array();
+
+        let nulls = is_null(&sparse).unwrap();
+        assert!(SparseArray::try_from(&nulls).is_ok());
+        assert_eq!(bools(&nulls), [true, false, true, true, true]);
+        assert_eq!(
+            bools(&is_not_null(&sparse).unwrap()),
+            [false, true, false, false, false]
+        );
+
+        let filled = fill_null(&sparse, &7i32.into()).unwrap();
+        assert!(SparseArray::try_from(&filled).is_ok());
+        assert!(!filled.dtype().is_nullable());
+        assert_eq!(
+            scalar_values::<i32>(&filled).unwrap(),
+            [Some(7), Some(1), Some(7), Some(7), Some(7)]
+        );
+    }
+
+    #[test]
+    fn fill_null_encodings() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array();
+        let filled = fill_null(&array, &0i32.into()).unwrap();
+        assert!(!filled.dtype().is_nullable());
+        assert_eq!(
+            scalar_values::<i32>(&filled).unwrap(),
+            [Some(1), Some(0), Some(3)]
+        );
+        assert!(fill_null(&array, &0i64.into()).is_err());
+
+        let bools_array = BoolArray::from_iter([Some(true), None]).into_array();
+        assert_eq!(
+            bools(&fill_null(&bools_array, &false.into()).unwrap()),
+            [true, false]
+        );
+
+        let strings =
+            VarBinArray::from_iter(vec![Some("a"), None], DType::Utf8(Nullability::Nullable))
+                .into_array();
+        let filled = fill_null(&strings, &"b".into()).unwrap();
+        assert_eq!(
+            String::try_from(scalar_at(&filled, 1).unwrap()).unwrap(),
+            "b"
+        );
+
+        let chunked = ChunkedArray::try_new(
+            vec![
+                array.clone(),
+                ConstantArray::new(
+                    Scalar::null(&DType::Primitive(
+                        vortex_dtype::PType::I32,
+                        Nullability::Nullable,
+                    )),
+                    2,
+                )
+                .into_array(),
+            ],
+            array.dtype().clone(),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(
+            scalar_values::<i32>(&fill_null(&chunked, &9i32.into()).unwrap()).unwrap(),
+            [Some(1), Some(9), Some(3), Some(9), Some(9)]
+        );
+    }
+
+    #[test]
+    fn coalesce_arrays() {
+        let a = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None, None]).into_array();
+        let b = PrimitiveArray::from_nullable_vec(vec![None, Some(2i32), None, None]).into_array();
+        assert_eq!(
+            scalar_values::<i32>(&coalesce(&[a.clone(), b.clone()]).unwrap()).unwrap(),
+            [Some(1), Some(2), None, None]
+        );
+        assert_eq!(
+            scalar_values::<i32>(
+                &coalesce(&[
+                    a.clone(),
+                    b.clone(),
+                    ConstantArray::new(5i32, 4).into_array()
+                ])
+                .unwrap()
+            )
+            .unwrap(),
+            [Some(1), Some(2), Some(5), Some(5)]
+        );
+
+        assert!(coalesce(&[]).is_err());
+        assert!(coalesce(&[a.clone(), vec![1i32].into_array()]).is_err());
+        assert!(coalesce(&[a, vec![1u8, 2, 3, 4].into_array()]).is_err());
+    }
+
+    #[test]
+    fn coalesce_mixed_arrays() {
+        let nullable =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), None]).into_array();
+        let non_nullable = vec![5i32, 6, 7, 8].into_array();
+        let coalesced = coalesce(&[nullable, non_nullable]).unwrap();
+        assert!(coalesced.dtype().is_nullable());
+        assert_eq!(
+            scalar_values::<i32>(&coalesced).unwrap(),
+            [Some(1), Some(6), Some(3), Some(8)]
+        );
+
+        let dtype = DType::Utf8(Nullability::Nullable);
+        let varbin = VarBinArray::from_iter(vec![Some("a"), None, None], dtype.clone());
+        let view = VarBinViewArray::from_iter(vec![Some("b"), Some("c"), None], dtype.clone());
+        let coalesced = coalesce(&[varbin.into_array(), view.into_array()]).unwrap();
+        assert_eq!(
+            scalar_values::<String>(&coalesced).unwrap(),
+            [Some("a".to_string()), Some("c".to_string()), None]
+        );
+    }
+}
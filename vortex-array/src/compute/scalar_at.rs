@@ -1,4 +1,4 @@
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::Array;
@@ -18,3 +18,20 @@ pub fn scalar_at(array: &Array, index: usize) -> VortexResult<Scalar> {
             .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "scalar_at", array.encoding().id())))
     })
 }
+
+/// The values of the array converted to `T`, or `None` where they are null.
+pub fn scalar_values<T>(array: &Array) -> VortexResult<Vec<Option<T>>>
+where
+    T: TryFrom<Scalar, Error = VortexError>,
+{
+    (0..array.len())
+        .map(|i| {
+            let scalar = scalar_at(array, i)?;
+            if scalar.is_null() {
+                Ok(None)
+            } else {
+                T::try_from(scalar).map(Some)
+            }
+        })
+        .collect()
+}
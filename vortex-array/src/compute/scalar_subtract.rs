@@ -1,31 +1,148 @@
-use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::{Array, ArrayDType, OwnedArray};
+use crate::compute::arithmetic::{arithmetic_scalar, BinaryOperator, OverflowMode};
+use crate::{Array, OwnedArray};
 
-pub trait SubtractScalarFn {
-    fn subtract_scalar(&self, to_subtract: &Scalar) -> VortexResult<OwnedArray>;
+/// Subtract the scalar from every value of the array, failing if any integer value overflows.
+pub fn subtract_scalar(array: &Array, to_subtract: &Scalar) -> VortexResult<OwnedArray> {
+    arithmetic_scalar(
+        array,
+        to_subtract,
+        BinaryOperator::Sub,
+        OverflowMode::Checked,
+    )
 }
 
-pub fn subtract_scalar(array: &Array, to_subtract: &Scalar) -> VortexResult<OwnedArray> {
-    if let Some(subtraction_result) =
-        array.with_dyn(|c| c.subtract_scalar().map(|t| t.subtract_scalar(to_subtract)))
-    {
-        return subtraction_result;
-    }
-    // if subtraction is not implemented for the given array type, but the array has a numeric
-    // DType, we can flatten the array and apply subtraction to the flattened primitive array
-    match array.dtype() {
-        DType::Primitive(..) => {
-            // TODO(@jcasale): pass array instead of ref to get rid of clone?
-            // downside is that subtract_scalar then consumes the array, which is not great
-            let flat = array.clone().flatten_primitive()?;
-            flat.subtract_scalar(to_subtract)
-        }
-        _ => Err(vortex_err!(
-            NotImplemented: "scalar_subtract",
-            array.encoding().id()
-        )),
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_scalar::Scalar;
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::scalar_subtract::subtract_scalar;
+    use crate::{ArrayTrait, IntoArray};
+
+    #[test]
+    fn test_scalar_subtract_unsigned() {
+        let values = vec![1u16, 2, 3].into_array();
+        let results = subtract_scalar(&values, &1u16.into())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<u16>()
+            .to_vec();
+        assert_eq!(results, &[0u16, 1, 2]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_signed() {
+        let values = vec![1i64, 2, 3].into_array();
+        let results = subtract_scalar(&values, &(-1i64).into())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<i64>()
+            .to_vec();
+        assert_eq!(results, &[2i64, 3, 4]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_nullable() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1u16), Some(2), None, Some(3)])
+            .into_array();
+        let flattened = subtract_scalar(&values, &Some(1u16).into())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+
+        let results = flattened.typed_data::<u16>().to_vec();
+        assert_eq!(results, &[0u16, 1, 0, 2]);
+        let valid_indices = flattened
+            .validity()
+            .to_logical(flattened.len())
+            .to_null_buffer()
+            .unwrap()
+            .unwrap()
+            .valid_indices()
+            .collect_vec();
+        assert_eq!(valid_indices, &[0, 1, 3]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_float() {
+        let values = vec![1.0f64, 2.0, 3.0].into_array();
+        let to_subtract = -1f64;
+        let results = subtract_scalar(&values, &to_subtract.into())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<f64>()
+            .to_vec();
+        assert_eq!(results, &[2.0f64, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_unsigned_underflow() {
+        let values = vec![u8::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1u8.into()).expect_err("should fail with underflow");
+        let values = vec![u16::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1u16.into()).expect_err("should fail with underflow");
+        let values = vec![u32::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1u32.into()).expect_err("should fail with underflow");
+        let values = vec![u64::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1u64.into()).expect_err("should fail with underflow");
+    }
+
+    #[test]
+    fn test_scalar_subtract_signed_overflow() {
+        let values = vec![i8::MAX, 2, 3].into_array();
+        let to_subtract: Scalar = (-1i8).into();
+        let _results =
+            subtract_scalar(&values, &to_subtract).expect_err("should fail with overflow");
+        let values = vec![i16::MAX, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &to_subtract).expect_err("should fail with overflow");
+        let values = vec![i32::MAX, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &to_subtract).expect_err("should fail with overflow");
+        let values = vec![i64::MAX, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &to_subtract).expect_err("should fail with overflow");
+    }
+
+    #[test]
+    fn test_scalar_subtract_signed_underflow() {
+        let values = vec![i8::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1i8.into()).expect_err("should fail with underflow");
+        let values = vec![i16::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1i16.into()).expect_err("should fail with underflow");
+        let values = vec![i32::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1i32.into()).expect_err("should fail with underflow");
+        let values = vec![i64::MIN, 2, 3].into_array();
+        let _results =
+            subtract_scalar(&values, &1i64.into()).expect_err("should fail with underflow");
+    }
+
+    #[test]
+    fn test_scalar_subtract_float_underflow_is_ok() {
+        let values = vec![f32::MIN, 2.0, 3.0].into_array();
+        let _results = subtract_scalar(&values, &1.0f32.into()).unwrap();
+        let _results = subtract_scalar(&values, &f32::MAX.into()).unwrap();
+    }
+
+    #[test]
+    fn test_scalar_subtract_type_mismatch_fails() {
+        let values = vec![1u64, 2, 3].into_array();
+        // Subtracting incompatible dtypes should fail
+        let _results =
+            subtract_scalar(&values, &1.5f64.into()).expect_err("Expected type mismatch error");
     }
 }
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use log::info;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::take::take;
+use crate::{Array, IntoArray, OwnedArray};
+
+/// How to order the values of an array.
+///
+/// The default sorts ascending with the null values last.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SortOptions {
+    /// Sort from the largest to the smallest value.
+    pub descending: bool,
+    /// Place the null values before all other values.
+    pub nulls_first: bool,
+}
+
+impl SortOptions {
+    pub fn ascending() -> Self {
+        Self::default()
+    }
+
+    pub fn descending() -> Self {
+        Self {
+            descending: true,
+            nulls_first: false,
+        }
+    }
+
+    pub fn with_descending(self, descending: bool) -> Self {
+        Self { descending, ..self }
+    }
+
+    pub fn with_nulls_first(self, nulls_first: bool) -> Self {
+        Self {
+            nulls_first,
+            ..self
+        }
+    }
+}
+
+pub trait ArgSortFn {
+    /// The indices that stably sort the array, see [`argsort`].
+    fn argsort(&self, options: SortOptions) -> VortexResult<OwnedArray>;
+}
+
+/// The u64 indices that would sort the array, where equal values keep their original order.
+///
+/// Floating point NaN values are larger than all other values, and binary values are compared
+/// bytewise.
+pub fn argsort(array: &Array, options: SortOptions) -> VortexResult<OwnedArray> {
+    if array.is_empty() {
+        return Ok(PrimitiveArray::from(Vec::<u64>::new()).into_array());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.argsort() {
+            return f.argsort(options);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("ArgSortFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.argsort().map(|f| f.argsort(options)).unwrap_or_else(|| {
+                Err(vortex_err!(NotImplemented: "argsort", array.encoding().id()))
+            })
+        })
+    })
+}
+
+/// Stably sort the array, see [`argsort`].
+pub fn sort(array: &Array, options: SortOptions) -> VortexResult<OwnedArray> {
+    take(array, &argsort(array, options)?)
+}
+
+/// The u64 indices that would lexicographically sort the rows of the columns.
+///
+/// Rows are ordered by the first column, with ties broken by each following column in turn, and
+/// rows that are equal in all columns keep their original order.
+pub fn lexsort(columns: &[Array], options: &[SortOptions]) -> VortexResult<OwnedArray> {
+    if columns.len() != options.len() {
+        vortex_bail!(
+            "Expected sort options for each of the {} columns, got {}",
+            columns.len(),
+            options.len()
+        );
+    }
+    let Some(len) = columns.iter().map(|c| c.len()).dedup().exactly_one().ok() else {
+        vortex_bail!("Expected at least one column, all of the same length")
+    };
+
+    // Stable sorts from the least to the most significant column leave the rows in lexicographic
+    // order, since each sort keeps the order of the rows it considers equal.
+    let mut indices = PrimitiveArray::from((0..len as u64).collect_vec()).into_array();
+    for (column, column_options) in columns.iter().zip(options).rev() {
+        let sorted = take(column, &indices)?;
+        let order = argsort(&sorted, *column_options)?;
+        indices = take(&indices, &order)?;
+    }
+    Ok(indices)
+}
+
+/// Stably sort the indices `0..len` with the given comparison of two valid indices.
+pub(crate) fn argsort_by(
+    len: usize,
+    options: SortOptions,
+    is_valid: impl Fn(usize) -> bool,
+    compare: impl Fn(usize, usize) -> Ordering,
+) -> OwnedArray {
+    let (mut valid, nulls): (Vec<_>, Vec<_>) = (0..len).partition(|&i| is_valid(i));
+    if options.descending {
+        valid.sort_by(|&a, &b| compare(b, a));
+    } else {
+        valid.sort_by(|&a, &b| compare(a, b));
+    }
+
+    let indices = if options.nulls_first {
+        nulls.into_iter().chain(valid)
+    } else {
+        valid.into_iter().chain(nulls)
+    };
+    PrimitiveArray::from(indices.map(|i| i as u64).collect_vec()).into_array()
+}
+
+/// Stably sort the indices of an array of byte strings, comparing them bytewise.
+pub(crate) fn argsort_bytes(
+    array: &impl ArrayAccessor<[u8]>,
+    options: SortOptions,
+) -> VortexResult<OwnedArray> {
+    array.with_iterator(|iter| {
+        let values = iter.collect_vec();
+        argsort_by(
+            values.len(),
+            options,
+            |i| values[i].is_some(),
+            |a, b| values[a].cmp(&values[b]),
+        )
+    })
+}
+
+/// A total order that places NaN values after all other values.
+pub(crate) fn total_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    #[allow(clippy::eq_op)]
+    a.partial_cmp(b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::bool::BoolArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::r#struct::StructArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::sort::{argsort, lexsort, sort, SortOptions};
+    use crate::validity::Validity;
+    use crate::{Array, IntoArray};
+
+    fn indices(array: &Array, options: SortOptions) -> Vec<u64> {
+        argsort(array, options)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<u64>()
+            .to_vec()
+    }
+
+    #[test]
+    fn argsort_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(1), Some(3), Some(-2)])
+                .into_array();
+        assert_eq!(indices(&array, SortOptions::ascending()), [4, 2, 0, 3, 1]);
+        assert_eq!(
+            indices(&array, SortOptions::ascending().with_nulls_first(true)),
+            [1, 4, 2, 0, 3]
+        );
+        assert_eq!(indices(&array, SortOptions::descending()), [0, 3, 2, 4, 1]);
+    }
+
+    #[test]
+    fn sort_floats() {
+        let array = vec![2.5f64, f64::NAN, -1.0, f64::INFINITY].into_array();
+        let sorted = sort(&array, SortOptions::ascending())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        let values = sorted.typed_data::<f64>();
+        assert_eq!(&values[..3], [-1.0, 2.5, f64::INFINITY]);
+        assert!(values[3].is_nan());
+    }
+
+    #[test]
+    fn sort_strings() {
+        let values = vec![Some("b"), None, Some("abc"), Some("ab"), Some("b")];
+        let dtype = DType::Utf8(Nullability::Nullable);
+        let varbin = VarBinArray::from_iter(values.clone(), dtype.clone()).into_array();
+        let view = VarBinViewArray::from_iter(values, dtype).into_array();
+        for array in [varbin, view] {
+            assert_eq!(indices(&array, SortOptions::ascending()), [3, 2, 0, 4, 1]);
+            let sorted = sort(&array, SortOptions::descending()).unwrap();
+            assert_eq!(
+                String::try_from(scalar_at(&sorted, 0).unwrap()).unwrap(),
+                "b"
+            );
+            assert_eq!(
+                String::try_from(scalar_at(&sorted, 3).unwrap()).unwrap(),
+                "ab"
+            );
+            assert!(scalar_at(&sorted, 4).unwrap().is_null());
+        }
+    }
+
+    #[test]
+    fn argsort_bool_and_constant() {
+        let bools = BoolArray::from(vec![true, false, true, false]).into_array();
+        assert_eq!(indices(&bools, SortOptions::ascending()), [1, 3, 0, 2]);
+        assert_eq!(indices(&bools, SortOptions::descending()), [0, 2, 1, 3]);
+
+        let constant = ConstantArray::new(7u8, 3).into_array();
+        assert_eq!(indices(&constant, SortOptions::descending()), [0, 1, 2]);
+    }
+
+    #[test]
+    fn lexsort_struct() {
+        let first = vec![2u8, 1, 2, 1, 2].into_array();
+        let second = VarBinArray::from(vec!["x", "y", "a", "y", "b"]).into_array();
+        assert_eq!(
+            lexsort(
+                &[first.clone(), second.clone()],
+                &[SortOptions::ascending(), SortOptions::descending()]
+            )
+            .unwrap()
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<u64>(),
+            [1, 3, 0, 4, 2]
+        );
+
+        let array = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![first, second],
+            5,
+            Validity::Array(BoolArray::from(vec![true, true, true, false, true]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(
+            indices(&array, SortOptions::ascending().with_nulls_first(true)),
+            [3, 1, 2, 4, 0]
+        );
+    }
+
+    #[test]
+    fn lexsort_mismatched_columns() {
+        let a = vec![1u8, 2].into_array();
+        let b = vec![1u8].into_array();
+        assert!(lexsort(&[a.clone(), b], &[SortOptions::default(); 2]).is_err());
+        assert!(lexsort(&[a], &[]).is_err());
+        assert!(lexsort(&[], &[]).is_err());
+    }
+}
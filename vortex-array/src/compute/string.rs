@@ -0,0 +1,560 @@
+use std::borrow::Cow;
+use std::iter;
+
+use arrow_buffer::BooleanBuffer;
+use log::info;
+use vortex_dtype::{DType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayData, OwnedArray};
+
+pub trait StringMatchFn {
+    /// A boolean array that is true wherever the value matches the predicate, and null wherever
+    /// the value is null.
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray>;
+}
+
+pub trait StringLengthFn {
+    /// The u64 length of every value, see [`length`].
+    fn string_length(&self) -> VortexResult<OwnedArray>;
+}
+
+pub trait StringTransformFn {
+    /// Apply the transform to every value of a utf8 array, keeping the nulls.
+    fn string_transform(&self, transform: &StringTransform) -> VortexResult<OwnedArray>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringPredicate {
+    StartsWith(Vec<u8>),
+    EndsWith(Vec<u8>),
+    Contains(Vec<u8>),
+    Like(LikePattern),
+}
+
+impl StringPredicate {
+    /// A SQL LIKE predicate, simplified to a prefix, suffix or substring check where the pattern
+    /// allows it.
+    pub fn like(pattern: &str) -> Self {
+        let pattern = LikePattern::new(pattern);
+        let tokens = pattern.tokens.as_slice();
+        let literal = |tokens: &[LikeToken]| -> Option<Vec<u8>> {
+            tokens
+                .iter()
+                .map(|t| match t {
+                    LikeToken::Byte(b) => Some(*b),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        match tokens {
+            [LikeToken::Any, inner @ .., LikeToken::Any] => literal(inner).map(Self::Contains),
+            [inner @ .., LikeToken::Any] => literal(inner).map(Self::StartsWith),
+            [LikeToken::Any, inner @ ..] => literal(inner).map(Self::EndsWith),
+            _ => None,
+        }
+        .unwrap_or(Self::Like(pattern))
+    }
+
+    pub fn matches(&self, value: &[u8]) -> bool {
+        match self {
+            Self::StartsWith(prefix) => value.starts_with(prefix),
+            Self::EndsWith(suffix) => value.ends_with(suffix),
+            Self::Contains(needle) => {
+                needle.is_empty() || value.windows(needle.len()).any(|w| w == needle)
+            }
+            Self::Like(pattern) => pattern.matches(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LikeToken {
+    /// `%`, any sequence of characters.
+    Any,
+    /// `_`, exactly one character.
+    One,
+    Byte(u8),
+}
+
+/// A SQL LIKE pattern, where `%` matches any sequence of characters, `_` matches a single
+/// character and a backslash escapes the next character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LikePattern {
+    tokens: Vec<LikeToken>,
+}
+
+impl LikePattern {
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => {
+                    // Consecutive wildcards are equivalent to one.
+                    if tokens.last() != Some(&LikeToken::Any) {
+                        tokens.push(LikeToken::Any);
+                    }
+                }
+                '_' => tokens.push(LikeToken::One),
+                _ => {
+                    let c = if c == '\\' {
+                        chars.next().unwrap_or(c)
+                    } else {
+                        c
+                    };
+                    let mut buf = [0u8; 4];
+                    tokens.extend(c.encode_utf8(&mut buf).bytes().map(LikeToken::Byte));
+                }
+            }
+        }
+        Self { tokens }
+    }
+
+    pub fn matches(&self, value: &[u8]) -> bool {
+        let tokens = self.tokens.as_slice();
+        let (mut t, mut v) = (0, 0);
+        // The position after the last `%`, and the next value position it should be tried at.
+        let mut backtrack: Option<(usize, usize)> = None;
+
+        while v < value.len() {
+            match tokens.get(t) {
+                Some(LikeToken::Any) => {
+                    t += 1;
+                    backtrack = Some((t, v));
+                    continue;
+                }
+                Some(LikeToken::One) => {
+                    t += 1;
+                    v = (v + char_width(value[v])).min(value.len());
+                    continue;
+                }
+                Some(LikeToken::Byte(b)) if *b == value[v] => {
+                    t += 1;
+                    v += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            // Let the last `%` swallow one more character and try again.
+            let Some((bt, bv)) = backtrack else {
+                return false;
+            };
+            let next = (bv + char_width(value[bv])).min(value.len());
+            backtrack = Some((bt, next));
+            t = bt;
+            v = next;
+        }
+
+        tokens[t..].iter().all(|t| *t == LikeToken::Any)
+    }
+}
+
+/// The number of bytes of the UTF-8 character starting with the byte.
+fn char_width(byte: u8) -> usize {
+    match byte.leading_ones() {
+        0 => 1,
+        n => n as usize,
+    }
+}
+
+/// Whether the byte starts a UTF-8 character, i.e. is not a continuation byte.
+fn is_char_boundary(byte: u8) -> bool {
+    (byte as i8) >= -0x40
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringTransform {
+    Lower,
+    Upper,
+    /// The characters from `start`, up to `length` of them or to the end of the value.
+    Substring {
+        start: usize,
+        length: Option<usize>,
+    },
+}
+
+impl StringTransform {
+    /// Transform a UTF-8 value, borrowing it where the result is a slice of it.
+    pub fn apply<'a>(&self, value: &'a [u8]) -> Cow<'a, [u8]> {
+        match self {
+            Self::Lower => String::from_utf8_lossy(value)
+                .to_lowercase()
+                .into_bytes()
+                .into(),
+            Self::Upper => String::from_utf8_lossy(value)
+                .to_uppercase()
+                .into_bytes()
+                .into(),
+            Self::Substring { start, length } => {
+                let mut boundaries = value
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, b)| is_char_boundary(**b))
+                    .map(|(i, _)| i)
+                    .chain(iter::once(value.len()));
+                let begin = boundaries.nth(*start).unwrap_or(value.len());
+                let end = match length {
+                    Some(0) => begin,
+                    Some(n) => boundaries.nth(n - 1).unwrap_or(value.len()),
+                    None => value.len(),
+                };
+                Cow::Borrowed(&value[begin..end])
+            }
+        }
+    }
+}
+
+/// Evaluate a SQL LIKE pattern against every value of a utf8 or binary array.
+pub fn like(array: &Array, pattern: &str) -> VortexResult<OwnedArray> {
+    string_match(array, &StringPredicate::like(pattern))
+}
+
+pub fn starts_with(array: &Array, prefix: &str) -> VortexResult<OwnedArray> {
+    string_match(
+        array,
+        &StringPredicate::StartsWith(prefix.as_bytes().to_vec()),
+    )
+}
+
+pub fn ends_with(array: &Array, suffix: &str) -> VortexResult<OwnedArray> {
+    string_match(
+        array,
+        &StringPredicate::EndsWith(suffix.as_bytes().to_vec()),
+    )
+}
+
+pub fn contains(array: &Array, needle: &str) -> VortexResult<OwnedArray> {
+    string_match(
+        array,
+        &StringPredicate::Contains(needle.as_bytes().to_vec()),
+    )
+}
+
+/// Match every value of a utf8 or binary array against the predicate.
+///
+/// The result is a boolean array with the nullability of the input, null wherever the value is.
+pub fn string_match(array: &Array, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+    if !matches!(array.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+        vortex_bail!(MismatchedTypes: "utf8 or binary", array.dtype());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.string_match() {
+            return f.string_match(predicate);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("StringMatchFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.string_match()
+                .map(|f| f.string_match(predicate))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "string_match", array.encoding().id()))
+                })
+        })
+    })
+}
+
+/// The length of every value of a utf8 or binary array, in characters for utf8 and in bytes for
+/// binary.
+///
+/// The result is a u64 array with the nullability of the input.
+pub fn length(array: &Array) -> VortexResult<OwnedArray> {
+    if !matches!(array.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+        vortex_bail!(MismatchedTypes: "utf8 or binary", array.dtype());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.string_length() {
+            return f.string_length();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("StringLengthFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.string_length()
+                .map(|f| f.string_length())
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "string_length", array.encoding().id()))
+                })
+        })
+    })
+}
+
+pub fn lower(array: &Array) -> VortexResult<OwnedArray> {
+    string_transform(array, &StringTransform::Lower)
+}
+
+pub fn upper(array: &Array) -> VortexResult<OwnedArray> {
+    string_transform(array, &StringTransform::Upper)
+}
+
+/// The characters of every value from `start`, up to `length` of them or to the end of the value.
+pub fn substring(array: &Array, start: usize, length: Option<usize>) -> VortexResult<OwnedArray> {
+    string_transform(array, &StringTransform::Substring { start, length })
+}
+
+/// Apply the transform to every value of a utf8 array, keeping its dtype.
+pub fn string_transform(array: &Array, transform: &StringTransform) -> VortexResult<OwnedArray> {
+    if !matches!(array.dtype(), DType::Utf8(_)) {
+        vortex_bail!(MismatchedTypes: "utf8", array.dtype());
+    }
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.string_transform() {
+            return f.string_transform(transform);
+        }
+
+        // Otherwise, flatten and try again.
+        info!(
+            "StringTransformFn not implemented for {}, flattening",
+            array
+        );
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.string_transform()
+                .map(|f| f.string_transform(transform))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "string_transform", array.encoding().id()))
+                })
+        })
+    })
+}
+
+/// Match every value of the accessor, where the validity is that of the array.
+pub(crate) fn string_match_accessor(
+    array: &impl ArrayAccessor<[u8]>,
+    predicate: &StringPredicate,
+    validity: Validity,
+) -> VortexResult<OwnedArray> {
+    let buffer = array.with_iterator(|iter| {
+        BooleanBuffer::from_iter(iter.map(|v| v.map_or(false, |v| predicate.matches(v))))
+    })?;
+    BoolArray::try_new(buffer, validity).map(|a| a.into_array_data().into_array())
+}
+
+/// The length of every value of the accessor, where the validity is that of the array.
+pub(crate) fn string_length_accessor(
+    array: &impl ArrayAccessor<[u8]>,
+    dtype: &DType,
+    validity: Validity,
+) -> VortexResult<OwnedArray> {
+    let utf8 = matches!(dtype, DType::Utf8(_));
+    let lengths = array.with_iterator(|iter| {
+        iter.map(|v| {
+            v.map_or(0, |v| {
+                if utf8 {
+                    v.iter().filter(|b| is_char_boundary(**b)).count() as u64
+                } else {
+                    v.len() as u64
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+    })?;
+    Ok(PrimitiveArray::from_vec(lengths, validity)
+        .into_array_data()
+        .into_array())
+}
+
+/// The dtype of the lengths of an array of the dtype.
+pub(crate) fn string_length_dtype(dtype: &DType) -> DType {
+    DType::Primitive(PType::U64, dtype.nullability())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::scalar_at::{scalar_at, scalar_values};
+    use crate::compute::string::{
+        contains, ends_with, length, like, lower, starts_with, substring, upper, StringPredicate,
+    };
+    use crate::{ArrayDType, IntoArray, OwnedArray};
+
+    fn encodings(values: &[Option<&str>]) -> Vec<OwnedArray> {
+        let dtype = DType::Utf8(Nullability::Nullable);
+        let varbin = VarBinArray::from_iter(values.iter().copied(), dtype.clone()).into_array();
+        let view = VarBinViewArray::from_iter(values.iter().copied(), dtype.clone()).into_array();
+        let (head, tail) = values.split_at(values.len() / 2);
+        let chunked = ChunkedArray::try_new(
+            vec![
+                VarBinArray::from_iter(head.iter().copied(), dtype.clone()).into_array(),
+                VarBinViewArray::from_iter(tail.iter().copied(), dtype.clone()).into_array(),
+            ],
+            dtype,
+        )
+        .unwrap()
+        .into_array();
+        vec![varbin, view, chunked]
+    }
+
+    #[test]
+    fn like_patterns() {
+        assert_eq!(
+            StringPredicate::like("abc%"),
+            StringPredicate::StartsWith(b"abc".to_vec())
+        );
+        assert_eq!(
+            StringPredicate::like("%abc"),
+            StringPredicate::EndsWith(b"abc".to_vec())
+        );
+        assert_eq!(
+            StringPredicate::like("%%abc%"),
+            StringPredicate::Contains(b"abc".to_vec())
+        );
+        assert!(matches!(
+            StringPredicate::like("a_c"),
+            StringPredicate::Like(_)
+        ));
+
+        let matches =
+            |pattern: &str, value: &str| StringPredicate::like(pattern).matches(value.as_bytes());
+        assert!(matches("a_c", "abc"));
+        assert!(matches("a_c", "aéc"));
+        assert!(!matches("a_c", "abbc"));
+        assert!(matches("a%b%c", "aXXbYYbZc"));
+        assert!(!matches("a%b%c", "aXXbYYbZ"));
+        assert!(matches("%", ""));
+        assert!(matches("", ""));
+        assert!(!matches("", "a"));
+        assert!(matches("100\\%", "100%"));
+        assert!(!matches("100\\%", "1000"));
+        assert!(matches("%n_", "banana"));
+        assert!(!matches("%a_", "banana"));
+    }
+
+    #[test]
+    fn match_encodings() {
+        let values = [
+            Some("apple"),
+            None,
+            Some("a much longer apple pie"),
+            Some("banana"),
+            Some("apricots and plums"),
+            Some("pineapple"),
+        ];
+        for array in encodings(&values) {
+            assert_eq!(
+                scalar_values::<bool>(&starts_with(&array, "ap").unwrap()).unwrap(),
+                [
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(false),
+                    Some(true),
+                    Some(false)
+                ]
+            );
+            assert_eq!(
+                scalar_values::<bool>(&starts_with(&array, "apricot").unwrap()).unwrap(),
+                [
+                    Some(false),
+                    None,
+                    Some(false),
+                    Some(false),
+                    Some(true),
+                    Some(false)
+                ]
+            );
+            assert_eq!(
+                scalar_values::<bool>(&ends_with(&array, "apple").unwrap()).unwrap(),
+                [
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(false),
+                    Some(false),
+                    Some(true)
+                ]
+            );
+            assert_eq!(
+                scalar_values::<bool>(&contains(&array, "apple").unwrap()).unwrap(),
+                [
+                    Some(true),
+                    None,
+                    Some(true),
+                    Some(false),
+                    Some(false),
+                    Some(true)
+                ]
+            );
+            assert_eq!(
+                scalar_values::<bool>(&like(&array, "%a_a%").unwrap()).unwrap(),
+                [
+                    Some(false),
+                    None,
+                    Some(false),
+                    Some(true),
+                    Some(false),
+                    Some(false)
+                ]
+            );
+        }
+        assert!(starts_with(&vec![1i32].into_array(), "a").is_err());
+    }
+
+    #[test]
+    fn length_and_transforms() {
+        let values = [
+            Some("Straße"),
+            None,
+            Some("a much longer ÉTÉ string"),
+            Some(""),
+        ];
+        for array in encodings(&values) {
+            let lengths = length(&array).unwrap();
+            assert!(lengths.dtype().is_nullable());
+            assert_eq!(
+                (0..lengths.len())
+                    .map(|i| u64::try_from(scalar_at(&lengths, i).unwrap()).ok())
+                    .collect::<Vec<_>>(),
+                [Some(6), None, Some(24), Some(0)]
+            );
+
+            assert_eq!(
+                scalar_values::<String>(&upper(&array).unwrap()).unwrap(),
+                [
+                    Some("STRASSE".to_string()),
+                    None,
+                    Some("A MUCH LONGER ÉTÉ STRING".to_string()),
+                    Some(String::new())
+                ]
+            );
+            assert_eq!(
+                scalar_values::<String>(&lower(&array).unwrap()).unwrap()[2],
+                Some("a much longer été string".to_string())
+            );
+            assert_eq!(
+                scalar_values::<String>(&substring(&array, 4, Some(2)).unwrap()).unwrap(),
+                [
+                    Some("ße".to_string()),
+                    None,
+                    Some("ch".to_string()),
+                    Some(String::new())
+                ]
+            );
+            assert_eq!(
+                scalar_values::<String>(&substring(&array, 14, None).unwrap()).unwrap()[2],
+                Some("ÉTÉ string".to_string())
+            );
+        }
+
+        let binary = VarBinArray::from_iter([Some("é")], DType::Binary(Nullability::NonNullable))
+            .into_array();
+        assert_eq!(
+            u64::try_from(scalar_at(&length(&binary).unwrap(), 0).unwrap()).unwrap(),
+            2
+        );
+        assert!(upper(&binary).is_err());
+    }
+}
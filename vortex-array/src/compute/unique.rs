@@ -0,0 +1,448 @@
+use std::hash::{Hash, Hasher};
+
+use ahash::RandomState;
+use hashbrown::hash_map::{Entry, RawEntryMut};
+use hashbrown::HashMap;
+use log::info;
+use num_traits::AsPrimitive;
+use vortex_dtype::{match_each_integer_ptype, match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::AsBytes;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::array::varbin::VarBinArray;
+use crate::array::varbinview::{BinaryView, VarBinViewArray};
+use crate::compute::as_contiguous::flatten_contiguous;
+use crate::compute::filter::filter;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, Flattened, IntoArray, OwnedArray};
+
+pub trait ValueCountsFn {
+    /// The distinct values of the array and how often each occurs, see [`value_counts`].
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)>;
+}
+
+/// The distinct values of the array, see [`value_counts`].
+pub fn unique(array: &Array) -> VortexResult<OwnedArray> {
+    value_counts(array).map(|(values, _)| values)
+}
+
+/// The distinct values of the array, and a u64 array with the number of times each occurs.
+///
+/// Values are distinct by their bytes, so e.g. floating point NaN values are only equal if they
+/// have the same bit pattern. Null values are counted as a single null value. The values are in no
+/// particular order.
+pub fn value_counts(array: &Array) -> VortexResult<(OwnedArray, OwnedArray)> {
+    array.with_dyn(|a| {
+        if let Some(f) = a.value_counts() {
+            return f.value_counts();
+        }
+
+        // Otherwise, flatten and try again.
+        info!("ValueCountsFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.value_counts()
+                .map(|f| f.value_counts())
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(
+                        NotImplemented: "value_counts",
+                        array.encoding().id()
+                    ))
+                })
+        })
+    })
+}
+
+/// The value counts of a dictionary encoded array, leaving out the values that no code refers to.
+pub fn dict_value_counts(codes: &Array, values: &Array) -> VortexResult<(OwnedArray, OwnedArray)> {
+    used_value_counts(values, code_counts(codes, values.len())?)
+}
+
+/// The number of occurrences of every code of a dictionary with the given number of values.
+pub fn code_counts(codes: &Array, num_values: usize) -> VortexResult<Vec<u64>> {
+    let codes = codes.clone().flatten_primitive()?;
+    let mut counts = vec![0u64; num_values];
+    match_each_integer_ptype!(codes.ptype(), |$P| {
+        for &code in codes.typed_data::<$P>() {
+            counts[code as usize] += 1;
+        }
+    });
+    Ok(counts)
+}
+
+/// The values with their counts, leaving out the values with a count of zero.
+pub(crate) fn used_value_counts(
+    values: &Array,
+    mut counts: Vec<u64>,
+) -> VortexResult<(OwnedArray, OwnedArray)> {
+    let used = BoolArray::from(counts.iter().map(|&c| c > 0).collect::<Vec<_>>());
+    counts.retain(|&c| c > 0);
+    Ok((
+        filter(values, &used)?,
+        PrimitiveArray::from(counts).into_array(),
+    ))
+}
+
+/// Dictionary encode an array of any encoding, returning the code of every value and the
+/// dictionary. Null values are encoded in the dictionary.
+pub(crate) fn dict_encode(array: &Array) -> VortexResult<(OwnedArray, OwnedArray)> {
+    let array = flatten_contiguous(array)?;
+    match array.clone().flatten()? {
+        Flattened::Primitive(p) => {
+            let (codes, values) = match_each_native_ptype!(p.ptype(), |$T| {
+                dict_encode_typed_primitive::<$T>(&p)
+            });
+            Ok((codes.into_array(), values.into_array()))
+        }
+        Flattened::VarBin(v) => {
+            let (codes, values) = dict_encode_varbin(&v);
+            Ok((codes.into_array(), values.into_array()))
+        }
+        Flattened::Bool(b) => {
+            // Codes follow the order of the values, with null first if the array is nullable.
+            let nullable = b.dtype().is_nullable();
+            let nulls = b.logical_validity().to_null_buffer()?;
+            let codes = b
+                .boolean_buffer()
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    if nulls.as_ref().map_or(false, |n| n.is_null(i)) {
+                        0
+                    } else {
+                        v as u64 + nullable as u64
+                    }
+                })
+                .collect::<Vec<_>>();
+            let values = if nullable {
+                BoolArray::from_iter([None, Some(false), Some(true)])
+            } else {
+                BoolArray::from(vec![false, true])
+            };
+            Ok((
+                PrimitiveArray::from(codes).into_array(),
+                values.into_array(),
+            ))
+        }
+        _ => Err(vortex_err!(NotImplemented: "dict_encode", array.encoding().id())),
+    }
+}
+
+#[derive(Debug)]
+struct Value<T>(T);
+
+impl<T: AsBytes> Hash for Value<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_bytes().hash(state)
+    }
+}
+
+impl<T: AsBytes> PartialEq<Self> for Value<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_bytes().eq(other.0.as_bytes())
+    }
+}
+
+impl<T: AsBytes> Eq for Value<T> {}
+
+/// Dictionary encode primitive array with given PType.
+/// Null values in the original array are encoded in the dictionary.
+pub fn dict_encode_typed_primitive<'a, T: NativePType>(
+    array: &PrimitiveArray<'a>,
+) -> (PrimitiveArray<'a>, PrimitiveArray<'a>) {
+    let mut lookup_dict: HashMap<Value<T>, u64> = HashMap::new();
+    let mut codes: Vec<u64> = Vec::new();
+    let mut values: Vec<T> = Vec::new();
+
+    if array.dtype().is_nullable() {
+        values.push(T::zero());
+    }
+
+    ArrayAccessor::<T>::with_iterator(array, |iter| {
+        for ov in iter {
+            match ov {
+                None => codes.push(0),
+                Some(&v) => {
+                    let code = match lookup_dict.entry(Value(v)) {
+                        Entry::Occupied(o) => *o.get(),
+                        Entry::Vacant(vac) => {
+                            let next_code = values.len() as u64;
+                            vac.insert(next_code.as_());
+                            values.push(v);
+                            next_code
+                        }
+                    };
+                    codes.push(code);
+                }
+            }
+        }
+    })
+    .unwrap();
+
+    let values_validity = if array.dtype().is_nullable() {
+        let mut validity = vec![true; values.len()];
+        validity[0] = false;
+
+        validity.into()
+    } else {
+        Validity::NonNullable
+    };
+
+    (
+        PrimitiveArray::from(codes),
+        PrimitiveArray::from_vec(values, values_validity),
+    )
+}
+
+/// Dictionary encode an array of byte strings, e.g. a varbin or varbinview array.
+/// Null values in the original array are encoded in the dictionary.
+pub fn dict_encode_varbin<'a, A>(array: &A) -> (PrimitiveArray<'a>, VarBinArray<'a>)
+where
+    A: ArrayAccessor<[u8]> + ArrayDType,
+{
+    array
+        .with_iterator(|iter| dict_encode_typed_varbin(array.dtype().clone(), iter))
+        .unwrap()
+}
+
+/// Dictionary encode a varbinview array into a varbinview dictionary.
+/// Null values in the original array are encoded in the dictionary.
+///
+/// Unlike [`dict_encode_varbin`], this hashes and compares the views of values rather than their
+/// bytes where it can.
+pub fn dict_encode_varbinview<'a>(
+    array: &VarBinViewArray,
+) -> VortexResult<(PrimitiveArray<'a>, VarBinViewArray<'a>)> {
+    let (codes, uniques) = dict_encode_views(array)?;
+    let values = array.with_iterator(|iter| {
+        let values = iter.collect::<Vec<_>>();
+        VarBinViewArray::from_iter(
+            array
+                .dtype()
+                .is_nullable()
+                .then_some(None)
+                .into_iter()
+                .chain(uniques.iter().map(|&row| values[row])),
+            array.dtype().clone(),
+        )
+    })?;
+    Ok((PrimitiveArray::from(codes), values))
+}
+
+/// The code of every value of a varbinview array, and the row of the first occurrence of each
+/// distinct non-null value, where code zero is reserved for nulls if the array is nullable.
+///
+/// Values of at most 12 bytes are inlined into their zero padded views, so they are hashed and
+/// compared as a single u128. Longer values only need their bytes compared if their sizes and
+/// prefixes are equal.
+fn dict_encode_views(array: &VarBinViewArray) -> VortexResult<(Vec<u64>, Vec<usize>)> {
+    let views = array.view_slice();
+    let buffers = array.flatten_buffers()?;
+    let validity = array.logical_validity().to_null_buffer()?;
+
+    let hasher = RandomState::new();
+    let hash_view = |view: &BinaryView| {
+        if view.is_inlined() {
+            hasher.hash_one(view.as_u128())
+        } else {
+            hasher.hash_one(view.value(&buffers))
+        }
+    };
+    let views_eq = |a: &BinaryView, b: &BinaryView| {
+        a.as_u128() == b.as_u128()
+            || (!a.is_inlined()
+                && a.size() == b.size()
+                && a.prefix() == b.prefix()
+                && a.value(&buffers) == b.value(&buffers))
+    };
+
+    // The set of distinct values, as indices into the uniques.
+    let mut lookup_dict: HashMap<usize, (), ()> = HashMap::with_hasher(());
+    let mut uniques: Vec<usize> = Vec::new();
+    let mut codes: Vec<u64> = Vec::with_capacity(views.len());
+    let first_code = array.dtype().is_nullable() as u64;
+
+    for (row, view) in views.iter().enumerate() {
+        if validity.as_ref().map_or(false, |v| v.is_null(row)) {
+            codes.push(0);
+            continue;
+        }
+
+        let view_hash = hash_view(view);
+        let raw_entry = lookup_dict
+            .raw_entry_mut()
+            .from_hash(view_hash, |&idx| views_eq(view, &views[uniques[idx]]));
+        let idx = match raw_entry {
+            RawEntryMut::Occupied(o) => *o.into_key(),
+            RawEntryMut::Vacant(vac) => {
+                uniques.push(row);
+                *vac.insert_with_hasher(view_hash, uniques.len() - 1, (), |&idx| {
+                    hash_view(&views[uniques[idx]])
+                })
+                .0
+            }
+        };
+        codes.push(idx as u64 + first_code);
+    }
+
+    Ok((codes, uniques))
+}
+
+fn lookup_bytes<'a, T: NativePType + AsPrimitive<usize>>(
+    offsets: &'a [T],
+    bytes: &'a [u8],
+    idx: usize,
+) -> &'a [u8] {
+    let begin: usize = offsets[idx].as_();
+    let end: usize = offsets[idx + 1].as_();
+    &bytes[begin..end]
+}
+
+fn dict_encode_typed_varbin<'a, I, U>(
+    dtype: DType,
+    values: I,
+) -> (PrimitiveArray<'a>, VarBinArray<'a>)
+where
+    I: Iterator<Item = Option<U>>,
+    U: AsRef<[u8]>,
+{
+    let (lower, _) = values.size_hint();
+    let hasher = RandomState::new();
+    let mut lookup_dict: HashMap<u64, (), ()> = HashMap::with_hasher(());
+    let mut codes: Vec<u64> = Vec::with_capacity(lower);
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut offsets: Vec<u64> = Vec::new();
+    offsets.push(0);
+
+    if dtype.is_nullable() {
+        offsets.push(0);
+    }
+
+    for o_val in values {
+        match o_val {
+            None => codes.push(0),
+            Some(val) => {
+                let byte_ref = val.as_ref();
+                let value_hash = hasher.hash_one(byte_ref);
+                let raw_entry = lookup_dict.raw_entry_mut().from_hash(value_hash, |idx| {
+                    byte_ref == lookup_bytes(offsets.as_slice(), bytes.as_slice(), idx.as_())
+                });
+
+                let code = match raw_entry {
+                    RawEntryMut::Occupied(o) => *o.into_key(),
+                    RawEntryMut::Vacant(vac) => {
+                        let next_code = offsets.len() as u64 - 1;
+                        bytes.extend_from_slice(byte_ref);
+                        offsets.push(bytes.len() as u64);
+                        vac.insert_with_hasher(value_hash, next_code, (), |idx| {
+                            hasher.hash_one(lookup_bytes(
+                                offsets.as_slice(),
+                                bytes.as_slice(),
+                                idx.as_(),
+                            ))
+                        });
+                        next_code
+                    }
+                };
+                codes.push(code)
+            }
+        }
+    }
+
+    let values_validity = if dtype.is_nullable() {
+        let mut validity = Vec::with_capacity(offsets.len() - 1);
+        validity.push(false);
+        validity.extend(vec![true; offsets.len() - 2]);
+
+        validity.into()
+    } else {
+        Validity::NonNullable
+    };
+
+    (
+        PrimitiveArray::from(codes),
+        VarBinArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            PrimitiveArray::from(bytes).into_array(),
+            dtype,
+            values_validity,
+        )
+        .unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::varbinview::VarBinViewArray;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::unique::{unique, value_counts};
+    use crate::{Array, IntoArray};
+
+    /// The values and counts as strings, ordered by value.
+    fn counted(array: &Array) -> Vec<(String, u64)> {
+        let (values, counts) = value_counts(array).unwrap();
+        let counts = counts.flatten_primitive().unwrap();
+        let mut counted = (0..values.len())
+            .map(|i| {
+                (
+                    scalar_at(&values, i).unwrap().to_string(),
+                    counts.typed_data::<u64>()[i],
+                )
+            })
+            .collect::<Vec<_>>();
+        counted.sort();
+        counted
+    }
+
+    fn owned(counted: &[(&str, u64)]) -> Vec<(String, u64)> {
+        counted.iter().map(|(v, c)| (v.to_string(), *c)).collect()
+    }
+
+    #[test]
+    fn value_counts_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(1), Some(3), None])
+                .into_array();
+        assert_eq!(
+            counted(&array),
+            owned(&[("1(i32)", 1), ("3(i32)", 2), ("<none>(i32?)", 2)])
+        );
+        assert_eq!(unique(&array).unwrap().len(), 3);
+
+        // A nullable array without nulls has no null value.
+        let no_nulls = PrimitiveArray::from_nullable_vec(vec![Some(2u8), Some(2)]).into_array();
+        assert_eq!(counted(&no_nulls), owned(&[("2(u8)", 2)]));
+    }
+
+    #[test]
+    fn value_counts_strings_and_bools() {
+        let array = VarBinViewArray::from_iter(
+            vec![Some("b"), Some("a long string value"), Some("b"), None],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        assert_eq!(
+            counted(&array),
+            owned(&[("\"a long string value\"", 1), ("\"b\"", 2), ("<none>", 1)])
+        );
+
+        let bools = BoolArray::from_iter([Some(true), None, Some(true)]).into_array();
+        assert_eq!(counted(&bools), owned(&[("null", 1), ("true", 2)]));
+    }
+
+    #[test]
+    fn value_counts_constant() {
+        let array = ConstantArray::new(Scalar::from(4u16), 10).into_array();
+        assert_eq!(counted(&array), owned(&[("4(u16)", 10)]));
+        let empty = ConstantArray::new(Scalar::from(4u16), 0).into_array();
+        assert!(unique(&empty).unwrap().is_empty());
+    }
+}
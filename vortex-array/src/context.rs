@@ -4,6 +4,7 @@ use crate::array::bool::BoolEncoding;
 use crate::array::chunked::ChunkedEncoding;
 use crate::array::constant::ConstantEncoding;
 use crate::array::extension::ExtensionEncoding;
+use crate::array::list::ListEncoding;
 use crate::array::primitive::PrimitiveEncoding;
 use crate::array::r#struct::StructEncoding;
 use crate::array::sparse::SparseEncoding;
@@ -46,6 +47,7 @@ impl Default for Context {
                     &ChunkedEncoding,
                     &ConstantEncoding,
                     &ExtensionEncoding,
+                    &ListEncoding,
                     &PrimitiveEncoding,
                     &SparseEncoding,
                     &StructEncoding,
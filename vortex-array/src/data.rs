@@ -86,26 +86,6 @@ impl ArrayData {
     pub fn depth_first_traversal(&self) -> ArrayDataIterator {
         ArrayDataIterator { stack: vec![self] }
     }
-
-    /// Return the buffer offsets and the total length of all buffers, assuming the given alignment.
-    /// This includes all child buffers.
-    pub fn all_buffer_offsets(&self, alignment: usize) -> Vec<u64> {
-        let mut offsets = vec![];
-        let mut offset = 0;
-
-        for col_data in self.depth_first_traversal() {
-            if let Some(buffer) = col_data.buffer() {
-                offsets.push(offset as u64);
-
-                let buffer_size = buffer.len();
-                let aligned_size = (buffer_size + (alignment - 1)) & !(alignment - 1);
-                offset += aligned_size;
-            }
-        }
-        offsets.push(offset as u64);
-
-        offsets
-    }
 }
 
 /// A depth-first pre-order iterator over a ArrayData.
@@ -3,6 +3,7 @@ use vortex_error::VortexResult;
 use crate::array::bool::BoolArray;
 use crate::array::chunked::ChunkedArray;
 use crate::array::extension::ExtensionArray;
+use crate::array::list::ListArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::r#struct::StructArray;
 use crate::array::varbin::VarBinArray;
@@ -14,6 +15,7 @@ use crate::{Array, IntoArray};
 pub enum Flattened<'a> {
     Bool(BoolArray<'a>),
     Chunked(ChunkedArray<'a>),
+    List(ListArray<'a>),
     Primitive(PrimitiveArray<'a>),
     Struct(StructArray<'a>),
     VarBin(VarBinArray<'a>),
@@ -49,6 +51,7 @@ impl<'a> IntoArray<'a> for Flattened<'a> {
     fn into_array(self) -> Array<'a> {
         match self {
             Flattened::Bool(a) => a.into_array(),
+            Flattened::List(a) => a.into_array(),
             Flattened::Primitive(a) => a.into_array(),
             Flattened::Struct(a) => a.into_array(),
             Flattened::Chunked(a) => a.into_array(),
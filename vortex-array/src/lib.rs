@@ -56,7 +56,7 @@ pub mod flatbuffers {
         }
         pub mod scalar {
             #[allow(unused_imports)]
-            pub use vortex_scalar::flatbuffers as scalar;
+            pub use vortex_scalar::flatbuffers::scalar;
         }
     }
 }
@@ -7,6 +7,7 @@ use vortex_dtype::{DType, NativePType};
 use vortex_error::{VortexError, VortexResult};
 use vortex_scalar::{ListScalarVec, Scalar};
 
+mod serde;
 mod statsset;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
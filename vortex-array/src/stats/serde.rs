@@ -0,0 +1,154 @@
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use itertools::Itertools;
+use vortex_error::VortexError;
+use vortex_flatbuffers::{ReadFlatBuffer, WriteFlatBuffer};
+use vortex_scalar::{ListScalarVec, Scalar};
+
+use crate::flatbuffers::array as fb;
+use crate::stats::{Stat, StatsSet};
+
+impl WriteFlatBuffer for StatsSet {
+    type Target<'t> = fb::ArrayStats<'t>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        let min = self
+            .get(Stat::Min)
+            .filter(|s| has_flatbuffer_repr(s))
+            .map(|s| s.write_flatbuffer(fbb));
+        let max = self
+            .get(Stat::Max)
+            .filter(|s| has_flatbuffer_repr(s))
+            .map(|s| s.write_flatbuffer(fbb));
+        let bit_width_freq = self
+            .get(Stat::BitWidthFreq)
+            .and_then(|s| ListScalarVec::<u64>::try_from(s).ok())
+            .map(|freq| fbb.create_vector(freq.0.as_slice()));
+        let trailing_zero_freq = self
+            .get(Stat::TrailingZeroFreq)
+            .and_then(|s| ListScalarVec::<u64>::try_from(s).ok())
+            .map(|freq| fbb.create_vector(freq.0.as_slice()));
+
+        fb::ArrayStats::create(
+            fbb,
+            &fb::ArrayStatsArgs {
+                min,
+                max,
+                is_sorted: self.get_bool(Stat::IsSorted),
+                is_strict_sorted: self.get_bool(Stat::IsStrictSorted),
+                is_constant: self.get_bool(Stat::IsConstant),
+                run_count: self.get_count(Stat::RunCount),
+                true_count: self.get_count(Stat::TrueCount),
+                null_count: self.get_count(Stat::NullCount),
+                bit_width_freq,
+                trailing_zero_freq,
+            },
+        )
+    }
+}
+
+impl ReadFlatBuffer for StatsSet {
+    type Source<'a> = fb::ArrayStats<'a>;
+    type Error = VortexError;
+
+    fn read_flatbuffer(fb: &Self::Source<'_>) -> Result<Self, Self::Error> {
+        let mut stats = StatsSet::new();
+        if let Some(min) = fb.min() {
+            stats.set(Stat::Min, Scalar::read_flatbuffer(&min)?);
+        }
+        if let Some(max) = fb.max() {
+            stats.set(Stat::Max, Scalar::read_flatbuffer(&max)?);
+        }
+
+        for (stat, value) in [
+            (Stat::IsSorted, fb.is_sorted()),
+            (Stat::IsStrictSorted, fb.is_strict_sorted()),
+            (Stat::IsConstant, fb.is_constant()),
+        ] {
+            if let Some(value) = value {
+                stats.set(stat, value.into());
+            }
+        }
+
+        for (stat, value) in [
+            (Stat::RunCount, fb.run_count()),
+            (Stat::TrueCount, fb.true_count()),
+            (Stat::NullCount, fb.null_count()),
+        ] {
+            if let Some(value) = value {
+                stats.set(stat, (value as usize).into());
+            }
+        }
+
+        for (stat, freq) in [
+            (Stat::BitWidthFreq, fb.bit_width_freq()),
+            (Stat::TrailingZeroFreq, fb.trailing_zero_freq()),
+        ] {
+            if let Some(freq) = freq.filter(|f| !f.is_empty()) {
+                stats.set(stat, ListScalarVec(freq.iter().collect_vec()).into());
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+impl StatsSet {
+    fn get_bool(&self, stat: Stat) -> Option<bool> {
+        self.get(stat).and_then(|s| bool::try_from(s).ok())
+    }
+
+    fn get_count(&self, stat: Stat) -> Option<u64> {
+        self.get(stat)
+            .and_then(|s| usize::try_from(s).ok())
+            .map(|c| c as u64)
+    }
+}
+
+/// Not every scalar can be written to a FlatBuffer yet, so we skip those stats.
+fn has_flatbuffer_repr(scalar: &Scalar) -> bool {
+    !matches!(scalar, Scalar::List(_) | Scalar::Struct(_))
+}
+
+#[cfg(test)]
+mod test {
+    use flatbuffers::{root, FlatBufferBuilder};
+    use vortex_flatbuffers::{ReadFlatBuffer, WriteFlatBuffer};
+    use vortex_scalar::{ListScalarVec, Scalar};
+
+    use crate::flatbuffers::array as fb;
+    use crate::stats::{Stat, StatsSet};
+
+    #[test]
+    fn round_trip() {
+        let mut stats = StatsSet::new();
+        stats.set(Stat::Min, 1i32.into());
+        stats.set(Stat::Max, Scalar::from("hello"));
+        stats.set(Stat::IsSorted, true.into());
+        stats.set(Stat::NullCount, 5usize.into());
+        stats.set(Stat::BitWidthFreq, ListScalarVec(vec![1u64, 2, 3]).into());
+
+        let mut fbb = FlatBufferBuilder::new();
+        let root_offset = stats.write_flatbuffer(&mut fbb);
+        fbb.finish_minimal(root_offset);
+        let fb = root::<fb::ArrayStats>(fbb.finished_data()).unwrap();
+        let read = StatsSet::read_flatbuffer(&fb).unwrap();
+
+        assert_eq!(read.get(Stat::Min), Some(&1i32.into()));
+        assert_eq!(read.get(Stat::Max), Some(&Scalar::from("hello")));
+        assert_eq!(read.get(Stat::IsSorted), Some(&true.into()));
+        assert_eq!(read.get(Stat::IsConstant), None);
+        assert_eq!(
+            usize::try_from(read.get(Stat::NullCount).unwrap()).unwrap(),
+            5
+        );
+        assert_eq!(
+            ListScalarVec::<u64>::try_from(read.get(Stat::BitWidthFreq).unwrap())
+                .unwrap()
+                .0,
+            vec![1, 2, 3]
+        );
+    }
+}
@@ -5,6 +5,7 @@ use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::bool::BoolArray;
 use crate::compute::as_contiguous::as_contiguous;
+use crate::compute::filter::filter;
 use crate::compute::scalar_at::scalar_at;
 use crate::compute::slice::slice;
 use crate::compute::take::take;
@@ -116,6 +117,13 @@ impl<'v> Validity<'v> {
         }
     }
 
+    pub fn filter(&self, mask: &BoolArray) -> VortexResult<Validity> {
+        match self {
+            Validity::Array(a) => Ok(Validity::Array(filter(a, mask)?)),
+            _ => Ok(self.clone()),
+        }
+    }
+
     pub fn to_logical(&self, length: usize) -> LogicalValidity {
         match self {
             Validity::NonNullable => LogicalValidity::AllValid(length),
@@ -139,6 +147,27 @@ impl<'v> Validity<'v> {
         }
     }
 
+    /// The validity of the same values in an array of the given nullability, which fails if the
+    /// array has nulls but must be non-nullable.
+    pub fn cast_nullability(
+        self,
+        nullability: Nullability,
+        length: usize,
+    ) -> VortexResult<Validity<'v>> {
+        match nullability {
+            Nullability::Nullable => Ok(match self {
+                Validity::NonNullable => Validity::AllValid,
+                validity => validity,
+            }),
+            Nullability::NonNullable => {
+                if length > 0 && !self.to_logical(length).all_valid() {
+                    vortex_bail!(ComputeError: "Cannot cast an array with nulls to a non-nullable type");
+                }
+                Ok(Validity::NonNullable)
+            }
+        }
+    }
+
     pub fn to_static(&self) -> OwnedValidity {
         match self {
             Validity::NonNullable => Validity::NonNullable,
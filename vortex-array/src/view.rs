@@ -1,13 +1,16 @@
 use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
 
 use itertools::Itertools;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_flatbuffers::ReadFlatBuffer;
+use vortex_scalar::Scalar;
 
 use crate::encoding::{EncodingId, EncodingRef};
 use crate::flatbuffers::array as fb;
-use crate::stats::{EmptyStatistics, Statistics};
+use crate::stats::{Stat, Statistics, StatsSet};
 use crate::Context;
 use crate::{Array, IntoArray, ToArray};
 
@@ -18,11 +21,20 @@ pub struct ArrayView<'v> {
     array: fb::Array<'v>,
     buffers: &'v [Buffer],
     ctx: &'v ViewContext,
+    stats: Arc<ViewStats>,
     // TODO(ngates): a store a Projection. A projected ArrayView contains the full fb::Array
     //  metadata, but only the buffers from the selected columns. Therefore we need to know
     //  which fb:Array children to skip when calculating how to slice into buffers.
 }
 
+/// The statistics of an array view and of its children, starting from those persisted with the
+/// array, which are parsed once when the root view is created.
+#[derive(Debug)]
+struct ViewStats {
+    stats_map: RwLock<StatsSet>,
+    children: Vec<Arc<ViewStats>>,
+}
+
 impl<'a> Debug for ArrayView<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ArrayView")
@@ -54,12 +66,17 @@ impl<'v> ArrayView<'v> {
             )
         }
 
+        // Validate the encodings and parse the persisted statistics of the whole tree up front,
+        // so that child views can be created without validating them again.
+        let stats = Arc::new(Self::validate_tree(ctx, array)?);
+
         let view = Self {
             encoding,
             dtype,
             array,
             buffers,
             ctx,
+            stats,
         };
 
         // Validate here that the metadata correctly parses, so that an encoding can infallibly
@@ -97,15 +114,40 @@ impl<'v> ArrayView<'v> {
             .sum();
         let buffer_count = Self::cumulative_nbuffers(child);
 
-        Some(
-            Self::try_new(
-                self.ctx,
-                dtype,
-                child,
-                &self.buffers[buffer_offset..][0..buffer_count],
-            )
-            .unwrap(),
-        )
+        Some(Self {
+            encoding: self.ctx.find_encoding(child.encoding())?,
+            dtype,
+            array: child,
+            buffers: &self.buffers[buffer_offset..][0..buffer_count],
+            ctx: self.ctx,
+            stats: self.stats.children.get(idx)?.clone(),
+        })
+    }
+
+    fn validate_tree(ctx: &ViewContext, array: fb::Array) -> VortexResult<ViewStats> {
+        if ctx.find_encoding(array.encoding()).is_none() {
+            vortex_bail!(InvalidSerde: "Encoding ID out of bounds")
+        }
+        // Rehydrate any statistics that were computed before the array was serialized.
+        let stats_map = array
+            .stats()
+            .map(|stats| StatsSet::read_flatbuffer(&stats))
+            .transpose()?
+            .unwrap_or_default();
+        let children = array
+            .children()
+            .unwrap_or_default()
+            .iter()
+            .map(|child| Self::validate_tree(ctx, child).map(Arc::new))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(ViewStats {
+            stats_map: RwLock::new(stats_map),
+            children,
+        })
+    }
+
+    fn stats_map(&self) -> &RwLock<StatsSet> {
+        &self.stats.stats_map
     }
 
     fn array_child(&self, idx: usize) -> Option<fb::Array<'v>> {
@@ -136,8 +178,65 @@ impl<'v> ArrayView<'v> {
     }
 
     pub fn statistics(&self) -> &dyn Statistics {
-        // TODO(ngates): store statistics in FlatBuffers
-        &EmptyStatistics
+        self
+    }
+}
+
+impl Statistics for ArrayView<'_> {
+    fn get(&self, stat: Stat) -> Option<Scalar> {
+        self.stats_map().read().unwrap().get(stat).cloned()
+    }
+
+    fn to_set(&self) -> StatsSet {
+        self.stats_map().read().unwrap().clone()
+    }
+
+    fn set(&self, stat: Stat, value: Scalar) {
+        self.stats_map().write().unwrap().set(stat, value);
+    }
+
+    fn compute(&self, stat: Stat) -> Option<Scalar> {
+        if let Some(s) = self.get(stat) {
+            return Some(s);
+        }
+
+        self.stats_map().write().unwrap().extend(
+            self.to_array()
+                .with_dyn(|a| a.compute_statistics(stat))
+                .ok()?,
+        );
+        self.get(stat)
+    }
+
+    #[inline]
+    fn with_stat_value<'a>(
+        &self,
+        stat: Stat,
+        f: &'a mut dyn FnMut(&Scalar) -> VortexResult<()>,
+    ) -> VortexResult<()> {
+        self.stats_map()
+            .read()
+            .unwrap()
+            .get(stat)
+            .ok_or_else(|| vortex_err!(ComputeError: "statistic {} missing", stat))
+            .and_then(f)
+    }
+
+    #[inline]
+    fn with_computed_stat_value<'a>(
+        &self,
+        stat: Stat,
+        f: &'a mut dyn FnMut(&Scalar) -> VortexResult<()>,
+    ) -> VortexResult<()> {
+        if let Some(s) = self.stats_map().read().unwrap().get(stat) {
+            return f(s);
+        }
+
+        self.stats_map()
+            .write()
+            .unwrap()
+            .extend(self.to_array().with_dyn(|a| a.compute_statistics(stat))?);
+        self.with_stat_value(stat, f)
     }
 }
 
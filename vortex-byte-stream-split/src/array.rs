@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::stats::ArrayStatisticsCompute;
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+use vortex_dtype::PType;
+use vortex_error::vortex_bail;
+
+use crate::compress::{join_planes, split_planes};
+
+impl_encoding!("vortex.bytestreamsplit", ByteStreamSplit);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ByteStreamSplitMetadata {
+    validity: ValidityMetadata,
+    length: usize,
+}
+
+impl ByteStreamSplitArray<'_> {
+    /// Create an array from one u8 plane per byte of the primitive type, holding that byte of
+    /// every little-endian value.
+    pub fn try_new(
+        planes: Vec<Array>,
+        validity: Validity,
+        dtype: DType,
+        length: usize,
+    ) -> VortexResult<Self> {
+        let ptype = PType::try_from(&dtype)?;
+        if ptype.byte_width() < 2 {
+            vortex_bail!(MismatchedTypes: "primitive wider than a byte", dtype);
+        }
+        if planes.len() != ptype.byte_width() {
+            vortex_bail!(
+                "Expected {} byte planes for {}, got {}",
+                ptype.byte_width(),
+                ptype,
+                planes.len()
+            );
+        }
+        if let Some(plane) = planes
+            .iter()
+            .find(|p| p.dtype() != &DType::BYTES || p.len() != length)
+        {
+            vortex_bail!(
+                "Byte planes must be {} of length {}, got {} of length {}",
+                DType::BYTES,
+                length,
+                plane.dtype(),
+                plane.len()
+            );
+        }
+
+        let metadata = ByteStreamSplitMetadata {
+            validity: validity.to_metadata(length)?,
+            length,
+        };
+
+        let mut children = Vec::with_capacity(planes.len() + 1);
+        children.extend(planes.into_iter().map(|p| p.into_array_data()));
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    pub fn encode(array: Array<'_>) -> VortexResult<OwnedByteStreamSplitArray> {
+        if let Ok(parray) = PrimitiveArray::try_from(array) {
+            ByteStreamSplitArray::try_new(
+                split_planes(&parray)
+                    .into_iter()
+                    .map(|p| p.into_array())
+                    .collect(),
+                parray.validity().to_static(),
+                parray.dtype().clone(),
+                parray.len(),
+            )
+        } else {
+            vortex_bail!("Byte stream split can only encode primitive arrays");
+        }
+    }
+
+    #[inline]
+    pub fn ptype(&self) -> PType {
+        self.dtype().try_into().unwrap()
+    }
+
+    #[inline]
+    pub fn plane(&self, byte: usize) -> Array {
+        self.array()
+            .child(byte, &DType::BYTES)
+            .expect("Missing byte plane")
+    }
+
+    /// The planes of every byte, least significant first.
+    pub fn planes(&self) -> Vec<Array> {
+        (0..self.ptype().byte_width())
+            .map(|byte| self.plane(byte))
+            .collect()
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(
+            self.array()
+                .child(self.ptype().byte_width(), &Validity::DTYPE),
+        )
+    }
+}
+
+impl ArrayFlatten for ByteStreamSplitArray<'_> {
+    fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
+    where
+        Self: 'a,
+    {
+        let planes = self
+            .planes()
+            .into_iter()
+            .map(|p| p.flatten_primitive())
+            .collect::<VortexResult<Vec<_>>>()?;
+        join_planes(
+            &planes
+                .iter()
+                .map(|p| p.typed_data::<u8>())
+                .collect::<Vec<_>>(),
+            self.ptype(),
+            self.validity().to_static(),
+        )
+        .map(Flattened::Primitive)
+    }
+}
+
+impl ArrayValidity for ByteStreamSplitArray<'_> {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ByteStreamSplitArray<'_> {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        for plane in self.planes() {
+            visitor.visit_child("plane", &plane)?;
+        }
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for ByteStreamSplitArray<'_> {}
+
+impl ArrayTrait for ByteStreamSplitArray<'_> {
+    fn len(&self) -> usize {
+        self.metadata().length
+    }
+}
@@ -0,0 +1,144 @@
+use arrow_buffer::{MutableBuffer, ScalarBuffer};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::{match_each_native_ptype, PType};
+use vortex_error::VortexResult;
+
+use crate::{ByteStreamSplitArray, ByteStreamSplitEncoding};
+
+impl EncodingCompression for ByteStreamSplitEncoding {
+    fn can_compress(
+        &self,
+        array: &Array,
+        _config: &CompressConfig,
+    ) -> Option<&dyn EncodingCompression> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // A single byte is already its own plane
+        if parray.ptype().byte_width() < 2 {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress(
+        &self,
+        array: &Array,
+        like: Option<&Array>,
+        ctx: Compressor,
+    ) -> VortexResult<OwnedArray> {
+        let like_split = like.map(|l| ByteStreamSplitArray::try_from(l).unwrap());
+        let parray = array.as_primitive();
+
+        let validity = ctx.compress_validity(parray.validity())?;
+        let planes = split_planes(&parray)
+            .into_iter()
+            .enumerate()
+            .map(|(byte, plane)| {
+                ctx.named(&format!("plane_{}", byte)).compress(
+                    &plane.into_array(),
+                    like_split.as_ref().map(|l| l.plane(byte)).as_ref(),
+                )
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        ByteStreamSplitArray::try_new(planes, validity, parray.dtype().clone(), parray.len())
+            .map(|a| a.into_array())
+    }
+}
+
+/// Split the little-endian bytes of every value into one plane per byte, least significant first.
+pub(crate) fn split_planes(parray: &PrimitiveArray) -> Vec<PrimitiveArray<'static>> {
+    let width = parray.ptype().byte_width();
+    let bytes = parray.buffer().as_ref();
+    (0..width)
+        .map(|byte| {
+            PrimitiveArray::from(
+                bytes
+                    .iter()
+                    .skip(byte)
+                    .step_by(width)
+                    .copied()
+                    .collect::<Vec<u8>>(),
+            )
+        })
+        .collect()
+}
+
+/// Interleave the byte planes back into values of the primitive type.
+pub(crate) fn join_planes<'a>(
+    planes: &[&[u8]],
+    ptype: PType,
+    validity: Validity<'a>,
+) -> VortexResult<PrimitiveArray<'a>> {
+    let width = planes.len();
+    let len = planes.first().map(|p| p.len()).unwrap_or_default();
+    let mut buffer = MutableBuffer::from_len_zeroed(len * width);
+    let bytes = buffer.as_slice_mut();
+    for (byte, plane) in planes.iter().enumerate() {
+        for (i, &b) in plane.iter().enumerate() {
+            bytes[i * width + byte] = b;
+        }
+    }
+
+    match_each_native_ptype!(ptype, |$T| {
+        PrimitiveArray::try_new(ScalarBuffer::<$T>::new(buffer.into(), 0, len), validity)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::constant::Constant;
+    use vortex::compress::Compressor;
+    use vortex::{ArrayDef, Context};
+
+    use super::*;
+
+    #[test]
+    fn compress_constant_planes() {
+        let values = (0..4096)
+            .map(|i| 1.0 + (i % 256) as f64 / 1024.0)
+            .collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        let ctx = Context::default();
+        let compressed = ByteStreamSplitEncoding
+            .compress(&array, None, Compressor::new(&ctx))
+            .unwrap();
+        let split = ByteStreamSplitArray::try_from(&compressed).unwrap();
+        // Only the exponent and the top of the mantissa vary, the low bytes are all zero.
+        assert_eq!(split.plane(0).encoding().id(), Constant::ID);
+        assert_eq!(split.plane(7).encoding().id(), Constant::ID);
+        assert!(compressed.nbytes() < array.nbytes());
+        assert_eq!(
+            compressed.flatten_primitive().unwrap().typed_data::<f64>(),
+            values.as_slice()
+        );
+    }
+
+    #[test]
+    fn split_and_join() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(0x0102i16), None, Some(-2)]);
+        let planes = split_planes(&array);
+        assert_eq!(planes[0].typed_data::<u8>(), [0x02, 0, 0xfe]);
+        assert_eq!(planes[1].typed_data::<u8>(), [0x01, 0, 0xff]);
+
+        let joined = join_planes(
+            &planes
+                .iter()
+                .map(|p| p.typed_data::<u8>())
+                .collect::<Vec<_>>(),
+            PType::I16,
+            array.validity(),
+        )
+        .unwrap();
+        assert_eq!(joined.typed_data::<i16>(), array.typed_data::<i16>());
+        assert_eq!(joined.dtype(), array.dtype());
+        assert!(
+            ByteStreamSplitArray::encode(PrimitiveArray::from(vec![1u8]).into_array()).is_err()
+        );
+    }
+}
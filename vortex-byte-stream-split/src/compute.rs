@@ -0,0 +1,124 @@
+use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
+use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::take::{take, TakeFn};
+use vortex::compute::ArrayCompute;
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayData, OwnedArray};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::compress::join_planes;
+use crate::ByteStreamSplitArray;
+
+impl ArrayCompute for ByteStreamSplitArray<'_> {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ByteStreamSplitArray<'_> {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.validity().is_valid(index) {
+            return Ok(Scalar::null(self.dtype()));
+        }
+
+        let bytes = self
+            .planes()
+            .iter()
+            .map(|p| u8::try_from(scalar_at(p, index)?))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let validity = if self.dtype().is_nullable() {
+            Validity::AllValid
+        } else {
+            Validity::NonNullable
+        };
+        let value = join_planes(
+            &bytes.iter().map(std::slice::from_ref).collect::<Vec<_>>(),
+            self.ptype(),
+            validity,
+        )?;
+        scalar_at(&value.into_array(), 0)
+    }
+}
+
+impl SliceFn for ByteStreamSplitArray<'_> {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<OwnedArray> {
+        ByteStreamSplitArray::try_new(
+            self.planes()
+                .iter()
+                .map(|p| slice(p, start, stop))
+                .collect::<VortexResult<Vec<_>>>()?,
+            self.validity().slice(start, stop)?,
+            self.dtype().clone(),
+            stop - start,
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl TakeFn for ByteStreamSplitArray<'_> {
+    fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
+        ByteStreamSplitArray::try_new(
+            self.planes()
+                .iter()
+                .map(|p| take(p, indices))
+                .collect::<VortexResult<Vec<_>>>()?,
+            self.validity().take(indices)?,
+            self.dtype().clone(),
+            indices.len(),
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::scalar_at::scalar_at;
+    use vortex::compute::slice::slice;
+    use vortex::compute::take::take;
+    use vortex::{Array, ArrayDType, IntoArray};
+    use vortex_scalar::Scalar;
+
+    use crate::ByteStreamSplitArray;
+
+    fn scalars(array: &Array) -> Vec<Scalar> {
+        (0..array.len())
+            .map(|i| scalar_at(array, i).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn scalar_at_slice_take() {
+        let values = (0..100u32)
+            .map(|i| (i % 7 != 0).then_some(i as f32 * 1.5 - 20.0))
+            .collect::<Vec<_>>();
+        let parray = PrimitiveArray::from_nullable_vec(values).into_array();
+        let array = ByteStreamSplitArray::encode(parray.clone())
+            .unwrap()
+            .into_array();
+        assert_eq!(array.dtype(), parray.dtype());
+        assert_eq!(scalars(&array), scalars(&parray));
+
+        let sliced = slice(&array, 10, 60).unwrap();
+        assert_eq!(sliced.encoding().id(), array.encoding().id());
+        assert_eq!(scalars(&sliced), scalars(&slice(&parray, 10, 60).unwrap()));
+
+        let indices = PrimitiveArray::from(vec![0u64, 99, 3, 14, 3]).into_array();
+        let taken = take(&array, &indices).unwrap();
+        assert_eq!(taken.encoding().id(), array.encoding().id());
+        assert_eq!(scalars(&taken), scalars(&take(&parray, &indices).unwrap()));
+        assert_eq!(
+            scalars(&taken.flatten_primitive().unwrap().into_array()),
+            scalars(&take(&parray, &indices).unwrap())
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub use array::*;
+
+mod array;
+mod compress;
+mod compute;
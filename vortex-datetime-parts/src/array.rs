@@ -1,9 +1,17 @@
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use vortex::array::datetime::TimeUnit;
+use vortex::array::extension::ExtensionArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::cast::cast;
 use vortex::stats::ArrayStatisticsCompute;
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use vortex::{impl_encoding, ArrayDType, ArrayFlatten, ToArrayData};
-use vortex_error::vortex_bail;
+use vortex::{impl_encoding, ArrayDType, ArrayFlatten, IntoArray, ToArrayData};
+use vortex_dtype::{Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err};
+
+use crate::compress::time_unit_divisor;
 
 impl_encoding!("vortex.datetimeparts", DateTimeParts);
 
@@ -84,8 +92,36 @@ impl ArrayFlatten for DateTimePartsArray<'_> {
     where
         Self: 'a,
     {
-        // TODO(ngates): flatten into vortex.localdatetime or appropriate per dtype
-        todo!()
+        // Both vortex.localdatetime and vortex.instant store the time unit in the first byte of
+        // their metadata, and the timestamps relative to the Unix epoch.
+        let DType::Extension(ext, nullability) = self.dtype().clone() else {
+            vortex_bail!(MismatchedTypes: "datetime extension", self.dtype())
+        };
+        let time_unit = ext
+            .metadata()
+            .and_then(|m| m.as_ref().first().copied())
+            .and_then(|b| TimeUnit::try_from(b).ok())
+            .ok_or_else(|| vortex_err!("Invalid time unit in {} metadata", ext.id()))?;
+        let divisor = time_unit_divisor(time_unit);
+
+        let non_nullable = DType::Primitive(PType::I64, Nullability::NonNullable);
+        let days =
+            cast(&self.days(), &DType::Primitive(PType::I64, nullability))?.flatten_primitive()?;
+        let seconds = cast(&self.seconds(), &non_nullable)?.flatten_primitive()?;
+        let subsecond = cast(&self.subsecond(), &non_nullable)?.flatten_primitive()?;
+
+        let timestamps = days
+            .typed_data::<i64>()
+            .iter()
+            .zip_eq(seconds.typed_data::<i64>())
+            .zip_eq(subsecond.typed_data::<i64>())
+            .map(|((&d, &s), &ss)| d * 86_400 * divisor + s * divisor + ss)
+            .collect_vec();
+
+        Ok(Flattened::Extension(ExtensionArray::new(
+            ext,
+            PrimitiveArray::from_vec(timestamps, days.validity()).into_array(),
+        )))
     }
 }
 
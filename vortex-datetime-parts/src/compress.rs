@@ -1,9 +1,9 @@
-use vortex::array::datetime::{LocalDateTimeArray, TimeUnit};
+use vortex::array::datetime::{InstantArray, LocalDateTimeArray, TimeUnit};
 use vortex::array::primitive::PrimitiveArray;
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
 use vortex::compute::cast::cast;
-use vortex::{Array, ArrayTrait, IntoArray, OwnedArray};
-use vortex_dtype::PType;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::{DType, PType};
 use vortex_error::VortexResult;
 
 use crate::{DateTimePartsArray, DateTimePartsEncoding};
@@ -14,7 +14,7 @@ impl EncodingCompression for DateTimePartsEncoding {
         array: &Array,
         _config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
-        if LocalDateTimeArray::try_from(array).is_ok() {
+        if LocalDateTimeArray::try_from(array).is_ok() || InstantArray::try_from(array).is_ok() {
             return Some(self);
         }
         None
@@ -26,27 +26,50 @@ impl EncodingCompression for DateTimePartsEncoding {
         like: Option<&Array>,
         ctx: Compressor,
     ) -> VortexResult<OwnedArray> {
-        compress_localdatetime(
-            LocalDateTimeArray::try_from(array)?,
-            like.map(|l| DateTimePartsArray::try_from(l).unwrap()),
+        let like = like.map(|l| DateTimePartsArray::try_from(l).unwrap());
+        if let Ok(instant) = InstantArray::try_from(array) {
+            return compress_timestamps(
+                array.dtype(),
+                instant.time_unit(),
+                &instant.timestamps(),
+                like,
+                ctx,
+            );
+        }
+        let localdatetime = LocalDateTimeArray::try_from(array)?;
+        compress_timestamps(
+            array.dtype(),
+            localdatetime.time_unit(),
+            &localdatetime.timestamps(),
+            like,
             ctx,
         )
     }
 }
 
-fn compress_localdatetime(
-    array: LocalDateTimeArray,
-    like: Option<DateTimePartsArray>,
-    ctx: Compressor,
-) -> VortexResult<OwnedArray> {
-    let timestamps = cast(&array.timestamps(), PType::I64.into())?.flatten_primitive()?;
-
-    let divisor = match array.time_unit() {
+/// The number of timestamps per second in the given time unit.
+pub(crate) fn time_unit_divisor(time_unit: TimeUnit) -> i64 {
+    match time_unit {
         TimeUnit::Ns => 1_000_000_000,
         TimeUnit::Us => 1_000_000,
         TimeUnit::Ms => 1_000,
         TimeUnit::S => 1,
-    };
+    }
+}
+
+/// Split timestamps into days, seconds and subseconds. Instants are split relative to the Unix
+/// epoch in UTC, so the timezone is only carried along in the dtype.
+fn compress_timestamps(
+    dtype: &DType,
+    time_unit: TimeUnit,
+    timestamps: &Array,
+    like: Option<DateTimePartsArray>,
+    ctx: Compressor,
+) -> VortexResult<OwnedArray> {
+    let i64_dtype = DType::Primitive(PType::I64, timestamps.dtype().nullability());
+    let timestamps = cast(timestamps, &i64_dtype)?.flatten_primitive()?;
+
+    let divisor = time_unit_divisor(time_unit);
 
     let length = timestamps.len();
     let mut days = Vec::with_capacity(length);
@@ -60,7 +83,7 @@ fn compress_localdatetime(
     }
 
     Ok(DateTimePartsArray::try_new(
-        array.dtype().clone(),
+        dtype.clone(),
         ctx.named("days").compress(
             &PrimitiveArray::from_vec(days, timestamps.validity()).into_array(),
             like.as_ref().map(|l| l.days()).as_ref(),
@@ -76,3 +99,63 @@ fn compress_localdatetime(
     )?
     .into_array())
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::datetime::{InstantArray, LocalDateTimeArray, TimeUnit};
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compress::{Compressor, EncodingCompression};
+    use vortex::validity::{ArrayValidity, Validity};
+    use vortex::{Context, IntoArray};
+
+    use crate::DateTimePartsEncoding;
+
+    fn timestamps() -> PrimitiveArray<'static> {
+        PrimitiveArray::from_vec(
+            vec![1_700_000_000_123i64, -86_400_001, 0, 42],
+            Validity::from(vec![true, true, true, false]),
+        )
+    }
+
+    #[test]
+    fn flatten_instant() {
+        let array = InstantArray::try_new(TimeUnit::Ms, "Europe/London", timestamps().into_array())
+            .unwrap()
+            .into_array();
+        let compressed = DateTimePartsEncoding
+            .compress(&array, None, Compressor::new(&Context::default()))
+            .unwrap();
+
+        let flattened = compressed.flatten().unwrap().into_array();
+        let instant = InstantArray::try_from(&flattened).unwrap();
+        assert_eq!(instant.timezone(), "Europe/London");
+        assert_eq!(instant.time_unit(), TimeUnit::Ms);
+        let read = instant.timestamps().flatten_primitive().unwrap();
+        assert_eq!(
+            read.typed_data::<i64>()[..3],
+            timestamps().typed_data::<i64>()[..3]
+        );
+        assert!(!read.is_valid(3));
+    }
+
+    #[test]
+    fn flatten_localdatetime() {
+        let array = LocalDateTimeArray::try_new(TimeUnit::S, timestamps().into_array())
+            .unwrap()
+            .into_array();
+        let compressed = DateTimePartsEncoding
+            .compress(&array, None, Compressor::new(&Context::default()))
+            .unwrap();
+
+        let flattened = compressed.flatten().unwrap().into_array();
+        let read = LocalDateTimeArray::try_from(&flattened)
+            .unwrap()
+            .timestamps()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(
+            read.typed_data::<i64>()[..3],
+            timestamps().typed_data::<i64>()[..3]
+        );
+    }
+}
@@ -1,20 +1,14 @@
-use std::hash::{Hash, Hasher};
-
-use ahash::RandomState;
-use hashbrown::hash_map::{Entry, RawEntryMut};
-use hashbrown::HashMap;
-use num_traits::AsPrimitive;
-use vortex::accessor::ArrayAccessor;
 use vortex::array::primitive::{Primitive, PrimitiveArray};
 use vortex::array::varbin::{VarBin, VarBinArray};
+use vortex::array::varbinview::{VarBinView, VarBinViewArray};
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
+pub use vortex::compute::unique::{
+    dict_encode_typed_primitive, dict_encode_varbin, dict_encode_varbinview,
+};
 use vortex::stats::ArrayStatistics;
-use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, ArrayDef, IntoArray, OwnedArray, ToArray};
-use vortex_dtype::NativePType;
-use vortex_dtype::{match_each_native_ptype, DType};
+use vortex::{Array, ArrayDef, IntoArray, OwnedArray, ToArray};
+use vortex_dtype::match_each_native_ptype;
 use vortex_error::VortexResult;
-use vortex_scalar::AsBytes;
 
 use crate::dict::{DictArray, DictEncoding};
 
@@ -24,8 +18,10 @@ impl EncodingCompression for DictEncoding {
         array: &Array,
         _config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
-        // TODO(robert): Add support for VarBinView
-        if array.encoding().id() != Primitive::ID && array.encoding().id() != VarBin::ID {
+        if array.encoding().id() != Primitive::ID
+            && array.encoding().id() != VarBin::ID
+            && array.encoding().id() != VarBinView::ID
+        {
             return None;
         };
 
@@ -82,6 +78,21 @@ impl EncodingCompression for DictEncoding {
                     )?,
                 )
             }
+            VarBinView::ID => {
+                let vbv = VarBinViewArray::try_from(array).unwrap();
+                // Keep the dictionary as views, such that the array flattens back into views.
+                let (codes, dict) = dict_encode_varbinview(&vbv)?;
+                (
+                    ctx.auxiliary("codes").excluding(&DictEncoding).compress(
+                        &codes.to_array(),
+                        dict_like_ref.map(|dict| dict.codes()).as_ref(),
+                    )?,
+                    ctx.named("values").excluding(&DictEncoding).compress(
+                        &dict.to_array(),
+                        dict_like_ref.map(|dict| dict.values()).as_ref(),
+                    )?,
+                )
+            }
 
             _ => unreachable!("This array kind should have been filtered out"),
         };
@@ -90,162 +101,6 @@ impl EncodingCompression for DictEncoding {
     }
 }
 
-#[derive(Debug)]
-struct Value<T>(T);
-
-impl<T: AsBytes> Hash for Value<T> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.as_bytes().hash(state)
-    }
-}
-
-impl<T: AsBytes> PartialEq<Self> for Value<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.as_bytes().eq(other.0.as_bytes())
-    }
-}
-
-impl<T: AsBytes> Eq for Value<T> {}
-
-/// Dictionary encode primitive array with given PType.
-/// Null values in the original array are encoded in the dictionary.
-pub fn dict_encode_typed_primitive<'a, T: NativePType>(
-    array: &PrimitiveArray<'a>,
-) -> (PrimitiveArray<'a>, PrimitiveArray<'a>) {
-    let mut lookup_dict: HashMap<Value<T>, u64> = HashMap::new();
-    let mut codes: Vec<u64> = Vec::new();
-    let mut values: Vec<T> = Vec::new();
-
-    if array.dtype().is_nullable() {
-        values.push(T::zero());
-    }
-
-    ArrayAccessor::<T>::with_iterator(array, |iter| {
-        for ov in iter {
-            match ov {
-                None => codes.push(0),
-                Some(&v) => {
-                    let code = match lookup_dict.entry(Value(v)) {
-                        Entry::Occupied(o) => *o.get(),
-                        Entry::Vacant(vac) => {
-                            let next_code = values.len() as u64;
-                            vac.insert(next_code.as_());
-                            values.push(v);
-                            next_code
-                        }
-                    };
-                    codes.push(code);
-                }
-            }
-        }
-    })
-    .unwrap();
-
-    let values_validity = if array.dtype().is_nullable() {
-        let mut validity = vec![true; values.len()];
-        validity[0] = false;
-
-        validity.into()
-    } else {
-        Validity::NonNullable
-    };
-
-    (
-        PrimitiveArray::from(codes),
-        PrimitiveArray::from_vec(values, values_validity),
-    )
-}
-
-/// Dictionary encode varbin array. Specializes for primitive byte arrays to avoid double copying
-pub fn dict_encode_varbin<'a>(array: &'a VarBinArray) -> (PrimitiveArray<'a>, VarBinArray<'a>) {
-    array
-        .with_iterator(|iter| dict_encode_typed_varbin(array.dtype().clone(), iter))
-        .unwrap()
-}
-
-fn lookup_bytes<'a, T: NativePType + AsPrimitive<usize>>(
-    offsets: &'a [T],
-    bytes: &'a [u8],
-    idx: usize,
-) -> &'a [u8] {
-    let begin: usize = offsets[idx].as_();
-    let end: usize = offsets[idx + 1].as_();
-    &bytes[begin..end]
-}
-
-fn dict_encode_typed_varbin<'a, I, U>(
-    dtype: DType,
-    values: I,
-) -> (PrimitiveArray<'a>, VarBinArray<'a>)
-where
-    I: Iterator<Item = Option<U>>,
-    U: AsRef<[u8]>,
-{
-    let (lower, _) = values.size_hint();
-    let hasher = RandomState::new();
-    let mut lookup_dict: HashMap<u64, (), ()> = HashMap::with_hasher(());
-    let mut codes: Vec<u64> = Vec::with_capacity(lower);
-    let mut bytes: Vec<u8> = Vec::new();
-    let mut offsets: Vec<u64> = Vec::new();
-    offsets.push(0);
-
-    if dtype.is_nullable() {
-        offsets.push(0);
-    }
-
-    for o_val in values {
-        match o_val {
-            None => codes.push(0),
-            Some(val) => {
-                let byte_ref = val.as_ref();
-                let value_hash = hasher.hash_one(byte_ref);
-                let raw_entry = lookup_dict.raw_entry_mut().from_hash(value_hash, |idx| {
-                    byte_ref == lookup_bytes(offsets.as_slice(), bytes.as_slice(), idx.as_())
-                });
-
-                let code = match raw_entry {
-                    RawEntryMut::Occupied(o) => *o.into_key(),
-                    RawEntryMut::Vacant(vac) => {
-                        let next_code = offsets.len() as u64 - 1;
-                        bytes.extend_from_slice(byte_ref);
-                        offsets.push(bytes.len() as u64);
-                        vac.insert_with_hasher(value_hash, next_code, (), |idx| {
-                            hasher.hash_one(lookup_bytes(
-                                offsets.as_slice(),
-                                bytes.as_slice(),
-                                idx.as_(),
-                            ))
-                        });
-                        next_code
-                    }
-                };
-                codes.push(code)
-            }
-        }
-    }
-
-    let values_validity = if dtype.is_nullable() {
-        let mut validity = Vec::with_capacity(offsets.len() - 1);
-        validity.push(false);
-        validity.extend(vec![true; offsets.len() - 2]);
-
-        validity.into()
-    } else {
-        Validity::NonNullable
-    };
-
-    (
-        PrimitiveArray::from(codes),
-        VarBinArray::try_new(
-            PrimitiveArray::from(offsets).into_array(),
-            PrimitiveArray::from(bytes).into_array(),
-            dtype,
-            values_validity,
-        )
-        .unwrap(),
-    )
-}
-
 #[cfg(test)]
 mod test {
     use std::str;
@@ -253,11 +108,18 @@ mod test {
     use vortex::accessor::ArrayAccessor;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::varbin::VarBinArray;
-    use vortex::compute::scalar_at::scalar_at;
-    use vortex::ToArray;
+    use vortex::array::varbinview::{VarBinView, VarBinViewArray};
+    use vortex::compress::Compressor;
+    use vortex::compute::scalar_at::{scalar_at, scalar_values};
+    use vortex::encoding::EncodingRef;
+    use vortex::{ArrayDef, Context, ToArray};
+    use vortex_dtype::{DType, Nullability};
     use vortex_scalar::PrimitiveScalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{
+        dict_encode_typed_primitive, dict_encode_varbin, dict_encode_varbinview,
+    };
+    use crate::{DictArray, DictEncoding};
 
     #[test]
     fn encode_primitive() {
@@ -362,4 +224,56 @@ mod test {
             .unwrap();
         assert_eq!(codes.typed_data::<u64>(), &[0u64, 0, 1, 1, 0, 1, 0, 1]);
     }
+
+    #[test]
+    fn encode_varbinview() {
+        let values = vec![
+            Some("short"),
+            Some("a value that is too long to inline"),
+            None,
+            Some("short"),
+            Some("a value that is too long to inline"),
+            Some("a value that is too long to inline, with a longer suffix"),
+            None,
+            Some("shorter"),
+        ];
+        let arr = VarBinViewArray::from_iter(values.clone(), DType::Utf8(Nullability::Nullable));
+
+        let (codes, views) = dict_encode_varbinview(&arr).unwrap();
+        assert_eq!(codes.typed_data::<u64>(), &[1, 2, 0, 1, 2, 3, 0, 4]);
+
+        let expected = [
+            None,
+            Some("short"),
+            Some("a value that is too long to inline"),
+            Some("a value that is too long to inline, with a longer suffix"),
+            Some("shorter"),
+        ];
+        assert_eq!(
+            scalar_values::<String>(&views.to_array()).unwrap(),
+            expected
+                .iter()
+                .map(|v| v.map(String::from))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn compress_varbinview() {
+        let arr = VarBinViewArray::from_vec(
+            (0..1000)
+                .map(|i| ["apple", "banana", "a much longer cherry value"][i % 3])
+                .collect(),
+            DType::Utf8(Nullability::NonNullable),
+        );
+        let ctx = Context::default().with_encoding(&DictEncoding as EncodingRef);
+        let compressed = Compressor::new(&ctx).compress(arr.array(), None).unwrap();
+        let dict = DictArray::try_from(&compressed).unwrap();
+        assert_eq!(dict.values().len(), 3);
+        assert_eq!(dict.values().encoding().id(), VarBinView::ID);
+        assert_eq!(
+            String::try_from(scalar_at(&compressed, 2).unwrap()).unwrap(),
+            "a much longer cherry value"
+        );
+    }
 }
@@ -1,14 +1,50 @@
+use vortex::array::bool::BoolArray;
+use vortex::compute::aggregate::{count, min_max, weighted_sum, CountDistinctFn, MinMaxFn, SumFn};
+use vortex::compute::cast::{cast, CastFn};
+use vortex::compute::compare::{compare, CompareFn, Operator};
+use vortex::compute::filter::{filter, FilterFn};
+use vortex::compute::hash::{hash, HashFn};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::string::{
+    length, string_match, string_transform, StringLengthFn, StringMatchFn, StringPredicate,
+    StringTransform, StringTransformFn,
+};
 use vortex::compute::take::{take, TakeFn};
+use vortex::compute::unique::{code_counts, dict_value_counts, ValueCountsFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray, OwnedArray};
+use vortex::{Array, ArrayDType, IntoArray, OwnedArray};
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray<'_> {
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -17,9 +53,40 @@ impl ArrayCompute for DictArray<'_> {
         Some(self)
     }
 
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn string_transform(&self) -> Option<&dyn StringTransformFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn value_counts(&self) -> Option<&dyn ValueCountsFn> {
+        Some(self)
+    }
+}
+
+impl CastFn for DictArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        // Null values are stored as a null in the dictionary, which can't be cast to a
+        // non-nullable dtype even if no code refers to it.
+        if self.dtype().is_nullable() && !dtype.is_nullable() {
+            return cast(&self.array().clone().flatten()?.into_array(), dtype);
+        }
+        DictArray::try_new(self.codes(), cast(&self.values(), dtype)?).map(|a| a.into_array())
+    }
 }
 
 impl ScalarAtFn for DictArray<'_> {
@@ -29,6 +96,85 @@ impl ScalarAtFn for DictArray<'_> {
     }
 }
 
+impl CompareFn for DictArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        // Compare each distinct value once, then look up the result of every code.
+        let compared_values = compare(&self.values(), other, operator)?;
+        take(&compared_values, &self.codes())
+    }
+}
+
+impl SumFn for DictArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        // Each value contributes as often as its code occurs.
+        weighted_sum(
+            &self.values(),
+            &code_counts(&self.codes(), self.values().len())?,
+        )
+    }
+}
+
+impl MinMaxFn for DictArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        min_max(&used_values(self)?)
+    }
+}
+
+impl CountDistinctFn for DictArray<'_> {
+    fn count_distinct(&self) -> VortexResult<usize> {
+        // The values of a dictionary are distinct, so this is exact.
+        count(&used_values(self)?)
+    }
+}
+
+impl HashFn for DictArray<'_> {
+    fn hash(&self) -> VortexResult<OwnedArray> {
+        // Hash each distinct value once, then look up the hash of every code.
+        take(&hash(&self.values())?, &self.codes())
+    }
+}
+
+impl StringMatchFn for DictArray<'_> {
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+        // Match each distinct value once, then look up the result of every code.
+        take(&string_match(&self.values(), predicate)?, &self.codes())
+    }
+}
+
+impl StringLengthFn for DictArray<'_> {
+    fn string_length(&self) -> VortexResult<OwnedArray> {
+        take(&length(&self.values())?, &self.codes())
+    }
+}
+
+impl StringTransformFn for DictArray<'_> {
+    fn string_transform(&self, transform: &StringTransform) -> VortexResult<OwnedArray> {
+        // Transformed values may no longer be distinct, which a dictionary tolerates.
+        DictArray::try_new(self.codes(), string_transform(&self.values(), transform)?)
+            .map(|a| a.into_array())
+    }
+}
+
+impl ValueCountsFn for DictArray<'_> {
+    fn value_counts(&self) -> VortexResult<(OwnedArray, OwnedArray)> {
+        // The values are already distinct, so only the codes need to be counted.
+        dict_value_counts(&self.codes(), &self.values())
+    }
+}
+
+/// The values that are referenced by at least one code.
+fn used_values(array: &DictArray) -> VortexResult<OwnedArray> {
+    dict_value_counts(&array.codes(), &array.values()).map(|(values, _)| values)
+}
+
+impl FilterFn for DictArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        // Only the codes need to be filtered, the dictionary is kept as is.
+        let codes = filter(&self.codes(), mask)?;
+        DictArray::try_new(codes, self.values()).map(|a| a.into_array())
+    }
+}
+
 impl TakeFn for DictArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         // Dict
@@ -49,10 +195,20 @@ impl SliceFn for DictArray<'_> {
 
 #[cfg(test)]
 mod test {
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::bool::BoolArray;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::varbin::VarBinArray;
-    use vortex::{IntoArray, ToArray};
-    use vortex_dtype::{DType, Nullability};
+    use vortex::compute::aggregate::{count_distinct, min_max, sum};
+    use vortex::compute::cast::cast;
+    use vortex::compute::compare::{compare, Operator};
+    use vortex::compute::filter::filter;
+    use vortex::compute::hash::hash;
+    use vortex::compute::slice::slice;
+    use vortex::compute::string::{length, starts_with, upper};
+    use vortex::compute::unique::value_counts;
+    use vortex::{ArrayDType, IntoArray, ToArray};
+    use vortex_dtype::{DType, Nullability, PType};
 
     use crate::{dict_encode_typed_primitive, dict_encode_varbin, DictArray};
 
@@ -94,4 +250,173 @@ mod test {
             reference.bytes().flatten_primitive().unwrap().buffer()
         );
     }
+
+    #[test]
+    fn compare_values() {
+        let reference = VarBinArray::from_iter(
+            vec![Some("a"), Some("b"), None, Some("a"), Some("c")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let result = compare(dict.array(), &"a".into(), Operator::Eq)
+            .unwrap()
+            .flatten_bool()
+            .unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true, false]
+        );
+        assert!(!result.array().with_dyn(|a| a.is_valid(2)));
+    }
+
+    #[test]
+    fn filter_codes() {
+        let reference = PrimitiveArray::from(vec![3i32, 7, 3, 3, 7]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let mask = BoolArray::from(vec![false, true, true, false, true]);
+        let filtered = DictArray::try_from(filter(dict.array(), &mask).unwrap()).unwrap();
+        assert_eq!(filtered.values().len(), 2);
+        assert_eq!(
+            filtered
+                .into_array()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            [7, 3, 7]
+        );
+    }
+
+    #[test]
+    fn aggregate_codes() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(42),
+            Some(-9),
+            None,
+            Some(42),
+            None,
+            Some(-9),
+        ]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        assert_eq!(sum(&dict).unwrap(), Some(66i64).into());
+        assert_eq!(
+            min_max(&dict).unwrap(),
+            (Some(-9i32).into(), Some(42i32).into())
+        );
+        assert_eq!(count_distinct(&dict).unwrap(), 2);
+    }
+
+    #[test]
+    fn hash_and_value_counts() {
+        let reference = VarBinArray::from_iter(
+            vec![Some("a"), Some("b"), None, Some("a"), Some("c"), Some("a")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let hashes = hash(&dict.to_array()).unwrap().flatten_primitive().unwrap();
+        let expected = hash(&reference.to_array())
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(hashes.typed_data::<u64>(), expected.typed_data::<u64>());
+
+        // Values that no code refers to are left out.
+        let sliced = slice(&dict.to_array(), 3, 6).unwrap();
+        let (values, counts) = value_counts(&sliced).unwrap();
+        assert_eq!(
+            values
+                .flatten_varbin()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(b"a".to_vec()), Some(b"c".to_vec())]
+        );
+        assert_eq!(
+            counts.flatten_primitive().unwrap().typed_data::<u64>(),
+            [2, 1]
+        );
+    }
+
+    #[test]
+    fn cast_values() {
+        let reference = PrimitiveArray::from(vec![3u8, 7, 3, 3]);
+        let (codes, values) = dict_encode_typed_primitive::<u8>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let dtype = DType::Primitive(PType::I64, Nullability::Nullable);
+        let casted = cast(dict.array(), &dtype).unwrap();
+        let casted_dict = DictArray::try_from(&casted).unwrap();
+        assert_eq!(casted_dict.values().dtype(), &dtype);
+        assert_eq!(
+            casted.flatten_primitive().unwrap().typed_data::<i64>(),
+            [3, 7, 3, 3]
+        );
+
+        let nullable = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&nullable);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let non_nullable = DType::Primitive(PType::I32, Nullability::NonNullable);
+        assert!(cast(dict.array(), &non_nullable).is_err());
+        let sliced = slice(dict.array(), 0, 1).unwrap();
+        assert_eq!(
+            cast(&sliced, &non_nullable)
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            [1]
+        );
+    }
+
+    #[test]
+    fn string_values() {
+        let reference = VarBinArray::from_iter(
+            vec![Some("apple"), None, Some("banana"), Some("apple")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let matched = starts_with(dict.array(), "app")
+            .unwrap()
+            .flatten_bool()
+            .unwrap();
+        assert_eq!(
+            matched.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+        assert!(!matched.array().with_dyn(|a| a.is_valid(1)));
+        assert_eq!(
+            length(dict.array())
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<u64>()[2],
+            6
+        );
+
+        let upper = upper(dict.array()).unwrap();
+        let upper_dict = DictArray::try_from(&upper).unwrap();
+        assert_eq!(upper_dict.values().len(), dict.values().len());
+        assert_eq!(
+            upper
+                .flatten_varbin()
+                .unwrap()
+                .with_iterator(|iter| iter
+                    .map(|v| v.map(|v| String::from_utf8(v.to_vec()).unwrap()))
+                    .collect::<Vec<_>>())
+                .unwrap(),
+            [
+                Some("APPLE".to_string()),
+                None,
+                Some("BANANA".to_string()),
+                Some("APPLE".to_string())
+            ]
+        );
+    }
 }
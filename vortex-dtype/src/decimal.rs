@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, ExtMetadata, Nullability, PType};
+
+/// A fixed-point decimal type, holding values as an integer scaled by `10^-scale`.
+///
+/// Decimals are represented as an extension type. Decimals with a precision of up to
+/// [`DecimalDType::MAX_I64_PRECISION`] are stored as `i64`, wider decimals are stored as 16-byte
+/// (or, beyond [`DecimalDType::MAX_I128_PRECISION`], 32-byte) little-endian two's complement binary
+/// values until Vortex has wider primitive types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalDType {
+    precision: u8,
+    scale: i8,
+}
+
+impl DecimalDType {
+    pub const ID: &'static str = "vortex.decimal";
+
+    /// The maximum precision of a decimal, matching that of Arrow's Decimal256.
+    pub const MAX_PRECISION: u8 = 76;
+
+    /// The maximum precision of a decimal whose values always fit into an i128.
+    pub const MAX_I128_PRECISION: u8 = 38;
+
+    /// The maximum precision of a decimal whose values always fit into an i64.
+    pub const MAX_I64_PRECISION: u8 = 18;
+
+    pub fn try_new(precision: u8, scale: i8) -> VortexResult<Self> {
+        if precision == 0 || precision > Self::MAX_PRECISION {
+            vortex_bail!(
+                "Decimal precision must be between 1 and {}, got {}",
+                Self::MAX_PRECISION,
+                precision
+            )
+        }
+        if scale > 0 && scale as u8 > precision {
+            vortex_bail!(
+                "Decimal scale {} is greater than its precision {}",
+                scale,
+                precision
+            )
+        }
+        Ok(Self { precision, scale })
+    }
+
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[inline]
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Whether values of this decimal are stored as i64, rather than as 16-byte binary.
+    #[inline]
+    pub fn is_i64(&self) -> bool {
+        self.precision <= Self::MAX_I64_PRECISION
+    }
+
+    /// The width in bytes of a single stored value.
+    #[inline]
+    pub fn value_width(&self) -> usize {
+        if self.is_i64() {
+            8
+        } else if self.precision <= Self::MAX_I128_PRECISION {
+            16
+        } else {
+            32
+        }
+    }
+
+    /// The little-endian binary representation of an unscaled value of a decimal that is not
+    /// stored as i64, sign extended to [`DecimalDType::value_width`].
+    pub fn value_to_bytes(&self, value: i128) -> VortexResult<Vec<u8>> {
+        if self.is_i64() {
+            vortex_bail!("Values of {} are stored as i64, not as binary", self)
+        }
+        let sign = if value < 0 { 0xFF } else { 0 };
+        let mut bytes = value.to_le_bytes().to_vec();
+        bytes.resize(self.value_width(), sign);
+        Ok(bytes)
+    }
+
+    /// Read an unscaled value from its little-endian binary representation, returning an error if
+    /// it does not fit into an i128.
+    pub fn value_from_bytes(&self, bytes: &[u8]) -> VortexResult<i128> {
+        if self.is_i64() {
+            vortex_bail!("Values of {} are stored as i64, not as binary", self)
+        }
+        if bytes.len() != self.value_width() {
+            vortex_bail!(
+                "Values of {} must be {} bytes, got {}",
+                self,
+                self.value_width(),
+                bytes.len()
+            )
+        }
+        let (low, high) = bytes.split_at(16);
+        let value = i128::from_le_bytes(low.try_into().expect("split at 16 bytes"));
+        let sign = if value < 0 { 0xFF } else { 0 };
+        if high.iter().any(|&b| b != sign) {
+            vortex_bail!("Value of {} does not fit into an i128", self)
+        }
+        Ok(value)
+    }
+
+    /// The DType of the storage array holding the unscaled values of this decimal.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        if self.is_i64() {
+            DType::Primitive(PType::I64, nullability)
+        } else {
+            DType::Binary(nullability)
+        }
+    }
+
+    pub fn ext_dtype(&self) -> ExtDType {
+        ExtDType::new(
+            ExtID::from(Self::ID),
+            Some(ExtMetadata::from(
+                [self.precision, self.scale as u8].as_ref(),
+            )),
+        )
+    }
+
+    pub fn dtype(&self, nullability: Nullability) -> DType {
+        DType::Extension(self.ext_dtype(), nullability)
+    }
+
+    /// Format an unscaled value of this decimal, e.g. `12345` with scale 2 as `123.45`.
+    pub fn format_value(&self, value: i128) -> String {
+        self.format_digits(value < 0, value.unsigned_abs().to_string())
+    }
+
+    /// Format an unscaled value of this decimal from its little-endian two's complement binary
+    /// representation, which may be wider than an i128.
+    pub fn format_le_bytes(&self, bytes: &[u8]) -> String {
+        let negative = bytes.last().map_or(false, |&b| b & 0x80 != 0);
+        // Take the absolute value as big-endian 32-bit limbs, then repeatedly divide by 10.
+        let mut magnitude = bytes.to_vec();
+        if negative {
+            let mut carry = true;
+            for b in magnitude.iter_mut() {
+                let (v, c) = (!*b).overflowing_add(carry as u8);
+                *b = v;
+                carry = c;
+            }
+        }
+        let mut limbs = magnitude
+            .chunks(4)
+            .rev()
+            .map(|c| c.iter().rev().fold(0u32, |acc, &b| (acc << 8) | b as u32))
+            .collect::<Vec<_>>();
+        let mut digits = Vec::new();
+        while limbs.iter().any(|&l| l != 0) {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut() {
+                let current = (remainder << 32) | *limb as u64;
+                *limb = (current / 10) as u32;
+                remainder = current % 10;
+            }
+            digits.push(b'0' + remainder as u8);
+        }
+        if digits.is_empty() {
+            digits.push(b'0');
+        }
+        digits.reverse();
+        self.format_digits(
+            negative,
+            String::from_utf8(digits).expect("digits are ASCII"),
+        )
+    }
+
+    fn format_digits(&self, negative: bool, digits: String) -> String {
+        if digits == "0" && self.scale <= 0 {
+            return "0".to_string();
+        }
+        let sign = if negative { "-" } else { "" };
+        if self.scale <= 0 {
+            return format!(
+                "{}{}{}",
+                sign,
+                digits,
+                "0".repeat(-(self.scale as i32) as usize)
+            );
+        }
+
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl Display for DecimalDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "decimal({}, {})", self.precision, self.scale)
+    }
+}
+
+impl TryFrom<&ExtDType> for DecimalDType {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtDType) -> Result<Self, Self::Error> {
+        if value.id().as_ref() != Self::ID {
+            vortex_bail!("Extension type {} is not a decimal", value.id())
+        }
+        let metadata: [u8; 2] = value
+            .metadata()
+            .ok_or_else(|| vortex_err!("Decimal extension type is missing its metadata"))?
+            .as_ref()
+            .try_into()
+            .map_err(|_| vortex_err!("Invalid decimal extension metadata"))?;
+        Self::try_new(metadata[0], metadata[1] as i8)
+    }
+}
+
+impl TryFrom<&DType> for DecimalDType {
+    type Error = VortexError;
+
+    fn try_from(value: &DType) -> Result<Self, Self::Error> {
+        match value {
+            DType::Extension(ext, _) => DecimalDType::try_from(ext),
+            _ => Err(vortex_err!(MismatchedTypes: "decimal", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DType, DecimalDType, Nullability};
+
+    #[test]
+    fn ext_dtype_round_trip() {
+        let decimal = DecimalDType::try_new(10, -2).unwrap();
+        let dtype = decimal.dtype(Nullability::Nullable);
+        assert_eq!(DecimalDType::try_from(&dtype).unwrap(), decimal);
+        assert!(DecimalDType::try_from(&DType::Null).is_err());
+    }
+
+    #[test]
+    fn invalid_precision() {
+        assert!(DecimalDType::try_new(0, 0).is_err());
+        assert!(DecimalDType::try_new(77, 0).is_err());
+        assert!(DecimalDType::try_new(5, 6).is_err());
+    }
+
+    #[test]
+    fn format_value() {
+        let decimal = DecimalDType::try_new(10, 2).unwrap();
+        assert_eq!(decimal.format_value(12345), "123.45");
+        assert_eq!(decimal.format_value(-5), "-0.05");
+        assert_eq!(decimal.format_value(0), "0.00");
+        let decimal = DecimalDType::try_new(10, -2).unwrap();
+        assert_eq!(decimal.format_value(12), "1200");
+    }
+
+    #[test]
+    fn wide_values() {
+        let decimal = DecimalDType::try_new(76, 2).unwrap();
+        assert_eq!(decimal.value_width(), 32);
+        let bytes = decimal.value_to_bytes(-12345).unwrap();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(decimal.value_from_bytes(&bytes).unwrap(), -12345);
+        assert_eq!(decimal.format_le_bytes(&bytes), "-123.45");
+
+        let mut wide = [0u8; 32];
+        wide[16] = 1;
+        assert!(decimal.value_from_bytes(&wide).is_err());
+        // 2^128 / 100
+        assert_eq!(
+            decimal.format_le_bytes(&wide),
+            "3402823669209384634633746074317682114.56"
+        );
+    }
+
+    #[test]
+    fn i64_values_are_not_binary() {
+        let decimal = DecimalDType::try_new(10, 2).unwrap();
+        assert!(decimal.value_to_bytes(12345).is_err());
+        assert!(decimal.value_from_bytes(&[0u8; 8]).is_err());
+    }
+}
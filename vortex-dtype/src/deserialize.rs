@@ -2,7 +2,7 @@ use itertools::Itertools;
 use vortex_error::{vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::ReadFlatBuffer;
 
-use crate::{flatbuffers as fb, ExtDType, ExtID, ExtMetadata, Nullability};
+use crate::{flatbuffers as fb, DecimalDType, ExtDType, ExtID, ExtMetadata, Nullability};
 use crate::{DType, StructDType};
 
 impl ReadFlatBuffer for DType {
@@ -56,6 +56,13 @@ impl ReadFlatBuffer for DType {
                     fb_struct.nullability().try_into()?,
                 ))
             }
+            fb::Type::Decimal => {
+                let fb_decimal = fb.type__as_decimal().unwrap();
+                Ok(
+                    DecimalDType::try_new(fb_decimal.precision(), fb_decimal.scale())?
+                        .dtype(fb_decimal.nullability().try_into()?),
+                )
+            }
             fb::Type::Extension => {
                 let fb_ext = fb.type__as_extension().unwrap();
                 let id = ExtID::from(fb_ext.id().unwrap());
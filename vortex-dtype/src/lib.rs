@@ -1,7 +1,9 @@
+pub use decimal::*;
 pub use dtype::*;
 pub use extension::*;
 pub use half;
 pub use ptype::*;
+mod decimal;
 mod deserialize;
 mod dtype;
 mod extension;
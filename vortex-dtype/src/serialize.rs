@@ -3,7 +3,7 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, VortexError};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
-use crate::{flatbuffers as fb, PType};
+use crate::{flatbuffers as fb, DecimalDType, PType};
 use crate::{DType, Nullability};
 
 impl FlatBufferRoot for DType {}
@@ -81,19 +81,31 @@ impl WriteFlatBuffer for DType {
                 )
                 .as_union_value()
             }
-            DType::Extension(ext, n) => {
-                let id = Some(fbb.create_string(ext.id().as_ref()));
-                let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
-                fb::Extension::create(
+            DType::Extension(ext, n) => match DecimalDType::try_from(ext) {
+                Ok(decimal) => fb::Decimal::create(
                     fbb,
-                    &fb::ExtensionArgs {
-                        id,
-                        metadata,
+                    &fb::DecimalArgs {
+                        precision: decimal.precision(),
+                        scale: decimal.scale(),
                         nullability: n.into(),
                     },
                 )
-                .as_union_value()
-            }
+                .as_union_value(),
+                // Extensions with the decimal ID but invalid metadata are written as they are.
+                Err(_) => {
+                    let id = Some(fbb.create_string(ext.id().as_ref()));
+                    let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
+                    fb::Extension::create(
+                        fbb,
+                        &fb::ExtensionArgs {
+                            id,
+                            metadata,
+                            nullability: n.into(),
+                        },
+                    )
+                    .as_union_value()
+                }
+            },
         };
 
         let dtype_type = match self {
@@ -104,6 +116,7 @@ impl WriteFlatBuffer for DType {
             DType::Binary(_) => fb::Type::Binary,
             DType::Struct(..) => fb::Type::Struct_,
             DType::List(..) => fb::Type::List,
+            DType::Extension(ext, _) if DecimalDType::try_from(ext).is_ok() => fb::Type::Decimal,
             DType::Extension { .. } => fb::Type::Extension,
         };
 
@@ -180,7 +193,9 @@ mod test {
     use flatbuffers::root;
     use vortex_flatbuffers::{FlatBufferToBytes, ReadFlatBuffer};
 
-    use crate::{flatbuffers as fb, PType, StructDType};
+    use crate::{
+        flatbuffers as fb, DecimalDType, ExtDType, ExtID, ExtMetadata, PType, StructDType,
+    };
     use crate::{DType, Nullability};
 
     fn roundtrip_dtype(dtype: DType) {
@@ -209,6 +224,18 @@ mod test {
                 ],
             ),
             Nullability::NonNullable,
-        ))
+        ));
+        roundtrip_dtype(
+            DecimalDType::try_new(38, 4)
+                .unwrap()
+                .dtype(Nullability::Nullable),
+        );
+        roundtrip_dtype(DType::Extension(
+            ExtDType::new(
+                ExtID::from(DecimalDType::ID),
+                Some(ExtMetadata::from([80u8, 0].as_ref())),
+            ),
+            Nullability::NonNullable,
+        ));
     }
 }
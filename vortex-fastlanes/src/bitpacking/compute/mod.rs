@@ -5,16 +5,18 @@ use itertools::Itertools;
 use vortex::array::constant::ConstantArray;
 use vortex::array::primitive::PrimitiveArray;
 use vortex::array::sparse::SparseArray;
+use vortex::compute::aggregate::{count, merge_sums, sum, sum_ptype, SumFn};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
+use vortex::validity::ArrayValidity;
 use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
-use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_dtype::{match_each_integer_ptype, NativePType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{PrimitiveScalar, Scalar};
 
-use crate::bitpacking::compress::unpack_single;
+use crate::bitpacking::compress::{unpack, unpack_single};
 use crate::{match_integers_by_width, unpack_single_primitive, BitPackedArray};
 
 mod slice;
@@ -28,6 +30,10 @@ impl ArrayCompute for BitPackedArray<'_> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -48,6 +54,73 @@ impl ScalarAtFn for BitPackedArray<'_> {
     }
 }
 
+impl SumFn for BitPackedArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let ptype = PType::try_from(self.dtype())?;
+        // Packed values are only non-negative if they use fewer bits than the ptype.
+        if ptype.is_signed_int() && self.bit_width() >= ptype.bit_width() {
+            return sum(&unpack(self.clone())?.into_array());
+        }
+        if count(self.array())? == 0 {
+            return Ok(PrimitiveScalar::none_from_ptype(sum_ptype(ptype)).into());
+        }
+
+        // Sum one chunk of 1024 values at a time, skipping nulls and the positions held by patches.
+        let validity = self.logical_validity().to_null_buffer()?;
+        let patched = self
+            .patches()
+            .map(|p| {
+                p.with_dyn(|a| a.logical_validity())
+                    .to_present_null_buffer()
+            })
+            .transpose()?;
+        let bit_width = self.bit_width();
+        let offset = self.offset();
+        let mut total = 0u64;
+        if bit_width > 0 {
+            let packed = self.packed().flatten_primitive()?;
+            match_integers_by_width!(ptype, |$P| {
+                let mut unpacked: Vec<$P> = Vec::with_capacity(1024);
+                for (chunk, packed_chunk) in packed
+                    .typed_data::<u8>()
+                    .chunks_exact(128 * bit_width)
+                    .enumerate()
+                {
+                    unpacked.clear();
+                    TryBitPack::try_unpack_into(packed_chunk, bit_width, &mut unpacked)
+                        .map_err(|_| vortex_err!("Unsupported bit width {}", bit_width))?;
+                    for (i, &v) in unpacked.iter().enumerate() {
+                        let Some(index) = (chunk * 1024 + i).checked_sub(offset) else {
+                            continue;
+                        };
+                        if index >= self.len() {
+                            break;
+                        }
+                        if validity.as_ref().map_or(false, |n| n.is_null(index))
+                            || patched.as_ref().map_or(false, |p| p.is_valid(index))
+                        {
+                            continue;
+                        }
+                        total = total
+                            .checked_add(v as u64)
+                            .ok_or_else(|| vortex_err!("Sum of {} values overflowed", ptype))?;
+                    }
+                }
+            })
+        }
+
+        let packed_sum: Scalar = if ptype.is_signed_int() {
+            let total = i64::try_from(total)
+                .map_err(|_| vortex_err!("Sum of {} values overflowed", ptype))?;
+            PrimitiveScalar::nullable(Some(total)).into()
+        } else {
+            PrimitiveScalar::nullable(Some(total)).into()
+        };
+        let patches_sum = self.patches().map(|p| sum(&p)).transpose()?;
+        merge_sums(ptype, [Some(packed_sum), patches_sum].into_iter().flatten())
+    }
+}
+
 impl TakeFn for BitPackedArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         let ptype = self.dtype().try_into()?;
@@ -181,7 +254,9 @@ mod test {
     use vortex::array::primitive::{Primitive, PrimitiveArray};
     use vortex::array::sparse::SparseArray;
     use vortex::compress::Compressor;
+    use vortex::compute::aggregate::sum;
     use vortex::compute::scalar_at::scalar_at;
+    use vortex::compute::slice::slice;
     use vortex::compute::take::take;
     use vortex::{ArrayDef, Context, IntoArray};
 
@@ -263,4 +338,24 @@ mod test {
             );
         });
     }
+
+    #[test]
+    fn sum_patched() {
+        let values = (0..3000u32)
+            .map(|i| if i == 1234 { 100_000 } else { i % 100 })
+            .collect::<Vec<_>>();
+        let expected =
+            |start: usize, stop: usize| values[start..stop].iter().map(|&v| v as u64).sum::<u64>();
+        let bitpacked = BitPackedArray::encode(PrimitiveArray::from(values.clone()).array(), 7)
+            .unwrap()
+            .into_array();
+        assert!(BitPackedArray::try_from(&bitpacked)
+            .unwrap()
+            .patches()
+            .is_some());
+        assert_eq!(sum(&bitpacked).unwrap(), Some(expected(0, 3000)).into());
+
+        let sliced = slice(&bitpacked, 1030, 2500).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Some(expected(1030, 2500)).into());
+    }
 }
@@ -112,11 +112,11 @@ fn decompress_primitive<T: NativePType + WrappingAdd + PrimInt>(
     shift: u8,
 ) -> Vec<T> {
     if shift > 0 {
-        let shifted_reference = reference << shift as usize;
+        // The reference has at least `shift` trailing zeros, so it is added back unshifted.
         values
             .iter()
             .map(|&v| v << shift as usize)
-            .map(|v| v.wrapping_add(&shifted_reference))
+            .map(|v| v.wrapping_add(&reference))
             .collect_vec()
     } else {
         values
@@ -182,6 +182,22 @@ mod test {
         assert_eq!(decompressed.typed_data::<u32>(), array.typed_data::<u32>());
     }
 
+    #[test]
+    fn test_decompress_shifted() {
+        // Every value is a multiple of 8 and the minimum is nonzero, so the encoded values are
+        // shifted relative to the reference.
+        let array = PrimitiveArray::from((0i32..10_000).map(|v| v * 8 + 4096).collect_vec());
+        let compressed = FoREncoding {}
+            .compress(array.array(), None, Compressor::new(&ctx()))
+            .unwrap();
+        let for_array = FoRArray::try_from(&compressed).unwrap();
+        assert_eq!(for_array.shift(), 3);
+        assert_eq!(i32::try_from(for_array.reference()).unwrap(), 4096);
+
+        let decompressed = compressed.flatten_primitive().unwrap();
+        assert_eq!(decompressed.typed_data::<i32>(), array.typed_data::<i32>());
+    }
+
     #[test]
     fn test_overflow() {
         // Create a range offset by a million
@@ -1,15 +1,37 @@
+use vortex::array::bool::BoolArray;
+use vortex::compute::aggregate::{count, merge_sums, sum, sum_ptype, sum_repeated, SumFn};
+use vortex::compute::arithmetic::{
+    arithmetic_scalar, scalar_arithmetic, ArithmeticScalarFn, BinaryOperator, OverflowMode,
+    PrimitiveArithmetic,
+};
+use vortex::compute::cast::{cast, CastFn};
+use vortex::compute::compare::{compare, CompareFn, Operator};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray, OwnedArray};
-use vortex_dtype::match_each_integer_ptype;
-use vortex_error::VortexResult;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
+use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::{PrimitiveScalar, Scalar};
 
 use crate::FoRArray;
 
 impl ArrayCompute for FoRArray<'_> {
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -18,11 +40,169 @@ impl ArrayCompute for FoRArray<'_> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl ArithmeticScalarFn for FoRArray<'_> {
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        // Adding to every value only moves the reference, as long as no value overflows. The
+        // reference must keep at least `shift` trailing zeros for the encoded values to stay
+        // valid, and wrapped values would no longer be offsets from it.
+        let ptype = PType::try_from(self.dtype())?;
+        let shift = self.shift() as usize;
+        let pushdown = matches!(operator, BinaryOperator::Add | BinaryOperator::Sub)
+            && mode != OverflowMode::Wrapping
+            && match_each_integer_ptype!(ptype, |$T| {
+                let rhs = <$T>::try_from(other)?;
+                let in_range = |stat: Stat| {
+                    self.statistics()
+                        .compute_as_cast::<$T>(stat)
+                        .and_then(|v| v.apply(rhs, operator, OverflowMode::Checked))
+                        .is_ok()
+                };
+                (rhs.trailing_zeros() as usize) >= shift
+                    && in_range(Stat::Min)
+                    && in_range(Stat::Max)
+            });
+
+        if !pushdown {
+            let decoded = self.array().clone().flatten_primitive()?;
+            return arithmetic_scalar(&decoded.into_array(), other, operator, mode);
+        }
+
+        FoRArray::try_new(
+            self.encoded(),
+            scalar_arithmetic(self.reference(), other, operator, OverflowMode::Checked)?,
+            self.shift(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl CastFn for FoRArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        let ptype = PType::try_from(self.dtype())?;
+        let encoded = self.encoded();
+
+        // Widening to a larger integer keeps every value an offset from the same reference, as
+        // long as the encoded values did not wrap around and the reference fits the new type.
+        let widened = match dtype {
+            DType::Primitive(to, _) => {
+                to.is_int()
+                    && to.bit_width() > ptype.bit_width()
+                    && (self.shift() as usize) < ptype.bit_width()
+                    && (!ptype.is_signed_int()
+                        || encoded
+                            .statistics()
+                            .compute_as_cast::<i64>(Stat::Min)
+                            .map_or(false, |min| min >= 0))
+                    && (!to.is_unsigned_int()
+                        || !match_each_integer_ptype!(ptype, |$T| {
+                            <$T>::try_from(self.reference())? < <$T>::default()
+                        }))
+            }
+            _ => false,
+        };
+        if !widened {
+            return cast(
+                &self.array().clone().flatten_primitive()?.into_array(),
+                dtype,
+            );
+        }
+
+        FoRArray::try_new(
+            cast(&encoded, dtype)?,
+            self.reference().clone(),
+            self.shift(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl CompareFn for FoRArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        let ptype = PType::try_from(self.dtype())?;
+        let shift = self.shift() as usize;
+
+        // The encoded values may wrap around, so they only preserve equality.
+        if !matches!(operator, Operator::Eq | Operator::NotEq) || shift >= ptype.bit_width() {
+            let decoded = self.array().clone().flatten_primitive()?;
+            return compare(&decoded.into_array(), other, operator);
+        }
+
+        match_each_integer_ptype!(ptype, |$T| {
+            let value = <$T>::try_from(other)?;
+            let reference = <$T>::try_from(self.reference())?;
+
+            // Every encoded value had at least `shift` trailing zeros, so none can be equal to
+            // a scalar with fewer.
+            if (value.trailing_zeros() as usize) < shift {
+                let matches = vec![operator == Operator::NotEq; self.len()];
+                return Ok(
+                    BoolArray::from_vec(matches, self.logical_validity().into_validity())
+                        .into_array(),
+                );
+            }
+
+            let encoded_value = (value >> shift).wrapping_sub(reference >> shift);
+            compare(&self.encoded(), &encoded_value.into(), operator)
+        })
+    }
+}
+
+impl SumFn for FoRArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let ptype = PType::try_from(self.dtype())?;
+        let shift = self.shift() as usize;
+        // Signed encoded values are only offsets from the reference if they did not wrap around.
+        let encoded = self.encoded();
+        let non_negative = !ptype.is_signed_int()
+            || encoded
+                .statistics()
+                .compute_as_cast::<i64>(Stat::Min)
+                .map_or(false, |min| min >= 0);
+        if !non_negative || shift >= ptype.bit_width() {
+            return sum(&self.array().clone().flatten_primitive()?.into_array());
+        }
+
+        // Every value is `(encoded << shift) + reference`.
+        let encoded_sum = sum(&encoded)?;
+        if encoded_sum.is_null() {
+            return Ok(encoded_sum);
+        }
+        let multiplier: Scalar = match sum_ptype(ptype) {
+            PType::I64 => i64::try_from(1u64 << shift)
+                .map_err(|_| vortex_err!("Shift {} out of range for {}", shift, ptype))?
+                .into(),
+            _ => (1u64 << shift).into(),
+        };
+        let shifted_sum = scalar_arithmetic(
+            &encoded_sum,
+            &multiplier,
+            BinaryOperator::Mul,
+            OverflowMode::Checked,
+        )?;
+        merge_sums(
+            ptype,
+            [
+                shifted_sum,
+                sum_repeated(self.reference(), count(self.array())?)?,
+            ],
+        )
+    }
+}
+
 impl TakeFn for FoRArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         FoRArray::try_new(
@@ -69,10 +249,139 @@ impl SliceFn for FoRArray<'_> {
 mod test {
     use vortex::array::primitive::PrimitiveArray;
     use vortex::compress::{Compressor, EncodingCompression};
+    use vortex::compute::aggregate::sum;
+    use vortex::compute::arithmetic::{arithmetic_scalar, BinaryOperator, OverflowMode};
+    use vortex::compute::cast::cast;
+    use vortex::compute::compare::{compare, Operator};
     use vortex::compute::scalar_at::scalar_at;
     use vortex::Context;
+    use vortex_dtype::PType;
+
+    use crate::{FoRArray, FoREncoding};
+
+    #[test]
+    fn for_arithmetic_scalar() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![16i32, 24, 16, 40]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        let result = arithmetic_scalar(
+            &forarr,
+            &(-24).into(),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert!(FoRArray::try_from(&result).is_ok());
+        assert_eq!(
+            result.flatten_primitive().unwrap().typed_data::<i32>(),
+            [-8, 0, -8, 16]
+        );
+
+        let result = arithmetic_scalar(
+            &forarr,
+            &i32::MAX.into(),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn for_arithmetic_then_compare_and_sum() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![16i32, 24, 16, 40]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        assert_eq!(FoRArray::try_from(&forarr).unwrap().shift(), 3);
+
+        // Adding a value with fewer trailing zeros than the shift can't move the reference.
+        let added = arithmetic_scalar(
+            &forarr,
+            &1.into(),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert_eq!(
+            compare(&added, &17.into(), Operator::Eq)
+                .unwrap()
+                .flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [true, false, true, false]
+        );
+        assert_eq!(sum(&added).unwrap(), Some(100i64).into());
 
-    use crate::FoREncoding;
+        // Wrapped values are no longer offsets from the reference.
+        let wrapping = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![0u8, 255]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        let wrapped = arithmetic_scalar(
+            &wrapping,
+            &10u8.into(),
+            BinaryOperator::Add,
+            OverflowMode::Wrapping,
+        )
+        .unwrap();
+        assert_eq!(
+            wrapped.flatten_primitive().unwrap().typed_data::<u8>(),
+            [10, 9]
+        );
+        assert_eq!(sum(&wrapped).unwrap(), Some(19u64).into());
+    }
+
+    #[test]
+    fn for_sum() {
+        let values = vec![1_000_016u32, 1_000_024, 1_000_016, 1_000_040];
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(values.clone()).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        assert_eq!(
+            sum(&forarr).unwrap(),
+            Some(values.iter().map(|&v| v as u64).sum::<u64>()).into()
+        );
+    }
+
+    #[test]
+    fn for_compare() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![16i32, 24, 16, 40]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        let matches = |value: i32, operator: Operator| {
+            compare(&forarr, &value.into(), operator)
+                .unwrap()
+                .flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(matches(16, Operator::Eq), [true, false, true, false]);
+        assert_eq!(matches(24, Operator::NotEq), [true, false, true, true]);
+        assert_eq!(matches(17, Operator::Eq), [false; 4]);
+        assert_eq!(matches(20, Operator::Gt), [false, true, false, true]);
+    }
 
     #[test]
     fn for_scalar_at() {
@@ -87,4 +396,32 @@ mod test {
         assert_eq!(scalar_at(&forarr, 1).unwrap(), 15.into());
         assert_eq!(scalar_at(&forarr, 2).unwrap(), 19.into());
     }
+
+    #[test]
+    fn for_cast() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![1000u16, 1008, 1040]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        let wide = cast(&forarr, PType::I64.into()).unwrap();
+        assert!(FoRArray::try_from(&wide).is_ok());
+        assert_eq!(
+            wide.flatten_primitive().unwrap().typed_data::<i64>(),
+            [1000, 1008, 1040]
+        );
+
+        // Negative values can't be cast to an unsigned type, whether or not the cast is pushed
+        // down.
+        let signed = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![-8i8, 0, 120]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        assert!(cast(&signed, PType::U16.into()).is_err());
+    }
 }
@@ -1,5 +1,6 @@
 use std::io;
 use std::io::Write;
+use std::mem::size_of;
 
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 
@@ -56,7 +57,11 @@ impl<W: Write> FlatBufferWriter for W {
         let fb_data = fbb.finished_data();
         let fb_size = fb_data.len();
 
-        let aligned_size = (fb_size + (alignment - 1)) & !(alignment - 1);
+        // The u32 length prefix counts towards the alignment so that whatever is written after
+        // the message starts on an aligned boundary.
+        let prefix_size = size_of::<u32>();
+        let aligned_size =
+            ((prefix_size + fb_size + (alignment - 1)) & !(alignment - 1)) - prefix_size;
         let padding_bytes = aligned_size - fb_size;
 
         self.write_all(&(aligned_size as u32).to_le_bytes())?;
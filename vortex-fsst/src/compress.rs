@@ -0,0 +1,216 @@
+use vortex::accessor::ArrayAccessor;
+use vortex::array::varbin::{VarBin, VarBinArray};
+use vortex::array::varbinview::{VarBinView, VarBinViewArray};
+use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray, OwnedArray};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{FSSTArray, FSSTEncoding, SymbolTable};
+
+/// The maximum number of values that a symbol table is trained on.
+const MAX_TRAINING_VALUES: usize = 1024;
+
+impl EncodingCompression for FSSTEncoding {
+    fn can_compress(
+        &self,
+        array: &Array,
+        _config: &CompressConfig,
+    ) -> Option<&dyn EncodingCompression> {
+        if !matches!(array.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+            return None;
+        }
+        if array.encoding().id() != VarBin::ID && array.encoding().id() != VarBinView::ID {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress(
+        &self,
+        array: &Array,
+        like: Option<&Array>,
+        ctx: Compressor,
+    ) -> VortexResult<OwnedArray> {
+        let fsst_like = like.map(|like_arr| FSSTArray::try_from(like_arr).unwrap());
+        let fsst = match array.encoding().id() {
+            VarBin::ID => fsst_encode(&VarBinArray::try_from(array)?, array, fsst_like.as_ref())?,
+            VarBinView::ID => fsst_encode(
+                &VarBinViewArray::try_from(array)?,
+                array,
+                fsst_like.as_ref(),
+            )?,
+            _ => vortex_bail!("FSST can only encode varbin and varbinview arrays"),
+        };
+
+        let codes = VarBinArray::try_from(fsst.codes())?;
+        let codes_like = fsst_like
+            .as_ref()
+            .and_then(|fsst| VarBinArray::try_from(fsst.codes()).ok());
+        let compressed_codes = VarBinArray::try_new(
+            ctx.auxiliary("offsets").compress(
+                &codes.offsets(),
+                codes_like.as_ref().map(|c| c.offsets()).as_ref(),
+            )?,
+            codes.bytes(),
+            codes.dtype().clone(),
+            ctx.compress_validity(codes.validity())?,
+        )?;
+
+        FSSTArray::try_new(
+            fsst.symbols(),
+            fsst.symbol_lengths(),
+            compressed_codes.into_array(),
+            array.dtype().clone(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+/// Compress the array with the symbol table of the like array, which was trained on a sample of
+/// it, or otherwise with a symbol table trained on the array itself.
+fn fsst_encode<'a>(
+    accessor: &impl ArrayAccessor<[u8]>,
+    array: &Array,
+    like: Option<&FSSTArray>,
+) -> VortexResult<FSSTArray<'a>> {
+    let table = match like {
+        Some(like) => like.symbol_table()?,
+        None => {
+            let step = (array.len() / MAX_TRAINING_VALUES).max(1);
+            accessor.with_iterator(|iter| SymbolTable::train(iter.step_by(step).flatten()))?
+        }
+    };
+    FSSTArray::encode(accessor, array.dtype(), &table)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::array::varbinview::VarBinViewArray;
+    use vortex::compress::Compressor;
+    use vortex::compute::compare::{compare, Operator};
+    use vortex::compute::scalar_at::{scalar_at, scalar_values};
+    use vortex::compute::slice::slice;
+    use vortex::compute::string::{contains, starts_with};
+    use vortex::compute::take::take;
+    use vortex::encoding::EncodingRef;
+    use vortex::{ArrayDType, Context, IntoArray};
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::{FSSTArray, FSSTEncoding};
+
+    fn urls() -> Vec<Option<String>> {
+        (0..3000)
+            .map(|i| {
+                (i % 11 != 0).then(|| {
+                    format!(
+                        "https://www.example.com/{}/items/{}?ref=homepage",
+                        ["shop", "blog", "news"][i % 3],
+                        i * 7919 % 10007
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compress_urls() {
+        let values = urls();
+        let array =
+            VarBinArray::from_iter(values.clone(), DType::Utf8(Nullability::Nullable)).into_array();
+        let ctx = Context::default().with_encoding(&FSSTEncoding as EncodingRef);
+        let compressed = Compressor::new(&ctx).compress(&array, None).unwrap();
+        let fsst = FSSTArray::try_from(&compressed).unwrap();
+        assert!(fsst.codes().nbytes() * 2 < array.nbytes());
+        assert_eq!(compressed.dtype(), array.dtype());
+
+        for i in [0, 1, 11, 1234, 2999] {
+            assert_eq!(
+                scalar_at(&compressed, i).unwrap(),
+                scalar_at(&array, i).unwrap()
+            );
+        }
+        let flattened = compressed.clone().flatten_varbin().unwrap();
+        assert_eq!(
+            flattened
+                .with_iterator(|iter| iter
+                    .map(|v| v.map(|v| String::from_utf8(v.to_vec()).unwrap()))
+                    .collect::<Vec<_>>())
+                .unwrap(),
+            values
+        );
+
+        let sliced = slice(&compressed, 10, 13).unwrap();
+        assert!(FSSTArray::try_from(&sliced).is_ok());
+        assert!(scalar_at(&sliced, 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&sliced, 2).unwrap(),
+            scalar_at(&array, 12).unwrap()
+        );
+
+        let taken = take(&compressed, &vec![5u32, 0].into_array()).unwrap();
+        assert!(FSSTArray::try_from(&taken).is_ok());
+        assert_eq!(scalar_at(&taken, 0).unwrap(), scalar_at(&array, 5).unwrap());
+    }
+
+    #[test]
+    fn compressed_predicates() {
+        let values = urls()[..30].to_vec();
+        let array = VarBinViewArray::from_iter(values.clone(), DType::Utf8(Nullability::Nullable))
+            .into_array();
+        let ctx = Context::default().with_encoding(&FSSTEncoding as EncodingRef);
+        let compressed = Compressor::new(&ctx).compress(&array, None).unwrap();
+        assert!(FSSTArray::try_from(&compressed).is_ok());
+        let reference =
+            VarBinArray::from_iter(values.clone(), DType::Utf8(Nullability::Nullable)).into_array();
+
+        let target = values[4].clone().unwrap();
+        assert_eq!(
+            scalar_values::<bool>(
+                &compare(&compressed, &target.as_str().into(), Operator::Eq).unwrap()
+            )
+            .unwrap(),
+            scalar_values::<bool>(
+                &compare(&reference, &target.as_str().into(), Operator::Eq).unwrap()
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            scalar_values::<bool>(
+                &compare(&compressed, &"https://".into(), Operator::NotEq).unwrap()
+            )
+            .unwrap(),
+            scalar_values::<bool>(
+                &compare(&reference, &"https://".into(), Operator::NotEq).unwrap()
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            scalar_values::<bool>(
+                &compare(&compressed, &target.as_str().into(), Operator::Lt).unwrap()
+            )
+            .unwrap(),
+            scalar_values::<bool>(
+                &compare(&reference, &target.as_str().into(), Operator::Lt).unwrap()
+            )
+            .unwrap()
+        );
+        for prefix in [
+            "https://www.example.com/shop",
+            "https://www.example.com/blog/items/1",
+            "",
+        ] {
+            assert_eq!(
+                scalar_values::<bool>(&starts_with(&compressed, prefix).unwrap()).unwrap(),
+                scalar_values::<bool>(&starts_with(&reference, prefix).unwrap()).unwrap()
+            );
+        }
+        assert_eq!(
+            scalar_values::<bool>(&contains(&compressed, "items/7").unwrap()).unwrap(),
+            scalar_values::<bool>(&contains(&reference, "items/7").unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use arrow_buffer::BooleanBuffer;
+use vortex::accessor::ArrayAccessor;
+use vortex::array::bool::BoolArray;
+use vortex::array::varbin::{varbin_scalar, varbin_scalar_bytes};
+use vortex::compute::compare::{compare, CompareFn, Operator};
+use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
+use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::string::{StringMatchFn, StringPredicate};
+use vortex::compute::take::{take, TakeFn};
+use vortex::compute::ArrayCompute;
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayData, OwnedArray};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::FSSTArray;
+
+impl ArrayCompute for FSSTArray<'_> {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for FSSTArray<'_> {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype()));
+        }
+        let codes = Vec::<u8>::try_from(scalar_at(&self.codes(), index)?)?;
+        Ok(varbin_scalar(
+            self.symbol_table()?.decompress(&codes)?,
+            self.dtype(),
+        ))
+    }
+}
+
+impl SliceFn for FSSTArray<'_> {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<OwnedArray> {
+        FSSTArray::try_new(
+            self.symbols(),
+            self.symbol_lengths(),
+            slice(&self.codes(), start, stop)?,
+            self.dtype().clone(),
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl TakeFn for FSSTArray<'_> {
+    fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
+        FSSTArray::try_new(
+            self.symbols(),
+            self.symbol_lengths(),
+            take(&self.codes(), indices)?,
+            self.dtype().clone(),
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+impl CompareFn for FSSTArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        match operator {
+            // A value always compresses to the same codes, so equal values have equal codes.
+            Operator::Eq | Operator::NotEq => {
+                let codes = self.symbol_table()?.compress(&varbin_scalar_bytes(other)?);
+                compare(&self.codes(), &codes.into(), operator)
+            }
+            // Codes don't preserve the order of the values.
+            _ => compare(
+                &self.array().clone().flatten()?.into_array(),
+                other,
+                operator,
+            ),
+        }
+    }
+}
+
+impl StringMatchFn for FSSTArray<'_> {
+    fn string_match(&self, predicate: &StringPredicate) -> VortexResult<OwnedArray> {
+        let table = self.symbol_table()?;
+        let codes = self.codes().flatten_varbin()?;
+        let mut value = Vec::new();
+        let buffer = codes.with_iterator(|iter| {
+            iter.map(|v| {
+                v.map_or(Ok(false), |v| match predicate {
+                    // Only the codes covering the prefix need to be decompressed.
+                    StringPredicate::StartsWith(prefix) => table.starts_with(v, prefix),
+                    _ => {
+                        value.clear();
+                        table.decompress_into(v, &mut value)?;
+                        Ok(predicate.matches(&value))
+                    }
+                })
+            })
+            .collect::<VortexResult<BooleanBuffer>>()
+        })??;
+        BoolArray::try_new(buffer, codes.validity()).map(|a| a.into_array_data().into_array())
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use vortex::accessor::ArrayAccessor;
+use vortex::array::varbin::VarBinArray;
+use vortex::stats::ArrayStatisticsCompute;
+use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+use vortex_dtype::{Nullability, PType};
+use vortex_error::vortex_bail;
+
+use crate::table::SymbolTable;
+
+impl_encoding!("vortex.fsst", FSST);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FSSTMetadata {
+    codes_dtype: DType,
+}
+
+impl FSSTArray<'_> {
+    const SYMBOLS_DTYPE: DType = DType::Primitive(PType::U64, Nullability::NonNullable);
+    const SYMBOL_LENGTHS_DTYPE: DType = DType::Primitive(PType::U8, Nullability::NonNullable);
+
+    /// Create an array from its symbol table, as u64 symbols and u8 symbol lengths, and the
+    /// compressed codes of every value, as a binary array with the nulls of the array.
+    pub fn try_new(
+        symbols: Array,
+        symbol_lengths: Array,
+        codes: Array,
+        dtype: DType,
+    ) -> VortexResult<Self> {
+        if !matches!(dtype, DType::Utf8(_) | DType::Binary(_)) {
+            vortex_bail!(MismatchedTypes: "utf8 or binary", dtype);
+        }
+        if symbols.dtype() != &Self::SYMBOLS_DTYPE {
+            vortex_bail!(MismatchedTypes: Self::SYMBOLS_DTYPE, symbols.dtype());
+        }
+        if symbol_lengths.dtype() != &Self::SYMBOL_LENGTHS_DTYPE {
+            vortex_bail!(MismatchedTypes: Self::SYMBOL_LENGTHS_DTYPE, symbol_lengths.dtype());
+        }
+        if codes.dtype() != &DType::Binary(dtype.nullability()) {
+            vortex_bail!(MismatchedTypes: DType::Binary(dtype.nullability()), codes.dtype());
+        }
+        if symbols.len() != symbol_lengths.len() {
+            vortex_bail!(
+                "Got {} symbols but {} symbol lengths",
+                symbols.len(),
+                symbol_lengths.len()
+            );
+        }
+
+        Self::try_from_parts(
+            dtype,
+            FSSTMetadata {
+                codes_dtype: codes.dtype().clone(),
+            },
+            [
+                symbols.into_array_data(),
+                symbol_lengths.into_array_data(),
+                codes.into_array_data(),
+            ]
+            .into(),
+            StatsSet::new(),
+        )
+    }
+
+    /// Compress every value of the utf8 or binary array with the symbol table.
+    pub fn encode(
+        array: &impl ArrayAccessor<[u8]>,
+        dtype: &DType,
+        table: &SymbolTable,
+    ) -> VortexResult<Self> {
+        let codes = array.with_iterator(|iter| {
+            VarBinArray::from_iter(
+                iter.map(|v| v.map(|v| table.compress(v))),
+                DType::Binary(dtype.nullability()),
+            )
+        })?;
+        let (symbols, lengths) = table.to_parts();
+        Self::try_new(
+            symbols.into_array(),
+            lengths.into_array(),
+            codes.into_array(),
+            dtype.clone(),
+        )
+    }
+
+    #[inline]
+    pub fn symbols(&self) -> Array {
+        self.array()
+            .child(0, &Self::SYMBOLS_DTYPE)
+            .expect("missing symbols")
+    }
+
+    #[inline]
+    pub fn symbol_lengths(&self) -> Array {
+        self.array()
+            .child(1, &Self::SYMBOL_LENGTHS_DTYPE)
+            .expect("missing symbol lengths")
+    }
+
+    #[inline]
+    pub fn codes(&self) -> Array {
+        self.array()
+            .child(2, &self.metadata().codes_dtype)
+            .expect("missing codes")
+    }
+
+    pub fn symbol_table(&self) -> VortexResult<SymbolTable> {
+        let symbols = self.symbols().flatten_primitive()?;
+        let lengths = self.symbol_lengths().flatten_primitive()?;
+        SymbolTable::try_new(symbols.typed_data::<u64>(), lengths.typed_data::<u8>())
+    }
+}
+
+impl ArrayFlatten for FSSTArray<'_> {
+    fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
+    where
+        Self: 'a,
+    {
+        let table = self.symbol_table()?;
+        let values = self.codes().flatten_varbin()?.with_iterator(|iter| {
+            iter.map(|codes| codes.map(|codes| table.decompress(codes)).transpose())
+                .collect::<VortexResult<Vec<_>>>()
+        })??;
+        Ok(Flattened::VarBin(VarBinArray::from_iter(
+            values,
+            self.dtype().clone(),
+        )))
+    }
+}
+
+impl ArrayValidity for FSSTArray<'_> {
+    fn is_valid(&self, index: usize) -> bool {
+        self.codes().with_dyn(|a| a.is_valid(index))
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.codes().with_dyn(|a| a.logical_validity())
+    }
+}
+
+impl AcceptArrayVisitor for FSSTArray<'_> {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("symbols", &self.symbols())?;
+        visitor.visit_child("symbol_lengths", &self.symbol_lengths())?;
+        visitor.visit_child("codes", &self.codes())
+    }
+}
+
+impl ArrayStatisticsCompute for FSSTArray<'_> {}
+
+impl ArrayTrait for FSSTArray<'_> {
+    fn len(&self) -> usize {
+        self.codes().len()
+    }
+}
@@ -0,0 +1,7 @@
+pub use fsst::*;
+pub use table::*;
+
+mod compress;
+mod compute;
+mod fsst;
+mod table;
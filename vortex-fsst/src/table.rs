@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use itertools::Itertools;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+/// The code that is followed by a single uncompressed byte.
+pub const ESCAPE_CODE: u8 = u8::MAX;
+/// The maximum number of symbols, such that every code but the escape code refers to one.
+pub const MAX_SYMBOLS: usize = ESCAPE_CODE as usize;
+/// The maximum length of a symbol in bytes.
+pub const MAX_SYMBOL_LENGTH: usize = 8;
+
+/// The number of rounds of training, each of which refines the symbols of the previous one.
+const GENERATIONS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Symbol {
+    len: u8,
+    bytes: [u8; MAX_SYMBOL_LENGTH],
+}
+
+impl Symbol {
+    fn new(value: &[u8]) -> Self {
+        let len = value.len().min(MAX_SYMBOL_LENGTH);
+        let mut bytes = [0u8; MAX_SYMBOL_LENGTH];
+        bytes[..len].copy_from_slice(&value[..len]);
+        Self {
+            len: len as u8,
+            bytes,
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// The symbol followed by the other one, truncated to the maximum symbol length.
+    fn concat(&self, other: &Symbol) -> Self {
+        let mut bytes = self.bytes;
+        let len = (self.len + other.len).min(MAX_SYMBOL_LENGTH as u8);
+        bytes[self.len as usize..len as usize]
+            .copy_from_slice(&other.bytes[..(len - self.len) as usize]);
+        Self { len, bytes }
+    }
+}
+
+/// A table of up to 255 symbols of up to 8 bytes each, used to compress strings by replacing
+/// frequent substrings with one byte codes.
+///
+/// Bytes that aren't covered by a symbol are written as the escape code followed by the byte.
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    symbols: Vec<Symbol>,
+    /// The codes of the symbols starting with every byte, longest symbols first.
+    ///
+    /// Only compression needs the index, so it isn't built until a value is compressed.
+    index: OnceLock<Vec<Vec<u8>>>,
+}
+
+impl PartialEq for SymbolTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbols == other.symbols
+    }
+}
+
+impl Eq for SymbolTable {}
+
+impl SymbolTable {
+    /// Create a table from the symbols, each packed into the low bytes of a little-endian u64.
+    pub fn try_new(symbols: &[u64], lengths: &[u8]) -> VortexResult<Self> {
+        if symbols.len() != lengths.len() {
+            vortex_bail!(
+                "Got {} symbols but {} symbol lengths",
+                symbols.len(),
+                lengths.len()
+            );
+        }
+        if symbols.len() > MAX_SYMBOLS {
+            vortex_bail!("At most {} symbols are supported", MAX_SYMBOLS);
+        }
+        if let Some(len) = lengths
+            .iter()
+            .find(|&&l| l == 0 || l as usize > MAX_SYMBOL_LENGTH)
+        {
+            vortex_bail!("Invalid symbol length {}", len);
+        }
+
+        Ok(Self::from_symbols(
+            symbols
+                .iter()
+                .zip(lengths)
+                .map(|(s, &l)| Symbol::new(&s.to_le_bytes()[..l as usize]))
+                .collect(),
+        ))
+    }
+
+    fn from_symbols(symbols: Vec<Symbol>) -> Self {
+        Self {
+            symbols,
+            index: OnceLock::new(),
+        }
+    }
+
+    fn index(&self) -> &[Vec<u8>] {
+        self.index.get_or_init(|| {
+            let mut index = vec![Vec::new(); 256];
+            for (code, symbol) in self
+                .symbols
+                .iter()
+                .enumerate()
+                .sorted_by_key(|(_, s)| std::cmp::Reverse(s.len))
+            {
+                index[symbol.bytes[0] as usize].push(code as u8);
+            }
+            index
+        })
+    }
+
+    /// Train a table on a sample of the values to be compressed.
+    ///
+    /// Every generation compresses the sample with the current table, and keeps the symbols and
+    /// pairs of adjacent symbols that would have saved the most bytes.
+    pub fn train<'a>(sample: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        let sample = sample.into_iter().collect_vec();
+        let mut table = Self::from_symbols(Vec::new());
+
+        for _ in 0..GENERATIONS {
+            let mut gains: HashMap<Symbol, usize> = HashMap::new();
+            for value in sample.iter() {
+                let mut previous: Option<Symbol> = None;
+                let mut pos = 0;
+                while pos < value.len() {
+                    let symbol = table
+                        .find_symbol(&value[pos..])
+                        .map(|code| table.symbols[code as usize])
+                        .unwrap_or_else(|| Symbol::new(&value[pos..pos + 1]));
+                    *gains.entry(symbol).or_default() += symbol.len as usize;
+                    if let Some(previous) = previous {
+                        let pair = previous.concat(&symbol);
+                        *gains.entry(pair).or_default() += pair.len as usize;
+                    }
+                    previous = Some(symbol);
+                    pos += symbol.len as usize;
+                }
+            }
+
+            table = Self::from_symbols(
+                gains
+                    .into_iter()
+                    .sorted_by(|(a, a_gain), (b, b_gain)| b_gain.cmp(a_gain).then(a.cmp(b)))
+                    .map(|(symbol, _)| symbol)
+                    .take(MAX_SYMBOLS)
+                    .collect(),
+            );
+        }
+        table
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// The symbols packed into the low bytes of little-endian u64s, and their lengths.
+    pub fn to_parts(&self) -> (Vec<u64>, Vec<u8>) {
+        self.symbols
+            .iter()
+            .map(|s| (u64::from_le_bytes(s.bytes), s.len))
+            .unzip()
+    }
+
+    /// The bytes of the symbol with the code, which may have been read from a file and so isn't
+    /// trusted to be in the table.
+    pub fn symbol(&self, code: u8) -> VortexResult<&[u8]> {
+        self.symbols
+            .get(code as usize)
+            .map(|s| s.as_slice())
+            .ok_or_else(|| vortex_err!(OutOfBounds: code as usize, 0, self.symbols.len()))
+    }
+
+    /// The code of the longest symbol that the value starts with.
+    fn find_symbol(&self, value: &[u8]) -> Option<u8> {
+        self.index()[value[0] as usize]
+            .iter()
+            .copied()
+            .find(|&code| value.starts_with(self.symbols[code as usize].as_slice()))
+    }
+
+    /// Append the codes of the value to the output.
+    pub fn compress_into(&self, value: &[u8], output: &mut Vec<u8>) {
+        let mut pos = 0;
+        while pos < value.len() {
+            match self.find_symbol(&value[pos..]) {
+                Some(code) => {
+                    output.push(code);
+                    pos += self.symbols[code as usize].len as usize;
+                }
+                None => {
+                    output.push(ESCAPE_CODE);
+                    output.push(value[pos]);
+                    pos += 1;
+                }
+            }
+        }
+    }
+
+    pub fn compress(&self, value: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(value.len());
+        self.compress_into(value, &mut output);
+        output
+    }
+
+    /// Append the value of the codes to the output.
+    pub fn decompress_into(&self, codes: &[u8], output: &mut Vec<u8>) -> VortexResult<()> {
+        let mut codes = codes.iter();
+        while let Some(&code) = codes.next() {
+            if code == ESCAPE_CODE {
+                output.extend(codes.next());
+            } else {
+                output.extend_from_slice(self.symbol(code)?);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn decompress(&self, codes: &[u8]) -> VortexResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(codes.len() * 2);
+        self.decompress_into(codes, &mut output)?;
+        Ok(output)
+    }
+
+    /// Whether the value of the codes starts with the prefix, decompressing no more of them than
+    /// is needed to tell.
+    pub fn starts_with(&self, codes: &[u8], prefix: &[u8]) -> VortexResult<bool> {
+        let mut matched = 0;
+        let mut codes = codes.iter();
+        while matched < prefix.len() {
+            let bytes = match codes.next() {
+                None => return Ok(false),
+                Some(&ESCAPE_CODE) => match codes.next() {
+                    Some(byte) => std::slice::from_ref(byte),
+                    None => return Ok(false),
+                },
+                Some(&code) => self.symbol(code)?,
+            };
+            let n = bytes.len().min(prefix.len() - matched);
+            if bytes[..n] != prefix[matched..matched + n] {
+                return Ok(false);
+            }
+            matched += n;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::table::{SymbolTable, ESCAPE_CODE, MAX_SYMBOLS};
+
+    #[test]
+    fn train_and_round_trip() {
+        let urls = (0..200)
+            .map(|i| {
+                format!(
+                    "https://www.example.com/products/{}/reviews?page={}",
+                    i,
+                    i % 7
+                )
+            })
+            .collect::<Vec<_>>();
+        let table = SymbolTable::train(urls.iter().map(|u| u.as_bytes()));
+        assert!(!table.is_empty() && table.len() <= MAX_SYMBOLS);
+
+        for url in urls.iter() {
+            let codes = table.compress(url.as_bytes());
+            assert!(
+                codes.len() * 2 < url.len(),
+                "{} codes for {}",
+                codes.len(),
+                url
+            );
+            assert_eq!(table.decompress(&codes).unwrap(), url.as_bytes());
+            assert!(table.starts_with(&codes, b"https://www.exa").unwrap());
+            assert!(!table.starts_with(&codes, b"https://www.exb").unwrap());
+            assert!(table.starts_with(&codes, url.as_bytes()).unwrap());
+        }
+
+        // Bytes that weren't in the sample are escaped.
+        let codes = table.compress("ü".as_bytes());
+        assert_eq!(codes[0], ESCAPE_CODE);
+        assert_eq!(table.decompress(&codes).unwrap(), "ü".as_bytes());
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let table = SymbolTable::train(["abcabcabc".as_bytes(), "abcdefgh".as_bytes()]);
+        let (symbols, lengths) = table.to_parts();
+        assert_eq!(SymbolTable::try_new(&symbols, &lengths).unwrap(), table);
+        assert!(SymbolTable::try_new(&symbols, &lengths[1..]).is_err());
+        assert!(SymbolTable::try_new(&[0], &[9]).is_err());
+    }
+
+    #[test]
+    fn codes_out_of_bounds() {
+        let table = SymbolTable::try_new(&[u64::from_le_bytes(*b"abcdefgh")], &[8]).unwrap();
+        assert_eq!(table.decompress(&[0, 0]).unwrap(), b"abcdefghabcdefgh");
+        assert!(table.decompress(&[0, 1]).is_err());
+        assert!(table.starts_with(&[1], b"a").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+
+use crate::{
+    encode, BlockDecoder, GorillaArray, GorillaEncoding, GorillaFloat, OwnedGorillaArray,
+    BLOCK_SIZE,
+};
+
+#[macro_export]
+macro_rules! match_each_gorilla_float_ptype {
+    ($self:expr, | $_:tt $enc:ident | $($body:tt)*) => ({
+        macro_rules! __with__ {( $_ $enc:ident ) => ( $($body)* )}
+        use vortex_dtype::PType;
+        use vortex_error::vortex_err;
+        let ptype = $self;
+        match ptype {
+            PType::F32 => Ok(__with__! { f32 }),
+            PType::F64 => Ok(__with__! { f64 }),
+            _ => Err(vortex_err!("Gorilla can only encode f32 and f64")),
+        }
+    })
+}
+
+impl EncodingCompression for GorillaEncoding {
+    fn can_compress(
+        &self,
+        array: &Array,
+        _config: &CompressConfig,
+    ) -> Option<&dyn EncodingCompression> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // Only supports f32 and f64
+        if !matches!(parray.ptype(), PType::F32 | PType::F64) {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress(
+        &self,
+        array: &Array,
+        like: Option<&Array>,
+        ctx: Compressor,
+    ) -> VortexResult<OwnedArray> {
+        let like_gorilla = like.map(|l| GorillaArray::try_from(l).unwrap());
+        let parray = array.as_primitive();
+
+        let validity = ctx.compress_validity(parray.validity())?;
+        let (bytes, block_offsets) = match_each_gorilla_float_ptype!(parray.ptype(), |$T| {
+            encode_values::<$T>(&parray)?
+        })?;
+        let block_offsets = ctx.auxiliary("block_offsets").compress(
+            &PrimitiveArray::from(block_offsets).into_array(),
+            like_gorilla.as_ref().map(|g| g.block_offsets()).as_ref(),
+        )?;
+
+        GorillaArray::try_new(
+            PrimitiveArray::from(bytes).into_array(),
+            block_offsets,
+            validity,
+            parray.dtype().clone(),
+            parray.len(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+pub(crate) fn gorilla_encode(parray: &PrimitiveArray) -> VortexResult<OwnedGorillaArray> {
+    let (bytes, block_offsets) = match_each_gorilla_float_ptype!(parray.ptype(), |$T| {
+        encode_values::<$T>(parray)?
+    })?;
+    GorillaArray::try_new(
+        PrimitiveArray::from(bytes).into_array(),
+        PrimitiveArray::from(block_offsets).into_array(),
+        parray.validity().to_static(),
+        parray.dtype().clone(),
+        parray.len(),
+    )
+}
+
+/// Encode the values with every null replaced by the previous value, which costs a single bit.
+fn encode_values<T: GorillaFloat>(parray: &PrimitiveArray) -> VortexResult<(Vec<u8>, Vec<u64>)> {
+    let values = parray.typed_data::<T>();
+    match parray.logical_validity().to_null_buffer()? {
+        None => Ok(encode(values)),
+        Some(nulls) => {
+            let mut previous = T::zero();
+            let filled = values
+                .iter()
+                .zip(nulls.iter())
+                .map(|(&v, valid)| {
+                    if valid {
+                        previous = v;
+                    }
+                    previous
+                })
+                .collect::<Vec<_>>();
+            Ok(encode(&filled))
+        }
+    }
+}
+
+pub fn decompress(array: &GorillaArray) -> VortexResult<PrimitiveArray<'static>> {
+    let bytes = array.bytes().flatten_primitive()?;
+    let block_offsets = array.block_offsets().flatten_primitive()?;
+    match_each_gorilla_float_ptype!(array.dtype().try_into()?, |$T| {
+        PrimitiveArray::from_vec(
+            decode_values::<$T>(
+                bytes.typed_data::<u8>(),
+                block_offsets.typed_data::<u64>(),
+                array.offset(),
+                array.len(),
+            ),
+            array.validity().to_static(),
+        )
+    })
+}
+
+/// Decode `len` values starting `offset` values into the first of the blocks.
+fn decode_values<T: GorillaFloat>(
+    bytes: &[u8],
+    block_offsets: &[u64],
+    offset: usize,
+    len: usize,
+) -> Vec<T> {
+    let end = offset + len;
+    let mut values = Vec::with_capacity(len);
+    for (block, &start) in block_offsets.iter().enumerate() {
+        let count = BLOCK_SIZE.min(end - block * BLOCK_SIZE);
+        let skip = if block == 0 { offset } else { 0 };
+        values.extend(
+            BlockDecoder::<T>::new(&bytes[start as usize..])
+                .take(count)
+                .skip(skip),
+        );
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::compress::Compressor;
+    use vortex::encoding::EncodingRef;
+    use vortex::{Context, IntoArray};
+
+    use super::*;
+
+    fn temperatures() -> Vec<Option<f64>> {
+        (0..5000)
+            .map(|i| (i % 37 != 5).then(|| 18.0 + ((i / 16) as f64 * 0.01).sin()))
+            .collect()
+    }
+
+    #[test]
+    fn compress_time_series() {
+        let values = temperatures();
+        let array = PrimitiveArray::from_nullable_vec(values.clone()).into_array();
+        let ctx = Context::default().with_encoding(&GorillaEncoding as EncodingRef);
+        let compressed = Compressor::new(&ctx).compress(&array, None).unwrap();
+        let gorilla = GorillaArray::try_from(&compressed).unwrap();
+        assert!(gorilla.bytes().nbytes() * 4 < array.nbytes());
+
+        let decoded = decompress(&gorilla).unwrap();
+        assert_eq!(decoded.dtype(), array.dtype());
+        for (i, v) in values.iter().enumerate() {
+            assert_eq!(decoded.validity().is_valid(i), v.is_some());
+            if let Some(v) = v {
+                assert_eq!(decoded.typed_data::<f64>()[i], *v);
+            }
+        }
+    }
+
+    #[test]
+    fn encode_f32() {
+        let values = (0..3000)
+            .map(|i| (i as f32 * 0.001).exp())
+            .collect::<Vec<_>>();
+        let encoded =
+            GorillaArray::encode(PrimitiveArray::from(values.clone()).into_array()).unwrap();
+        assert_eq!(encoded.block_offsets().len(), 3);
+        assert_eq!(
+            decompress(&encoded).unwrap().typed_data::<f32>(),
+            values.as_slice()
+        );
+        assert!(GorillaArray::encode(PrimitiveArray::from(vec![1u32, 2]).into_array()).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
+use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::ArrayCompute;
+use vortex::{ArrayDType, IntoArray, IntoArrayData, OwnedArray};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::{match_each_gorilla_float_ptype, BlockDecoder, GorillaArray, BLOCK_SIZE};
+
+impl ArrayCompute for GorillaArray<'_> {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for GorillaArray<'_> {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.validity().is_valid(index) {
+            return Ok(Scalar::null(self.dtype()));
+        }
+
+        // Seek to the start of the block and decode no further than the value.
+        let position = self.offset() + index;
+        let start: u64 = scalar_at(&self.block_offsets(), position / BLOCK_SIZE)?.try_into()?;
+        let bytes = self.bytes().flatten_primitive()?;
+        let bytes = bytes
+            .typed_data::<u8>()
+            .get(start as usize..)
+            .ok_or_else(|| vortex_err!("Block offset {} out of bounds", start))?;
+
+        match_each_gorilla_float_ptype!(self.dtype().try_into()?, |$T| {
+            let value = BlockDecoder::<$T>::new(bytes)
+                .nth(position % BLOCK_SIZE)
+                .ok_or_else(|| vortex_err!("Index {} out of bounds", index))?;
+            PrimitiveScalar::try_new(Some(value), self.dtype().nullability())?.into()
+        })
+    }
+}
+
+impl SliceFn for GorillaArray<'_> {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<OwnedArray> {
+        let first_block = (self.offset() + start) / BLOCK_SIZE;
+        let last_block = (self.offset() + stop + BLOCK_SIZE - 1) / BLOCK_SIZE;
+        Self::try_new_from_offset(
+            self.bytes(),
+            slice(&self.block_offsets(), first_block, last_block)?,
+            self.validity().slice(start, stop)?,
+            self.dtype().clone(),
+            stop - start,
+            (self.offset() + start) % BLOCK_SIZE,
+        )
+        .map(|a| a.into_array_data().into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::scalar_at::scalar_values;
+    use vortex::compute::slice::slice;
+    use vortex::compute::take::take;
+    use vortex::{ArrayTrait, IntoArray};
+
+    use crate::GorillaArray;
+
+    #[test]
+    fn seek_and_slice() {
+        let values = (0..3000)
+            .map(|i| (i % 10 != 3).then(|| 1000.0 + i as f64 / 7.0))
+            .collect::<Vec<_>>();
+        let array =
+            GorillaArray::encode(PrimitiveArray::from_nullable_vec(values.clone()).into_array())
+                .unwrap()
+                .into_array();
+        assert_eq!(scalar_values::<f64>(&array).unwrap(), values);
+
+        let sliced = slice(&array, 1000, 2100).unwrap();
+        let gorilla = GorillaArray::try_from(&sliced).unwrap();
+        assert_eq!(gorilla.offset(), 1000);
+        assert_eq!(gorilla.block_offsets().len(), 3);
+        assert_eq!(scalar_values::<f64>(&sliced).unwrap(), values[1000..2100]);
+
+        let resliced = slice(&sliced, 30, 1050).unwrap();
+        let gorilla = GorillaArray::try_from(&resliced).unwrap();
+        assert_eq!(gorilla.offset(), 6);
+        assert_eq!(gorilla.block_offsets().len(), 2);
+        assert_eq!(scalar_values::<f64>(&resliced).unwrap(), values[1030..2050]);
+        assert_eq!(
+            scalar_values::<f64>(&resliced.clone().flatten_primitive().unwrap().into_array())
+                .unwrap(),
+            values[1030..2050]
+        );
+
+        let empty = slice(&sliced, 24, 24).unwrap();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.flatten_primitive().unwrap().len(), 0);
+
+        let taken = take(
+            &resliced,
+            &PrimitiveArray::from(vec![0u32, 3, 1019]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            scalar_values::<f64>(&taken).unwrap(),
+            [values[1030], values[1033], values[2049]]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::stats::ArrayStatisticsCompute;
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+use vortex_dtype::{Nullability, PType};
+use vortex_error::vortex_bail;
+
+use crate::compress::{decompress, gorilla_encode};
+use crate::BLOCK_SIZE;
+
+impl_encoding!("vortex.gorilla", Gorilla);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorillaMetadata {
+    validity: ValidityMetadata,
+    offset: usize,
+    length: usize,
+}
+
+impl GorillaArray<'_> {
+    const BLOCK_OFFSETS_DTYPE: DType = DType::Primitive(PType::U64, Nullability::NonNullable);
+
+    /// Create an array from the encoded blocks and the byte offset of every block within them.
+    pub fn try_new(
+        bytes: Array,
+        block_offsets: Array,
+        validity: Validity,
+        dtype: DType,
+        length: usize,
+    ) -> VortexResult<Self> {
+        Self::try_new_from_offset(bytes, block_offsets, validity, dtype, length, 0)
+    }
+
+    /// Create an array of the values that start `offset` values into the first block.
+    pub(crate) fn try_new_from_offset(
+        bytes: Array,
+        block_offsets: Array,
+        validity: Validity,
+        dtype: DType,
+        length: usize,
+        offset: usize,
+    ) -> VortexResult<Self> {
+        if !matches!(dtype, DType::Primitive(PType::F32 | PType::F64, _)) {
+            vortex_bail!(MismatchedTypes: "f32 or f64", dtype);
+        }
+        if bytes.dtype() != &DType::BYTES {
+            vortex_bail!(MismatchedTypes: DType::BYTES, bytes.dtype());
+        }
+        if block_offsets.dtype() != &Self::BLOCK_OFFSETS_DTYPE {
+            vortex_bail!(MismatchedTypes: Self::BLOCK_OFFSETS_DTYPE, block_offsets.dtype());
+        }
+        if offset >= BLOCK_SIZE {
+            vortex_bail!("Offset {} must be within the first block", offset);
+        }
+        let expected_blocks = (offset + length + BLOCK_SIZE - 1) / BLOCK_SIZE;
+        if block_offsets.len() != expected_blocks {
+            vortex_bail!(
+                "Expected {} block offsets, got {}",
+                expected_blocks,
+                block_offsets.len()
+            );
+        }
+
+        let metadata = GorillaMetadata {
+            validity: validity.to_metadata(length)?,
+            offset,
+            length,
+        };
+
+        let mut children = Vec::with_capacity(3);
+        children.push(bytes.into_array_data());
+        children.push(block_offsets.into_array_data());
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    pub fn encode(array: Array<'_>) -> VortexResult<OwnedGorillaArray> {
+        if let Ok(parray) = PrimitiveArray::try_from(array) {
+            gorilla_encode(&parray)
+        } else {
+            vortex_bail!("Gorilla can only encode primitive arrays");
+        }
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> Array {
+        self.array().child(0, &DType::BYTES).expect("Missing bytes")
+    }
+
+    #[inline]
+    pub fn block_offsets(&self) -> Array {
+        self.array()
+            .child(1, &Self::BLOCK_OFFSETS_DTYPE)
+            .expect("Missing block offsets")
+    }
+
+    /// The number of values to skip in the first block.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.metadata().offset
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(2, &Validity::DTYPE))
+    }
+}
+
+impl ArrayFlatten for GorillaArray<'_> {
+    fn flatten<'a>(self) -> VortexResult<Flattened<'a>>
+    where
+        Self: 'a,
+    {
+        decompress(&self).map(Flattened::Primitive)
+    }
+}
+
+impl ArrayValidity for GorillaArray<'_> {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for GorillaArray<'_> {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("bytes", &self.bytes())?;
+        visitor.visit_child("block_offsets", &self.block_offsets())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for GorillaArray<'_> {}
+
+impl ArrayTrait for GorillaArray<'_> {
+    fn len(&self) -> usize {
+        self.metadata().length
+    }
+}
@@ -0,0 +1,7 @@
+pub use gorilla::*;
+pub use xor::*;
+
+mod compress;
+mod compute;
+mod gorilla;
+mod xor;
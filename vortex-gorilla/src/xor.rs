@@ -0,0 +1,273 @@
+use num_traits::Float;
+use vortex_dtype::NativePType;
+
+/// The number of values in a block, each of which starts with an uncompressed value so that it
+/// can be decoded without reading the blocks before it.
+pub const BLOCK_SIZE: usize = 1024;
+
+/// The width of the leading zeros field of a new window, which caps the leading zeros at 31.
+const LEADING_ZEROS_BITS: u32 = 5;
+/// The width of the meaningful bits field of a new window, where 0 stands for 64.
+const MEANINGFUL_BITS_BITS: u32 = 6;
+
+/// A float whose bit representation can be XORed with that of the previous value.
+pub trait GorillaFloat: NativePType + Float {
+    const BITS: u32;
+
+    fn to_bits_u64(self) -> u64;
+
+    fn from_bits_u64(bits: u64) -> Self;
+}
+
+impl GorillaFloat for f32 {
+    const BITS: u32 = 32;
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn from_bits_u64(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl GorillaFloat for f64 {
+    const BITS: u32 = 64;
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_bits_u64(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+/// Encode the values into byte aligned blocks of [`BLOCK_SIZE`] values, returning the bytes and
+/// the offset of every block within them.
+///
+/// Within a block every value is stored as the XOR with the previous one: a single 0 bit when they
+/// are equal, otherwise the meaningful bits of the XOR, either within the window of leading and
+/// trailing zeros of the previous XOR or with a new window.
+pub fn encode<T: GorillaFloat>(values: &[T]) -> (Vec<u8>, Vec<u64>) {
+    let mut writer = BitWriter::with_capacity(values.len() * 2);
+    let mut offsets = Vec::with_capacity((values.len() + BLOCK_SIZE - 1) / BLOCK_SIZE);
+    for block in values.chunks(BLOCK_SIZE) {
+        offsets.push(writer.bytes.len() as u64);
+        encode_block(block, &mut writer);
+        writer.flush();
+    }
+    (writer.bytes, offsets)
+}
+
+fn encode_block<T: GorillaFloat>(values: &[T], writer: &mut BitWriter) {
+    let mut previous = values[0].to_bits_u64();
+    writer.write(previous, T::BITS);
+
+    let mut window: Option<(u32, u32)> = None;
+    for v in &values[1..] {
+        let bits = v.to_bits_u64();
+        let xor = bits ^ previous;
+        previous = bits;
+        if xor == 0 {
+            writer.write(0, 1);
+            continue;
+        }
+
+        let leading = (xor.leading_zeros() - (64 - T::BITS)).min((1 << LEADING_ZEROS_BITS) - 1);
+        let trailing = xor.trailing_zeros();
+        match window {
+            Some((window_leading, window_trailing))
+                if leading >= window_leading && trailing >= window_trailing =>
+            {
+                writer.write(0b10, 2);
+                writer.write(
+                    xor >> window_trailing,
+                    T::BITS - window_leading - window_trailing,
+                );
+            }
+            _ => {
+                let meaningful = T::BITS - leading - trailing;
+                writer.write(0b11, 2);
+                writer.write(leading as u64, LEADING_ZEROS_BITS);
+                writer.write(meaningful as u64 % 64, MEANINGFUL_BITS_BITS);
+                writer.write(xor >> trailing, meaningful);
+                window = Some((leading, trailing));
+            }
+        }
+    }
+}
+
+/// Decodes the values of a block, starting at its first byte.
+///
+/// The decoder doesn't know the length of the block, so callers take no more values than it holds.
+pub struct BlockDecoder<'a, T> {
+    reader: BitReader<'a>,
+    previous: Option<u64>,
+    window: (u32, u32),
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: GorillaFloat> BlockDecoder<'a, T> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            reader: BitReader::new(bytes),
+            previous: None,
+            window: (0, 0),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: GorillaFloat> Iterator for BlockDecoder<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(previous) = self.previous else {
+            let first = self.reader.read(T::BITS);
+            self.previous = Some(first);
+            return Some(T::from_bits_u64(first));
+        };
+
+        let bits = if self.reader.read(1) == 0 {
+            previous
+        } else {
+            if self.reader.read(1) == 1 {
+                let leading = self.reader.read(LEADING_ZEROS_BITS) as u32;
+                let meaningful = match self.reader.read(MEANINGFUL_BITS_BITS) as u32 {
+                    0 => 64,
+                    m => m,
+                };
+                self.window = (leading, T::BITS.saturating_sub(leading + meaningful));
+            }
+            let (leading, trailing) = self.window;
+            let meaningful = T::BITS.saturating_sub(leading + trailing);
+            previous ^ (self.reader.read(meaningful) << trailing)
+        };
+        self.previous = Some(bits);
+        Some(T::from_bits_u64(bits))
+    }
+}
+
+/// Writes bits most significant first, padding the last byte with zeros.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u128,
+    buffered: u32,
+}
+
+impl BitWriter {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+            buffer: 0,
+            buffered: 0,
+        }
+    }
+
+    /// Write the low `width` bits of the value.
+    fn write(&mut self, value: u64, width: u32) {
+        if width == 0 {
+            return;
+        }
+        self.buffer = (self.buffer << width) | (value & mask(width)) as u128;
+        self.buffered += width;
+        while self.buffered >= 8 {
+            self.buffered -= 8;
+            self.bytes.push((self.buffer >> self.buffered) as u8);
+        }
+        self.buffer &= (1 << self.buffered) - 1;
+    }
+
+    /// Pad the buffered bits to a whole byte.
+    fn flush(&mut self) {
+        if self.buffered > 0 {
+            self.bytes.push((self.buffer << (8 - self.buffered)) as u8);
+            self.buffer = 0;
+            self.buffered = 0;
+        }
+    }
+}
+
+/// Reads bits most significant first, as zeros once the bytes run out.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    buffer: u128,
+    buffered: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            buffer: 0,
+            buffered: 0,
+        }
+    }
+
+    fn read(&mut self, width: u32) -> u64 {
+        if width == 0 {
+            return 0;
+        }
+        while self.buffered < width {
+            let byte = self.bytes.get(self.position).copied().unwrap_or(0);
+            self.position += 1;
+            self.buffer = (self.buffer << 8) | byte as u128;
+            self.buffered += 8;
+        }
+        self.buffered -= width;
+        let value = (self.buffer >> self.buffered) as u64 & mask(width);
+        self.buffer &= (1 << self.buffered) - 1;
+        value
+    }
+}
+
+#[inline]
+fn mask(width: u32) -> u64 {
+    u64::MAX >> (64 - width)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::xor::{encode, BlockDecoder, BLOCK_SIZE};
+
+    #[test]
+    fn round_trip_blocks() {
+        let values = (0..2500)
+            .map(|i| match i % 100 {
+                0 => f64::NAN,
+                1 => -0.0,
+                2 => f64::MAX,
+                _ => 100.0 + (i as f64 / 10.0).sin(),
+            })
+            .collect::<Vec<_>>();
+        let (bytes, offsets) = encode(&values);
+        assert_eq!(offsets.len(), 3);
+
+        for (block, offset) in offsets.iter().enumerate() {
+            let expected =
+                &values[block * BLOCK_SIZE..((block + 1) * BLOCK_SIZE).min(values.len())];
+            let decoded = BlockDecoder::<f64>::new(&bytes[*offset as usize..])
+                .take(expected.len())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                decoded.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+                expected.iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn repeated_values() {
+        let values = [1.5f32; 2048];
+        let (bytes, offsets) = encode(&values);
+        // The first value and one bit for every repeat.
+        assert_eq!(bytes.len(), 2 * (4 + 1023 / 8 + 1));
+        assert_eq!(offsets, vec![0, bytes.len() as u64 / 2]);
+        assert!(BlockDecoder::<f32>::new(&bytes[offsets[1] as usize..])
+            .take(BLOCK_SIZE)
+            .all(|v| v == 1.5));
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::SeekFrom;
+
+use futures_util::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use vortex::array::chunked::ChunkedArray;
+use vortex::{Context, IntoArray, OwnedArray, ViewContext};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::async_io::AsyncMessageReader;
+use crate::file::reader::{parse_footer, parse_trailer};
+use crate::file::TRAILER_SIZE;
+use crate::flatbuffers::ipc::ChunkLocation;
+use crate::messages::SerdeContextDeserializer;
+
+/// Reads arrays from a Vortex file over an [`AsyncRead`] + [`AsyncSeek`], seeking directly to
+/// the chunks that are required.
+pub struct AsyncFileReader<R: AsyncRead + AsyncSeek + Unpin> {
+    read: R,
+    ctx: ViewContext,
+    dtype: DType,
+    chunks: Vec<ChunkLocation>,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncFileReader<R> {
+    pub async fn try_new(mut read: R, ctx: &Context) -> VortexResult<Self> {
+        // Read the trailer from the end of the file.
+        let file_size = read.seek(SeekFrom::End(0)).await?;
+        if file_size < TRAILER_SIZE as u64 {
+            vortex_bail!(InvalidSerde: "File is too small to be a Vortex file")
+        }
+        read.seek(SeekFrom::End(-(TRAILER_SIZE as i64))).await?;
+        let mut trailer = [0u8; TRAILER_SIZE];
+        read.read_exact(&mut trailer).await?;
+        let footer_offset = parse_trailer(&trailer, file_size)?;
+
+        // Read the footer.
+        read.seek(SeekFrom::Start(footer_offset)).await?;
+        let messages = AsyncMessageReader::try_new(&mut read).await?;
+        let (dtype, chunks) = parse_footer(messages.peek())?;
+
+        // Read the context from the start of the file.
+        read.seek(SeekFrom::Start(0)).await?;
+        let messages = AsyncMessageReader::try_new(&mut read).await?;
+        let view_ctx: ViewContext = SerdeContextDeserializer {
+            fb: messages
+                .peek()
+                .and_then(|msg| msg.header_as_context())
+                .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Context at file start"))?,
+            ctx,
+        }
+        .try_into()?;
+
+        Ok(Self {
+            read,
+            ctx: view_ctx,
+            dtype,
+            chunks,
+        })
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// The total number of rows in the file.
+    pub fn len(&self) -> usize {
+        self.chunks
+            .last()
+            .map(|c| (c.row_offset() + c.row_count()) as usize)
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn nchunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Read a single chunk of the file.
+    pub async fn read_chunk(&mut self, idx: usize) -> VortexResult<OwnedArray> {
+        let location = *self
+            .chunks
+            .get(idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, self.chunks.len()))?;
+        self.read.seek(SeekFrom::Start(location.offset())).await?;
+
+        let messages = AsyncMessageReader::try_new(&mut self.read).await?;
+        let array = messages
+            .into_chunk(&mut self.read, &self.ctx, &self.dtype)
+            .await?
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Chunk at chunk offset"))?;
+        if array.len() != location.row_count() as usize {
+            vortex_bail!(InvalidSerde:
+                "Chunk {} has {} rows, footer expected {}",
+                idx,
+                array.len(),
+                location.row_count()
+            )
+        }
+        Ok(array)
+    }
+
+    /// Read the whole array from the file.
+    pub async fn read_array(&mut self) -> VortexResult<OwnedArray> {
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for idx in 0..self.nchunks() {
+            chunks.push(self.read_chunk(idx).await?);
+        }
+        if chunks.len() == 1 {
+            Ok(chunks.remove(0))
+        } else {
+            ChunkedArray::try_new(chunks, self.dtype.clone()).map(|c| c.into_array())
+        }
+    }
+
+    /// Returns a [`Stream`] over the chunks of the file, in order.
+    pub fn chunks(&mut self) -> impl Stream<Item = VortexResult<OwnedArray>> + '_ {
+        futures_util::stream::try_unfold((self, 0), |(reader, idx)| async move {
+            if idx >= reader.nchunks() {
+                return Ok(None);
+            }
+            let chunk = reader.read_chunk(idx).await?;
+            Ok(Some((chunk, (reader, idx + 1))))
+        })
+    }
+}
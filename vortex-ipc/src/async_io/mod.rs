@@ -0,0 +1,298 @@
+//! Async counterparts of the IPC readers and writers, built on tokio's `AsyncRead`/`AsyncWrite`.
+//!
+//! The wire format is identical to that of the blocking [`crate::reader`] and [`crate::writer`],
+//! so streams and files may be written synchronously and read asynchronously, or vice versa.
+
+use arrow_buffer::Buffer as ArrowBuffer;
+use flatbuffers::{root, root_unchecked};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use vortex::{ArrayView, IntoArray, OwnedArray, ToStatic, ViewContext};
+use vortex_buffer::Buffer;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::compression::decompress;
+use crate::flatbuffers::ipc::Message;
+use crate::missing;
+
+pub mod file;
+pub mod reader;
+pub mod writer;
+
+/// Reads length-prefixed messages from an async stream, allowing the next message to be peeked.
+pub(crate) struct AsyncMessageReader {
+    message: Vec<u8>,
+    finished: bool,
+}
+
+impl AsyncMessageReader {
+    pub async fn try_new<R: AsyncRead + Unpin>(read: &mut R) -> VortexResult<Self> {
+        let mut reader = Self {
+            message: Vec::new(),
+            finished: false,
+        };
+        reader.advance(read).await?;
+        Ok(reader)
+    }
+
+    pub fn peek(&self) -> Option<Message> {
+        if self.finished {
+            return None;
+        }
+        // The message has been validated by the advance() call.
+        Some(unsafe { root_unchecked::<Message>(&self.message) })
+    }
+
+    /// Discard the current message and load the next one from the stream.
+    pub async fn advance<R: AsyncRead + Unpin>(&mut self, read: &mut R) -> VortexResult<()> {
+        let mut len_buf = [0u8; 4];
+        if let Err(e) = read.read_exact(&mut len_buf).await {
+            return match e.kind() {
+                std::io::ErrorKind::UnexpectedEof => {
+                    self.finished = true;
+                    Ok(())
+                }
+                _ => Err(e.into()),
+            };
+        }
+
+        let len = u32::from_le_bytes(len_buf);
+        if len == u32::MAX {
+            // Marker for no more messages.
+            self.finished = true;
+            return Ok(());
+        }
+
+        self.message.clear();
+        self.message.reserve(len as usize);
+        if (&mut *read)
+            .take(len as u64)
+            .read_to_end(&mut self.message)
+            .await?
+            != len as usize
+        {
+            vortex_bail!(InvalidSerde: "Failed to read all bytes")
+        }
+        root::<Message>(&self.message)?;
+        Ok(())
+    }
+
+    /// Read the chunk at the head of the stream along with its buffers, then advance past it.
+    pub async fn read_chunk<R: AsyncRead + Unpin>(
+        &mut self,
+        read: &mut R,
+        ctx: &ViewContext,
+        dtype: &DType,
+    ) -> VortexResult<Option<OwnedArray>> {
+        let array = self.read_head_chunk(read, ctx, dtype).await?;
+        if array.is_some() {
+            self.advance(read).await?;
+        }
+        Ok(array)
+    }
+
+    /// Read the chunk at the head of the stream along with its buffers, without reading the
+    /// message that follows it, e.g. for random access into a file.
+    pub async fn into_chunk<R: AsyncRead + Unpin>(
+        self,
+        read: &mut R,
+        ctx: &ViewContext,
+        dtype: &DType,
+    ) -> VortexResult<Option<OwnedArray>> {
+        self.read_head_chunk(read, ctx, dtype).await
+    }
+
+    async fn read_head_chunk<R: AsyncRead + Unpin>(
+        &self,
+        read: &mut R,
+        ctx: &ViewContext,
+        dtype: &DType,
+    ) -> VortexResult<Option<OwnedArray>> {
+        let Some(chunk_msg) = self.peek().and_then(|msg| msg.header_as_chunk()) else {
+            return Ok(None);
+        };
+
+        let mut buffers = vec![];
+        let mut offset = 0;
+        for buffer in chunk_msg.buffers().unwrap_or_default().iter() {
+            skip(read, buffer.offset() - offset).await?;
+            let mut bytes = Vec::with_capacity(buffer.length() as usize);
+            if (&mut *read)
+                .take(buffer.length())
+                .read_to_end(&mut bytes)
+                .await?
+                != buffer.length() as usize
+            {
+                vortex_bail!(InvalidSerde: "Failed to read all bytes")
+            }
+            let bytes = decompress(buffer.compression(), bytes)?;
+            buffers.push(Buffer::from(ArrowBuffer::from_vec(bytes)));
+            offset = buffer.offset() + buffer.length();
+        }
+        // Consume any remaining padding after the final buffer.
+        skip(read, chunk_msg.buffer_size() - offset).await?;
+
+        let view = ArrayView::try_new(
+            ctx,
+            dtype,
+            chunk_msg.array().ok_or_else(missing("array"))?,
+            &buffers,
+        )?;
+        Ok(Some(view.into_array().to_static()))
+    }
+
+    /// Skip over any chunks at the head of the stream without reading their buffers.
+    pub async fn skip_chunks<R: AsyncRead + Unpin>(&mut self, read: &mut R) -> VortexResult<()> {
+        while let Some(buffer_size) = self
+            .peek()
+            .and_then(|msg| msg.header_as_chunk())
+            .map(|chunk| chunk.buffer_size())
+        {
+            skip(read, buffer_size).await?;
+            self.advance(read).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Skip n bytes in the stream.
+async fn skip<R: AsyncRead + Unpin>(read: &mut R, nbytes: u64) -> VortexResult<()> {
+    tokio::io::copy(&mut (&mut *read).take(nbytes), &mut tokio::io::sink()).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use futures_util::TryStreamExt;
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::{ArrayDType, Context, IntoArray, OwnedArray, ToStatic};
+
+    use crate::async_io::file::AsyncFileReader;
+    use crate::async_io::reader::AsyncStreamReader;
+    use crate::async_io::writer::AsyncStreamWriter;
+    use crate::compression::Compression;
+    use crate::file::writer::FileWriter;
+    use crate::reader::StreamReader;
+    use crate::writer::StreamWriter;
+
+    fn chunked_data() -> OwnedArray {
+        let chunks = (0..3)
+            .map(|c| PrimitiveArray::from((c * 100..(c + 1) * 100).collect_vec()).into_array())
+            .collect_vec();
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap().into_array()
+    }
+
+    fn values(chunks: &[OwnedArray]) -> Vec<i32> {
+        chunks
+            .iter()
+            .flat_map(|c| {
+                c.clone()
+                    .flatten_primitive()
+                    .unwrap()
+                    .typed_data::<i32>()
+                    .to_vec()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn read_stream_as_chunks() {
+        let mut buffer = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut buffer, &Context::default()).unwrap();
+            writer.write_array(&chunked_data()).unwrap();
+            writer
+                .write_array(&PrimitiveArray::from(vec![1i64, 2]).into_array())
+                .unwrap();
+        }
+
+        let mut reader = AsyncStreamReader::try_new(Cursor::new(buffer), &Context::default())
+            .await
+            .unwrap();
+        let chunks: Vec<OwnedArray> = reader
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_stream()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(values(&chunks), (0..300).collect_vec());
+
+        let second = reader.read_array().await.unwrap();
+        assert_eq!(
+            second.flatten_primitive().unwrap().typed_data::<i64>(),
+            [1, 2]
+        );
+        assert!(reader.next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn skip_unread_chunks() {
+        let mut buffer = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut buffer, &Context::default()).unwrap();
+            writer.write_array(&chunked_data()).unwrap();
+            writer
+                .write_array(&PrimitiveArray::from(vec![1i64, 2]).into_array())
+                .unwrap();
+        }
+
+        let mut reader = AsyncStreamReader::try_new(Cursor::new(buffer), &Context::default())
+            .await
+            .unwrap();
+        let mut array_reader = reader.next().await.unwrap().unwrap();
+        assert!(array_reader.next().await.unwrap().is_some());
+
+        let second = reader.read_array().await.unwrap();
+        assert_eq!(
+            second.flatten_primitive().unwrap().typed_data::<i64>(),
+            [1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn write_stream() {
+        let mut writer = AsyncStreamWriter::try_new(vec![], &Context::default())
+            .await
+            .unwrap()
+            .with_compression(Compression::LZ4);
+        writer.write_array(&chunked_data()).await.unwrap();
+        let buffer = writer.finish().await.unwrap();
+
+        let mut reader = StreamReader::try_new(Cursor::new(buffer), &Context::default()).unwrap();
+        let array = ChunkedArray::try_from(reader.read_array().unwrap().to_static()).unwrap();
+        let chunks = array.chunks().map(|c| c.to_static()).collect_vec();
+        assert_eq!(values(&chunks), (0..300).collect_vec());
+    }
+
+    #[tokio::test]
+    async fn read_file() {
+        let mut buffer = vec![];
+        {
+            let mut writer =
+                FileWriter::try_new(Cursor::new(&mut buffer), &Context::default()).unwrap();
+            writer.write_array(&chunked_data()).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut reader = AsyncFileReader::try_new(Cursor::new(buffer), &Context::default())
+            .await
+            .unwrap();
+        assert_eq!(reader.nchunks(), 3);
+        assert_eq!(reader.len(), 300);
+
+        let chunk = reader.read_chunk(1).await.unwrap();
+        assert_eq!(values(&[chunk]), (100..200).collect_vec());
+
+        let chunks: Vec<OwnedArray> = reader.chunks().try_collect().await.unwrap();
+        assert_eq!(values(&chunks), (0..300).collect_vec());
+    }
+}
@@ -0,0 +1,113 @@
+use futures_util::Stream;
+use tokio::io::AsyncRead;
+use vortex::array::chunked::ChunkedArray;
+use vortex::{Context, IntoArray, OwnedArray, ViewContext};
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_flatbuffers::ReadFlatBuffer;
+
+use crate::async_io::AsyncMessageReader;
+use crate::messages::SerdeContextDeserializer;
+
+/// Reads arrays from an IPC stream over an [`AsyncRead`].
+pub struct AsyncStreamReader<R: AsyncRead + Unpin> {
+    read: R,
+    messages: AsyncMessageReader,
+    ctx: ViewContext,
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    pub async fn try_new(mut read: R, ctx: &Context) -> VortexResult<Self> {
+        let mut messages = AsyncMessageReader::try_new(&mut read).await?;
+        let view_ctx: ViewContext = SerdeContextDeserializer {
+            fb: messages
+                .peek()
+                .ok_or_else(|| vortex_err!("IPC stream is empty"))?
+                .header_as_context()
+                .ok_or_else(
+                    || vortex_err!(InvalidSerde: "Expected IPC Context as first message in stream"),
+                )?,
+            ctx,
+        }
+        .try_into()?;
+        messages.advance(&mut read).await?;
+
+        Ok(Self {
+            read,
+            messages,
+            ctx: view_ctx,
+        })
+    }
+
+    /// Returns a reader over the chunks of the next array in the stream.
+    pub async fn next(&mut self) -> VortexResult<Option<AsyncArrayReader<'_, R>>> {
+        // Skip over any chunks left unread by the previous array reader.
+        self.messages.skip_chunks(&mut self.read).await?;
+
+        let Some(schema_msg) = self.messages.peek().and_then(|msg| msg.header_as_schema()) else {
+            return Ok(None);
+        };
+        let dtype = DType::read_flatbuffer(
+            &schema_msg
+                .dtype()
+                .ok_or_else(|| vortex_err!(InvalidSerde: "Schema missing DType"))?,
+        )
+        .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {}", e))?;
+        self.messages.advance(&mut self.read).await?;
+
+        Ok(Some(AsyncArrayReader {
+            reader: self,
+            dtype,
+        }))
+    }
+
+    /// Read a single array from the IPC stream.
+    pub async fn read_array(&mut self) -> VortexResult<OwnedArray> {
+        let mut array_reader = self
+            .next()
+            .await?
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Unexpected EOF"))?;
+
+        let mut chunks = vec![];
+        while let Some(chunk) = array_reader.next().await? {
+            chunks.push(chunk);
+        }
+
+        if chunks.len() == 1 {
+            Ok(chunks.remove(0))
+        } else {
+            ChunkedArray::try_new(chunks, array_reader.dtype().clone())
+                .map(|chunked| chunked.into_array())
+        }
+    }
+}
+
+/// Reads the chunks of a single array from an [`AsyncStreamReader`].
+///
+/// Any chunks that are not read are skipped when the next array is requested from the stream.
+pub struct AsyncArrayReader<'a, R: AsyncRead + Unpin> {
+    reader: &'a mut AsyncStreamReader<R>,
+    dtype: DType,
+}
+
+impl<'a, R: AsyncRead + Unpin> AsyncArrayReader<'a, R> {
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// Read the next chunk of the array, or None once every chunk has been read.
+    pub async fn next(&mut self) -> VortexResult<Option<OwnedArray>> {
+        let reader = &mut *self.reader;
+        reader
+            .messages
+            .read_chunk(&mut reader.read, &reader.ctx, &self.dtype)
+            .await
+    }
+
+    /// Convert the reader into a [`Stream`] of chunks.
+    pub fn into_stream(self) -> impl Stream<Item = VortexResult<OwnedArray>> + 'a {
+        futures_util::stream::try_unfold(self, |mut reader| async move {
+            Ok(reader.next().await?.map(|chunk| (chunk, reader)))
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use vortex::array::chunked::ChunkedArray;
+use vortex::{Array, ArrayDType, Context, ViewContext};
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_flatbuffers::FlatBufferWriter;
+
+use crate::compression::Compression;
+use crate::messages::{IPCContext, IPCMessage, IPCSchema};
+use crate::writer::write_chunk;
+use crate::ALIGNMENT;
+
+/// Writes arrays to an IPC stream over an [`AsyncWrite`].
+///
+/// Each message is serialized into memory before being written, and the stream must be
+/// terminated by calling [`AsyncStreamWriter::finish`].
+pub struct AsyncStreamWriter<W: AsyncWrite + Unpin> {
+    write: W,
+    ctx: ViewContext,
+    compression: Compression,
+    scratch: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncStreamWriter<W> {
+    pub async fn try_new(write: W, ctx: &Context) -> VortexResult<Self> {
+        let view_ctx = ViewContext::from(ctx);
+        let mut writer = Self {
+            write,
+            ctx: view_ctx,
+            compression: Compression::None,
+            scratch: vec![],
+        };
+
+        // Write the IPC context to the stream
+        writer
+            .scratch
+            .write_message(&IPCMessage::Context(IPCContext(&writer.ctx)), ALIGNMENT)?;
+        writer.flush_scratch().await?;
+        Ok(writer)
+    }
+
+    /// Compress the buffers of subsequently written chunks, see [`Compression`].
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub async fn write_array(&mut self, array: &Array<'_>) -> VortexResult<()> {
+        self.write_schema(array.dtype()).await?;
+        match ChunkedArray::try_from(array) {
+            Ok(chunked) => {
+                for chunk in chunked.chunks() {
+                    self.write_batch(&chunk).await?;
+                }
+                Ok(())
+            }
+            Err(_) => self.write_batch(array).await,
+        }
+    }
+
+    pub async fn write_schema(&mut self, dtype: &DType) -> VortexResult<()> {
+        self.scratch
+            .write_message(&IPCMessage::Schema(IPCSchema(dtype)), ALIGNMENT)?;
+        self.flush_scratch().await
+    }
+
+    pub async fn write_batch(&mut self, array: &Array<'_>) -> VortexResult<()> {
+        write_chunk(&mut self.scratch, &self.ctx, array, self.compression)?;
+        self.flush_scratch().await
+    }
+
+    /// Terminate the stream and flush the underlying writer, returning it.
+    pub async fn finish(mut self) -> VortexResult<W> {
+        self.write.write_all(&[u8::MAX; 4]).await?;
+        self.write.flush().await?;
+        Ok(self.write)
+    }
+
+    async fn flush_scratch(&mut self) -> VortexResult<()> {
+        self.write.write_all(&self.scratch).await?;
+        self.scratch.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::borrow::Cow;
+
+use lz4_flex::block::DecompressError;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::flatbuffers::ipc as fb;
+
+/// General-purpose block compression applied to each buffer written to an IPC stream.
+///
+/// Buffers that do not shrink when compressed are written uncompressed, so the choice is
+/// recorded per buffer in the Chunk message and readers decompress transparently.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    #[default]
+    None,
+    LZ4,
+    Zstd,
+}
+
+impl Compression {
+    /// Compress the bytes of a buffer, returning the compression that was actually applied.
+    pub(crate) fn compress<'a>(
+        &self,
+        bytes: &'a [u8],
+    ) -> VortexResult<(fb::Compression, Cow<'a, [u8]>)> {
+        let compressed = match self {
+            Compression::None => return Ok((fb::Compression::None, Cow::Borrowed(bytes))),
+            Compression::LZ4 => (fb::Compression::LZ4, lz4_flex::compress_prepend_size(bytes)),
+            Compression::Zstd => (
+                fb::Compression::ZSTD,
+                zstd::bulk::compress(bytes, zstd::DEFAULT_COMPRESSION_LEVEL)?,
+            ),
+        };
+
+        if compressed.1.len() >= bytes.len() {
+            Ok((fb::Compression::None, Cow::Borrowed(bytes)))
+        } else {
+            Ok((compressed.0, Cow::Owned(compressed.1)))
+        }
+    }
+}
+
+/// LZ4 cannot compress more than 255 bytes into one, which bounds the size a compressed buffer
+/// can decompress to.
+const LZ4_MAX_COMPRESSION_RATIO: usize = 255;
+
+/// Zstd can store a 128 KiB block as a 4 byte run-length block, which bounds the size a compressed
+/// buffer can decompress to.
+const ZSTD_MAX_COMPRESSION_RATIO: usize = 128 * 1024 / 4;
+
+/// Decompress the bytes of a buffer read from an IPC stream.
+pub(crate) fn decompress(compression: fb::Compression, bytes: Vec<u8>) -> VortexResult<Vec<u8>> {
+    match compression {
+        fb::Compression::None => Ok(bytes),
+        fb::Compression::LZ4 => {
+            let lz4_err =
+                |e: DecompressError| vortex_err!(InvalidSerde: "Invalid LZ4 buffer: {}", e);
+            // The size prefix is read from the file, so check it before allocating for it.
+            let (size, block) = lz4_flex::block::uncompressed_size(&bytes).map_err(lz4_err)?;
+            if size > block.len() * LZ4_MAX_COMPRESSION_RATIO {
+                vortex_bail!(InvalidSerde:
+                    "LZ4 buffer of {} bytes cannot decompress to {} bytes",
+                    block.len(),
+                    size
+                )
+            }
+            lz4_flex::decompress(block, size).map_err(lz4_err)
+        }
+        fb::Compression::ZSTD => {
+            // Buffers are compressed in one shot, which records their size in the frame header.
+            let size = zstd::zstd_safe::get_frame_content_size(&bytes)
+                .map_err(|_| vortex_err!(InvalidSerde: "Invalid ZSTD frame header"))?
+                .ok_or_else(|| vortex_err!(InvalidSerde: "ZSTD buffer has no content size"))?;
+            if size > (bytes.len() * ZSTD_MAX_COMPRESSION_RATIO) as u64 {
+                vortex_bail!(InvalidSerde:
+                    "ZSTD buffer of {} bytes cannot decompress to {} bytes",
+                    bytes.len(),
+                    size
+                )
+            }
+            Ok(zstd::bulk::decompress(&bytes, size as usize)?)
+        }
+        _ => vortex_bail!(InvalidSerde: "Unknown buffer compression {:?}", compression),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use vortex::array::varbin::VarBinArray;
+    use vortex::{ArrayTrait, Context, IntoArray};
+
+    use crate::compression::{decompress, Compression};
+    use crate::flatbuffers::ipc as fb;
+    use crate::reader::StreamReader;
+    use crate::writer::StreamWriter;
+
+    #[test]
+    fn round_trip() {
+        let bytes = (0..4096u32)
+            .flat_map(|i| (i % 7).to_le_bytes())
+            .collect::<Vec<u8>>();
+        for compression in [Compression::LZ4, Compression::Zstd] {
+            let (applied, compressed) = compression.compress(&bytes).unwrap();
+            assert_ne!(applied, fb::Compression::None);
+            assert!(compressed.len() < bytes.len());
+            assert_eq!(decompress(applied, compressed.into_owned()).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn lz4_size_out_of_bounds() {
+        let mut bytes = u32::MAX.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[0u8; 16]);
+        assert!(decompress(fb::Compression::LZ4, bytes).is_err());
+    }
+
+    #[test]
+    fn zstd_size_out_of_bounds() {
+        // A single segment frame header with an 8 byte content size.
+        let mut bytes = vec![0x28, 0xB5, 0x2F, 0xFD, 0xE0];
+        bytes.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 16]);
+        assert!(decompress(fb::Compression::ZSTD, bytes).is_err());
+    }
+
+    #[test]
+    fn incompressible() {
+        let bytes = [1u8, 2, 3];
+        for compression in [Compression::None, Compression::LZ4, Compression::Zstd] {
+            let (applied, compressed) = compression.compress(&bytes).unwrap();
+            assert_eq!(applied, fb::Compression::None);
+            assert_eq!(compressed.as_ref(), bytes);
+        }
+    }
+
+    #[test]
+    fn stream_round_trip() {
+        let values = (0..1000)
+            .map(|i| format!("value-{}", i % 10))
+            .collect::<Vec<_>>();
+        let array = VarBinArray::from(values.clone()).into_array();
+
+        let mut sizes = vec![];
+        for compression in [Compression::None, Compression::LZ4, Compression::Zstd] {
+            let mut buffer = vec![];
+            {
+                let mut writer =
+                    StreamWriter::try_new(Cursor::new(&mut buffer), &Context::default())
+                        .unwrap()
+                        .with_compression(compression);
+                writer.write_array(&array).unwrap();
+            }
+            sizes.push(buffer.len());
+
+            let mut reader =
+                StreamReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+            let read = VarBinArray::try_from(reader.read_array().unwrap()).unwrap();
+            let read_values = (0..read.len())
+                .map(|i| String::from_utf8(read.bytes_at(i).unwrap().as_ref().to_vec()).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(read_values, values);
+        }
+        assert!(sizes[1] < sizes[0]);
+        assert!(sizes[2] < sizes[0]);
+    }
+}
@@ -0,0 +1,280 @@
+use std::fs::File;
+use std::path::Path;
+
+use arrow_buffer::Buffer as ArrowBuffer;
+use bytes::Bytes;
+use flatbuffers::root;
+use itertools::Itertools;
+use vortex::array::chunked::ChunkedArray;
+use vortex::{ArrayView, Context, IntoArray, OwnedArray, ToStatic, ViewContext};
+use vortex_buffer::Buffer;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::compression::decompress;
+use crate::file::reader::{parse_footer, parse_trailer};
+use crate::file::TRAILER_SIZE;
+use crate::flatbuffers::ipc::{ChunkLocation, Compression, Message};
+use crate::messages::SerdeContextDeserializer;
+use crate::{missing, ALIGNMENT};
+
+/// Reads arrays from a Vortex file that is held entirely in memory, typically a memory-mapped
+/// region.
+///
+/// Uncompressed buffers are not copied: the arrays that are read hold [`Buffer::Bytes`] slices
+/// pointing directly into the underlying [`Bytes`], so opening a file only costs parsing its
+/// footer and each chunk only costs parsing its flatbuffer.
+pub struct BytesFileReader {
+    bytes: Bytes,
+    ctx: ViewContext,
+    dtype: DType,
+    chunks: Vec<ChunkLocation>,
+}
+
+impl BytesFileReader {
+    /// Memory-map the file at the given path and open it.
+    pub fn open<P: AsRef<Path>>(path: P, ctx: &Context) -> VortexResult<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only. As with any memory map, the file must not be
+        //  modified or truncated by another process while it is being read.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Self::try_new(Bytes::from_owner(mmap), ctx)
+    }
+
+    pub fn try_new(bytes: Bytes, ctx: &Context) -> VortexResult<Self> {
+        // Buffers are only aligned relative to the start of the file, so the file itself must be
+        // aligned for them to be read in place.
+        let bytes = if bytes.as_ptr() as usize % ALIGNMENT != 0 {
+            Bytes::from_owner(AlignedBytes(ArrowBuffer::from(bytes.as_ref())))
+        } else {
+            bytes
+        };
+
+        let file_size = bytes.len() as u64;
+        if bytes.len() < TRAILER_SIZE {
+            vortex_bail!(InvalidSerde: "File is too small to be a Vortex file")
+        }
+        let trailer: &[u8; TRAILER_SIZE] = bytes[bytes.len() - TRAILER_SIZE..].try_into().unwrap();
+        let footer_offset = parse_trailer(trailer, file_size)?;
+
+        let (footer, _) = message_at(&bytes, footer_offset as usize)?;
+        let (dtype, chunks) = parse_footer(Some(footer))?;
+
+        let (context, _) = message_at(&bytes, 0)?;
+        let view_ctx: ViewContext = SerdeContextDeserializer {
+            fb: context
+                .header_as_context()
+                .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Context at file start"))?,
+            ctx,
+        }
+        .try_into()?;
+
+        Ok(Self {
+            bytes,
+            ctx: view_ctx,
+            dtype,
+            chunks,
+        })
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// The total number of rows in the file.
+    pub fn len(&self) -> usize {
+        self.chunks
+            .last()
+            .map(|c| (c.row_offset() + c.row_count()) as usize)
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn nchunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Read a single chunk of the file without copying any of its uncompressed buffers.
+    pub fn read_chunk(&self, idx: usize) -> VortexResult<OwnedArray> {
+        let location = self
+            .chunks
+            .get(idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, self.chunks.len()))?;
+
+        let (message, buffers_offset) = message_at(&self.bytes, location.offset() as usize)?;
+        let chunk_msg = message
+            .header_as_chunk()
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Chunk at chunk offset"))?;
+        if buffers_offset + chunk_msg.buffer_size() as usize > self.bytes.len() {
+            vortex_bail!(InvalidSerde: "Chunk {} buffers extend past the end of the file", idx)
+        }
+
+        let buffers: Vec<Buffer> = chunk_msg
+            .buffers()
+            .unwrap_or_default()
+            .iter()
+            .map(|buffer| {
+                if buffer.offset() + buffer.length() > chunk_msg.buffer_size() {
+                    vortex_bail!(InvalidSerde: "Buffer extends past the end of chunk {}", idx)
+                }
+                let start = buffers_offset + buffer.offset() as usize;
+                let bytes = self.bytes.slice(start..start + buffer.length() as usize);
+                match buffer.compression() {
+                    Compression::None => Ok(Buffer::Bytes(bytes)),
+                    compression => decompress(compression, bytes.to_vec())
+                        .map(|decompressed| Buffer::from(ArrowBuffer::from_vec(decompressed))),
+                }
+            })
+            .try_collect()?;
+
+        let view = ArrayView::try_new(
+            &self.ctx,
+            &self.dtype,
+            chunk_msg.array().ok_or_else(missing("array"))?,
+            &buffers,
+        )?;
+        let array = view.into_array().to_static();
+        if array.len() != location.row_count() as usize {
+            vortex_bail!(InvalidSerde:
+                "Chunk {} has {} rows, footer expected {}",
+                idx,
+                array.len(),
+                location.row_count()
+            )
+        }
+        Ok(array)
+    }
+
+    /// Read the whole array from the file.
+    pub fn read_array(&self) -> VortexResult<OwnedArray> {
+        let mut chunks: Vec<OwnedArray> = (0..self.nchunks())
+            .map(|idx| self.read_chunk(idx))
+            .try_collect()?;
+        if chunks.len() == 1 {
+            Ok(chunks.remove(0))
+        } else {
+            ChunkedArray::try_new(chunks, self.dtype.clone()).map(|c| c.into_array())
+        }
+    }
+}
+
+/// A copy of some bytes into memory aligned to [`ALIGNMENT`].
+struct AlignedBytes(ArrowBuffer);
+
+impl AsRef<[u8]> for AlignedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Parse the length-prefixed message at the given offset, returning it along with the offset of
+/// the first byte after it.
+fn message_at(bytes: &[u8], offset: usize) -> VortexResult<(Message, usize)> {
+    let len_bytes = bytes
+        .get(offset..offset + 4)
+        .ok_or_else(|| vortex_err!(InvalidSerde: "Message offset {} is out of bounds", offset))?;
+    let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+    let end = offset + 4 + len;
+    let message = bytes
+        .get(offset + 4..end)
+        .ok_or_else(|| vortex_err!(InvalidSerde: "Message at {} is truncated", offset))?;
+    Ok((root::<Message>(message)?, end))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use bytes::Bytes;
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::{ArrayDType, Context, IntoArray, OwnedArray, ToStatic};
+    use vortex_buffer::Buffer;
+
+    use crate::compression::Compression;
+    use crate::file::bytes_reader::BytesFileReader;
+    use crate::file::writer::FileWriter;
+
+    fn write_file(compression: Compression) -> Vec<u8> {
+        let chunks = (0..3)
+            .map(|c| PrimitiveArray::from((c * 1000..(c + 1) * 1000).collect_vec()).into_array())
+            .collect_vec();
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        let mut buffer = vec![];
+        {
+            let mut writer = FileWriter::try_new(Cursor::new(&mut buffer), &Context::default())
+                .unwrap()
+                .with_compression(compression);
+            writer.write_array(&array).unwrap();
+            writer.finish().unwrap();
+        }
+        buffer
+    }
+
+    fn values(array: OwnedArray) -> Vec<i32> {
+        array
+            .flatten_primitive()
+            .unwrap()
+            .typed_data::<i32>()
+            .to_vec()
+    }
+
+    #[test]
+    fn zero_copy_chunks() {
+        let bytes = Bytes::from(write_file(Compression::None));
+        let reader = BytesFileReader::try_new(bytes, &Context::default()).unwrap();
+        assert_eq!(reader.nchunks(), 3);
+        assert_eq!(reader.len(), 3000);
+
+        let file_range = reader.bytes.as_ptr_range();
+        let chunk = reader.read_chunk(1).unwrap().into_primitive();
+        match chunk.buffer() {
+            Buffer::Bytes(b) => assert!(file_range.contains(&b.as_ptr())),
+            Buffer::Arrow(_) => panic!("Expected the buffer to point into the file"),
+        }
+        assert_eq!(chunk.typed_data::<i32>(), (1000..2000).collect_vec());
+    }
+
+    #[test]
+    fn unaligned_bytes() {
+        let mut buffer = vec![0u8];
+        buffer.extend(write_file(Compression::None));
+        let bytes = Bytes::from(buffer).slice(1..);
+        let reader = BytesFileReader::try_new(bytes, &Context::default()).unwrap();
+
+        let chunks = ChunkedArray::try_from(reader.read_array().unwrap()).unwrap();
+        let read = chunks
+            .chunks()
+            .flat_map(|c| values(c.to_static()))
+            .collect_vec();
+        assert_eq!(read, (0..3000).collect_vec());
+    }
+
+    #[test]
+    fn compressed_chunks() {
+        let bytes = Bytes::from(write_file(Compression::LZ4));
+        let reader = BytesFileReader::try_new(bytes, &Context::default()).unwrap();
+        assert_eq!(
+            values(reader.read_chunk(2).unwrap()),
+            (2000..3000).collect_vec()
+        );
+    }
+
+    #[test]
+    fn open_mmap() {
+        let path = std::env::temp_dir().join(format!("vortex-mmap-{}.vtx", std::process::id()));
+        std::fs::write(&path, write_file(Compression::None)).unwrap();
+        let reader = BytesFileReader::open(&path, &Context::default()).unwrap();
+        assert_eq!(
+            values(reader.read_chunk(0).unwrap()),
+            (0..1000).collect_vec()
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
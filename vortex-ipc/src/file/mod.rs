@@ -0,0 +1,147 @@
+//! A random-access Vortex file layout.
+//!
+//! A file contains an IPC Context message followed by a sequence of Chunk messages (each with its
+//! buffers), exactly as they appear in an IPC stream. These are followed by a Footer message
+//! holding the DType of the array and the location of every chunk, and finally a fixed-size
+//! trailer:
+//!
+//! ```text
+//! | footer offset: u64 | version: u32 | magic: "VRTX" |
+//! ```
+//!
+//! All integers are little-endian.
+
+pub mod bytes_reader;
+pub mod reader;
+pub mod writer;
+
+/// The bytes that terminate every Vortex file.
+pub const MAGIC_BYTES: [u8; 4] = *b"VRTX";
+
+/// The version of the file layout written by the [`writer::FileWriter`].
+pub const FILE_VERSION: u32 = 0;
+
+/// The size in bytes of the trailer at the end of a Vortex file.
+pub const TRAILER_SIZE: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::{ArrayDType, Context, IntoArray, OwnedArray};
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::file::reader::FileReader;
+    use crate::file::writer::FileWriter;
+
+    fn write_file(array: &OwnedArray) -> Vec<u8> {
+        let mut buffer = vec![];
+        {
+            let mut writer =
+                FileWriter::try_new(Cursor::new(&mut buffer), &Context::default()).unwrap();
+            writer.write_array(array).unwrap();
+            writer.finish().unwrap();
+        }
+        buffer
+    }
+
+    fn chunked_data() -> OwnedArray {
+        let chunks = (0..3)
+            .map(|c| PrimitiveArray::from((c * 1000..(c + 1) * 1000).collect_vec()).into_array())
+            .collect_vec();
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap().into_array()
+    }
+
+    fn primitive_values(array: &OwnedArray) -> Vec<i32> {
+        ChunkedArray::try_from(array)
+            .unwrap()
+            .chunks()
+            .flat_map(|c| c.flatten_primitive().unwrap().typed_data::<i32>().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn footer_round_trip() {
+        let buffer = write_file(&chunked_data());
+
+        let reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        assert_eq!(
+            reader.dtype(),
+            &DType::Primitive(PType::I32, Nullability::NonNullable)
+        );
+        assert_eq!(reader.nchunks(), 3);
+        assert_eq!(reader.len(), 3000);
+    }
+
+    #[test]
+    fn read_chunk() {
+        let buffer = write_file(&chunked_data());
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let chunk = reader.read_chunk(2).unwrap().into_primitive();
+        assert_eq!(chunk.typed_data::<i32>(), (2000..3000).collect_vec());
+        let chunk = reader.read_chunk(0).unwrap().into_primitive();
+        assert_eq!(chunk.typed_data::<i32>(), (0..1000).collect_vec());
+    }
+
+    #[test]
+    fn take_across_chunks() {
+        let buffer = write_file(&chunked_data());
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let indices = PrimitiveArray::from(vec![1u64, 999, 1000, 2500, 2500, 2999]).into_array();
+        let taken = reader.take(&indices).unwrap();
+        assert_eq!(primitive_values(&taken), &[1, 999, 1000, 2500, 2500, 2999]);
+    }
+
+    #[test]
+    fn take_unsorted() {
+        let buffer = write_file(&chunked_data());
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let indices = PrimitiveArray::from(vec![2500u32, 1, 2999, 1000, 1, 999]).into_array();
+        let taken = reader.take(&indices).unwrap();
+        assert_eq!(primitive_values(&taken), &[2500, 1, 2999, 1000, 1, 999]);
+    }
+
+    #[test]
+    fn take_out_of_bounds() {
+        let buffer = write_file(&chunked_data());
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let indices = PrimitiveArray::from(vec![1u64, 3000]).into_array();
+        assert!(reader.take(&indices).is_err());
+    }
+
+    #[test]
+    fn slice_across_chunks() {
+        let buffer = write_file(&chunked_data());
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let sliced = reader.slice(990, 2010).unwrap();
+        assert_eq!(sliced.len(), 1020);
+        assert_eq!(primitive_values(&sliced), (990..2010).collect_vec());
+    }
+
+    #[test]
+    fn read_array() {
+        let array = PrimitiveArray::from(vec![1i64, 2, 3]).into_array();
+        let buffer = write_file(&array);
+
+        let mut reader = FileReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let read = reader.read_array().unwrap().into_primitive();
+        assert_eq!(read.typed_data::<i64>(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let mut buffer = write_file(&chunked_data());
+        let len = buffer.len();
+        buffer[len - 1] = b'Z';
+        assert!(FileReader::try_new(Cursor::new(&buffer), &Context::default()).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use itertools::Itertools;
+use vortex::array::chunked::ChunkedArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::slice::slice;
+use vortex::compute::take::take;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, ArrayView, Context, IntoArray, OwnedArray, ToStatic, ViewContext};
+use vortex_buffer::Buffer;
+use vortex_dtype::{match_each_integer_ptype, DType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_flatbuffers::ReadFlatBuffer;
+
+use crate::file::{FILE_VERSION, MAGIC_BYTES, TRAILER_SIZE};
+use crate::flatbuffers::ipc::{ChunkLocation, Message};
+use crate::messages::SerdeContextDeserializer;
+use crate::missing;
+use crate::reader::{read_chunk_buffers, StreamMessageReader};
+
+/// Reads arrays from a Vortex file, seeking directly to the chunks that are required.
+pub struct FileReader<R: Read + Seek> {
+    read: R,
+    ctx: ViewContext,
+    dtype: DType,
+    chunks: Vec<ChunkLocation>,
+}
+
+impl<R: Read + Seek> FileReader<BufReader<R>> {
+    pub fn try_new(read: R, ctx: &Context) -> VortexResult<Self> {
+        Self::try_new_unbuffered(BufReader::new(read), ctx)
+    }
+}
+
+impl<R: Read + Seek> FileReader<R> {
+    pub fn try_new_unbuffered(mut read: R, ctx: &Context) -> VortexResult<Self> {
+        // Read the trailer from the end of the file.
+        let file_size = read.seek(SeekFrom::End(0))?;
+        if file_size < TRAILER_SIZE as u64 {
+            vortex_bail!(InvalidSerde: "File is too small to be a Vortex file")
+        }
+        read.seek(SeekFrom::End(-(TRAILER_SIZE as i64)))?;
+        let mut trailer = [0u8; TRAILER_SIZE];
+        read.read_exact(&mut trailer)?;
+
+        let footer_offset = parse_trailer(&trailer, file_size)?;
+
+        // Read the footer.
+        read.seek(SeekFrom::Start(footer_offset))?;
+        let messages = StreamMessageReader::try_new(&mut read)?;
+        let (dtype, chunks) = parse_footer(messages.peek())?;
+
+        // Read the context from the start of the file.
+        read.seek(SeekFrom::Start(0))?;
+        let messages = StreamMessageReader::try_new(&mut read)?;
+        let view_ctx: ViewContext = SerdeContextDeserializer {
+            fb: messages
+                .peek()
+                .and_then(|msg| msg.header_as_context())
+                .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Context at file start"))?,
+            ctx,
+        }
+        .try_into()?;
+
+        Ok(Self {
+            read,
+            ctx: view_ctx,
+            dtype,
+            chunks,
+        })
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// The total number of rows in the file.
+    pub fn len(&self) -> usize {
+        self.chunks
+            .last()
+            .map(|c| (c.row_offset() + c.row_count()) as usize)
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn nchunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// The row ranges of each chunk in the file.
+    pub fn chunk_ranges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.chunks.iter().map(|c| {
+            let start = c.row_offset() as usize;
+            (start, start + c.row_count() as usize)
+        })
+    }
+
+    /// Read a single chunk of the file.
+    pub fn read_chunk(&mut self, idx: usize) -> VortexResult<OwnedArray> {
+        let location = self
+            .chunks
+            .get(idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, self.chunks.len()))?;
+        self.read.seek(SeekFrom::Start(location.offset()))?;
+
+        let messages = StreamMessageReader::try_new(&mut self.read)?;
+        let chunk_msg = messages
+            .peek()
+            .and_then(|msg| msg.header_as_chunk())
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Chunk at chunk offset"))?;
+
+        let mut buffers: Vec<Buffer> = vec![];
+        read_chunk_buffers(&mut self.read, chunk_msg, &mut buffers)?;
+
+        let view = ArrayView::try_new(
+            &self.ctx,
+            &self.dtype,
+            chunk_msg.array().ok_or_else(missing("array"))?,
+            &buffers,
+        )?;
+        let array = view.into_array().to_static();
+        if array.len() != location.row_count() as usize {
+            vortex_bail!(InvalidSerde:
+                "Chunk {} has {} rows, footer expected {}",
+                idx,
+                array.len(),
+                location.row_count()
+            )
+        }
+        Ok(array)
+    }
+
+    /// Read the whole array from the file.
+    pub fn read_array(&mut self) -> VortexResult<OwnedArray> {
+        let chunks = (0..self.nchunks())
+            .map(|idx| self.read_chunk(idx))
+            .try_collect()?;
+        self.collect_chunks(chunks)
+    }
+
+    /// Read the rows in the range `start..stop`, only loading the chunks that overlap it.
+    pub fn slice(&mut self, start: usize, stop: usize) -> VortexResult<OwnedArray> {
+        if start > stop || stop > self.len() {
+            vortex_bail!(OutOfBounds: stop, start, self.len());
+        }
+
+        let overlapping = self
+            .chunk_ranges()
+            .enumerate()
+            .filter(|(_, (chunk_start, chunk_stop))| *chunk_start < stop && *chunk_stop > start)
+            .collect_vec();
+
+        let mut chunks = Vec::with_capacity(overlapping.len());
+        for (idx, (chunk_start, chunk_stop)) in overlapping {
+            let chunk = self.read_chunk(idx)?;
+            chunks.push(slice(
+                &chunk,
+                start.max(chunk_start) - chunk_start,
+                stop.min(chunk_stop) - chunk_start,
+            )?);
+        }
+        self.collect_chunks(chunks)
+    }
+
+    /// Take the rows at the given indices, only loading the chunks that contain them.
+    ///
+    /// The indices may be in any order. Chunks are read in file order, and the rows are then put
+    /// back into the order of the indices.
+    pub fn take(&mut self, indices: &Array) -> VortexResult<OwnedArray> {
+        let indices = self.take_indices(indices)?;
+        if let Some(&max) = indices.iter().max() {
+            if max as usize >= self.len() {
+                vortex_bail!(OutOfBounds: max as usize, 0, self.len());
+            }
+        }
+
+        let order = (0..indices.len())
+            .sorted_by_key(|&i| indices[i])
+            .collect_vec();
+        let sorted = order.iter().map(|&i| indices[i]).collect_vec();
+
+        let chunk_ranges = self.chunk_ranges().collect_vec();
+        let mut chunks = vec![];
+        for (idx, (chunk_start, chunk_stop)) in chunk_ranges.into_iter().enumerate() {
+            let left = sorted.partition_point(|&i| (i as usize) < chunk_start);
+            let right = sorted.partition_point(|&i| (i as usize) < chunk_stop);
+            if left == right {
+                continue;
+            }
+
+            let chunk = self.read_chunk(idx)?;
+            let chunk_indices = PrimitiveArray::from(
+                sorted[left..right]
+                    .iter()
+                    .map(|&i| i - chunk_start as u64)
+                    .collect_vec(),
+            );
+            chunks.push(take(&chunk, chunk_indices.array())?);
+        }
+        let taken = self.collect_chunks(chunks)?;
+
+        if order.iter().enumerate().all(|(pos, &i)| pos == i) {
+            return Ok(taken);
+        }
+        // The row for the i-th index was taken at the position of i in the sorted order.
+        let mut positions = vec![0u64; order.len()];
+        for (pos, &i) in order.iter().enumerate() {
+            positions[i] = pos as u64;
+        }
+        take(&taken, PrimitiveArray::from(positions).array())
+    }
+
+    fn take_indices(&self, indices: &Array) -> VortexResult<Vec<u64>> {
+        if indices.is_empty() {
+            return Ok(vec![]);
+        }
+        if !indices.dtype().is_int() {
+            vortex_bail!("Indices must be integers")
+        }
+        if indices.statistics().compute_null_count()? > 0 {
+            vortex_bail!("Indices must not contain nulls")
+        }
+        if indices.dtype().is_signed_int()
+            && indices.statistics().compute_as_cast::<i64>(Stat::Min)? < 0
+        {
+            vortex_bail!("Indices must be positive")
+        }
+
+        let indices = indices.clone().flatten_primitive()?;
+        Ok(match_each_integer_ptype!(indices.ptype(), |$P| {
+            indices.typed_data::<$P>().iter().map(|&i| i as u64).collect_vec()
+        }))
+    }
+
+    fn collect_chunks(&self, mut chunks: Vec<OwnedArray>) -> VortexResult<OwnedArray> {
+        if chunks.len() == 1 {
+            Ok(chunks.remove(0))
+        } else {
+            ChunkedArray::try_new(chunks, self.dtype.clone()).map(|c| c.into_array())
+        }
+    }
+}
+
+/// Validate the trailer at the end of a file, returning the offset of the footer.
+pub(crate) fn parse_trailer(trailer: &[u8; TRAILER_SIZE], file_size: u64) -> VortexResult<u64> {
+    if trailer[12..16] != MAGIC_BYTES {
+        vortex_bail!(InvalidSerde: "File does not end with the Vortex magic bytes")
+    }
+    let version = u32::from_le_bytes(trailer[8..12].try_into().unwrap());
+    if version != FILE_VERSION {
+        vortex_bail!(InvalidSerde: "Unsupported Vortex file version {}", version)
+    }
+    let footer_offset = u64::from_le_bytes(trailer[0..8].try_into().unwrap());
+    if footer_offset >= file_size {
+        vortex_bail!(InvalidSerde: "Footer offset {} is out of bounds", footer_offset)
+    }
+    Ok(footer_offset)
+}
+
+/// Extract the DType and chunk locations from the footer message.
+pub(crate) fn parse_footer(msg: Option<Message>) -> VortexResult<(DType, Vec<ChunkLocation>)> {
+    let footer = msg
+        .and_then(|msg| msg.header_as_footer())
+        .ok_or_else(|| vortex_err!(InvalidSerde: "Expected IPC Footer at footer offset"))?;
+    let dtype = DType::read_flatbuffer(&footer.dtype().ok_or_else(missing("dtype"))?)
+        .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {}", e))?;
+    let chunks = footer
+        .chunks()
+        .ok_or_else(missing("chunks"))?
+        .iter()
+        .copied()
+        .collect_vec();
+    Ok((dtype, chunks))
+}
@@ -0,0 +1,150 @@
+use std::io;
+use std::io::{BufWriter, Write};
+
+use log::error;
+use vortex::array::chunked::ChunkedArray;
+use vortex::{Array, ArrayDType, Context, ViewContext};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_flatbuffers::FlatBufferWriter;
+
+use crate::compression::Compression;
+use crate::file::{FILE_VERSION, MAGIC_BYTES};
+use crate::flatbuffers::ipc::ChunkLocation;
+use crate::messages::{IPCContext, IPCFooter, IPCMessage};
+use crate::writer::write_chunk;
+use crate::ALIGNMENT;
+
+/// Writes a single (possibly chunked) array to a seekable Vortex file.
+///
+/// The footer is written when the writer is finished, either explicitly with
+/// [`FileWriter::finish`] or implicitly when the writer is dropped.
+pub struct FileWriter<W: Write> {
+    write: CountingWrite<W>,
+    ctx: ViewContext,
+    dtype: Option<DType>,
+    chunks: Vec<ChunkLocation>,
+    row_count: u64,
+    compression: Compression,
+    finished: bool,
+}
+
+impl<W: Write> FileWriter<BufWriter<W>> {
+    pub fn try_new(write: W, ctx: &Context) -> VortexResult<Self> {
+        Self::try_new_unbuffered(BufWriter::new(write), ctx)
+    }
+}
+
+impl<W: Write> FileWriter<W> {
+    pub fn try_new_unbuffered(write: W, ctx: &Context) -> VortexResult<Self> {
+        let view_ctx = ViewContext::from(ctx);
+        let mut write = CountingWrite {
+            write,
+            bytes_written: 0,
+        };
+
+        // Write the IPC context to the start of the file
+        write.write_message(&IPCMessage::Context(IPCContext(&view_ctx)), ALIGNMENT)?;
+        Ok(Self {
+            write,
+            ctx: view_ctx,
+            dtype: None,
+            chunks: vec![],
+            row_count: 0,
+            compression: Compression::None,
+            finished: false,
+        })
+    }
+
+    /// Compress the buffers of subsequently written chunks, see [`Compression`].
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Append the array to the file. Each chunk of a chunked array is written as its own chunk.
+    pub fn write_array(&mut self, array: &Array) -> VortexResult<()> {
+        match ChunkedArray::try_from(array) {
+            Ok(chunked) => {
+                for chunk in chunked.chunks() {
+                    self.write_batch(&chunk)?;
+                }
+                Ok(())
+            }
+            Err(_) => self.write_batch(array),
+        }
+    }
+
+    /// Append a single chunk to the file.
+    pub fn write_batch(&mut self, array: &Array) -> VortexResult<()> {
+        if self.finished {
+            vortex_bail!("Cannot write to a finished Vortex file")
+        }
+        match &self.dtype {
+            None => self.dtype = Some(array.dtype().clone()),
+            Some(dtype) => {
+                if dtype != array.dtype() {
+                    vortex_bail!(MismatchedTypes: dtype, array.dtype());
+                }
+            }
+        }
+
+        self.chunks.push(ChunkLocation::new(
+            self.write.bytes_written,
+            self.row_count,
+            array.len() as u64,
+        ));
+        self.row_count += array.len() as u64;
+        write_chunk(&mut self.write, &self.ctx, array, self.compression)
+    }
+
+    /// Write the footer and trailer, completing the file.
+    pub fn finish(&mut self) -> VortexResult<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        let Some(dtype) = &self.dtype else {
+            vortex_bail!("Cannot finish a Vortex file without any arrays")
+        };
+
+        let footer_offset = self.write.bytes_written;
+        self.write.write_message(
+            &IPCMessage::Footer(IPCFooter(dtype, &self.chunks)),
+            ALIGNMENT,
+        )?;
+
+        self.write.write_all(&footer_offset.to_le_bytes())?;
+        self.write.write_all(&FILE_VERSION.to_le_bytes())?;
+        self.write.write_all(&MAGIC_BYTES)?;
+        self.write.flush()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for FileWriter<W> {
+    fn drop(&mut self) {
+        if let Err(err) = self.finish() {
+            error!("Error finishing FileWriter in destructor: {:?}", err);
+        }
+    }
+}
+
+/// Keeps track of the number of bytes written so we can record chunk offsets.
+struct CountingWrite<W: Write> {
+    write: W,
+    bytes_written: u64,
+}
+
+impl<W: Write> Write for CountingWrite<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.write.write(buf)?;
+        self.bytes_written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write.flush()
+    }
+}
@@ -20,11 +20,21 @@ pub mod flatbuffers {
         pub mod dtype {
             pub use vortex_dtype::flatbuffers as dtype;
         }
+        pub mod scalar {
+            #[allow(unused_imports)]
+            pub use vortex_scalar::flatbuffers::scalar;
+        }
     }
 }
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
+pub mod compression;
+pub mod file;
 pub mod iter;
 mod messages;
+pub mod predicate;
+pub mod projection;
 pub mod reader;
 pub mod writer;
 
@@ -38,9 +48,11 @@ mod tests {
 
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::r#struct::StructArray;
+    use vortex::stats::{ArrayStatistics, Stat};
     use vortex::validity::Validity;
     use vortex::Context;
     use vortex::{IntoArray, IntoArrayData};
+    use vortex_scalar::Scalar;
 
     use crate::iter::FallibleLendingIterator;
     use crate::reader::StreamReader;
@@ -92,4 +104,26 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_write_read_stats() {
+        let array = PrimitiveArray::from(vec![5i32, 1, 3]).into_array();
+        assert_eq!(array.statistics().compute_min::<i32>().unwrap(), 1);
+        assert!(!array.statistics().compute_is_sorted().unwrap());
+
+        let ctx = Context::default();
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut writer = StreamWriter::try_new_unbuffered(&mut cursor, &ctx).unwrap();
+            writer.write_array(&array).unwrap();
+        }
+        cursor.set_position(0);
+
+        let mut ipc_reader = StreamReader::try_new_unbuffered(cursor, &ctx).unwrap();
+        let mut array_reader = ipc_reader.next().unwrap().unwrap();
+        let chunk = array_reader.next().unwrap().unwrap();
+        assert_eq!(chunk.statistics().get(Stat::Min), Some(Scalar::from(1i32)));
+        assert_eq!(chunk.statistics().get(Stat::Max), Some(Scalar::from(5i32)));
+        assert_eq!(chunk.statistics().get(Stat::IsSorted), Some(false.into()));
+    }
 }
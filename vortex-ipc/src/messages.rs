@@ -7,19 +7,25 @@ use vortex_error::{vortex_err, VortexError};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
 use crate::flatbuffers::ipc as fb;
-use crate::flatbuffers::ipc::Compression;
 use crate::{missing, ALIGNMENT};
 
 pub(crate) enum IPCMessage<'a> {
     Context(IPCContext<'a>),
     Schema(IPCSchema<'a>),
     Chunk(IPCChunk<'a>),
+    Footer(IPCFooter<'a>),
 }
 
 pub(crate) struct IPCContext<'a>(pub &'a ViewContext);
 pub(crate) struct IPCSchema<'a>(pub &'a DType);
-pub(crate) struct IPCChunk<'a>(pub &'a ViewContext, pub &'a ArrayData);
+/// A chunk of array data, along with the compression and length of each buffer as written.
+pub(crate) struct IPCChunk<'a>(
+    pub &'a ViewContext,
+    pub &'a ArrayData,
+    pub &'a [(fb::Compression, usize)],
+);
 pub(crate) struct IPCArray<'a>(pub &'a ViewContext, pub &'a ArrayData);
+pub(crate) struct IPCFooter<'a>(pub &'a DType, pub &'a [fb::ChunkLocation]);
 
 impl FlatBufferRoot for IPCMessage<'_> {}
 impl WriteFlatBuffer for IPCMessage<'_> {
@@ -33,6 +39,7 @@ impl WriteFlatBuffer for IPCMessage<'_> {
             Self::Context(f) => f.write_flatbuffer(fbb).as_union_value(),
             Self::Schema(f) => f.write_flatbuffer(fbb).as_union_value(),
             Self::Chunk(f) => f.write_flatbuffer(fbb).as_union_value(),
+            Self::Footer(f) => f.write_flatbuffer(fbb).as_union_value(),
         };
 
         let mut msg = fb::MessageBuilder::new(fbb);
@@ -41,6 +48,7 @@ impl WriteFlatBuffer for IPCMessage<'_> {
             Self::Context(_) => fb::MessageHeader::Context,
             Self::Schema(_) => fb::MessageHeader::Schema,
             Self::Chunk(_) => fb::MessageHeader::Chunk,
+            Self::Footer(_) => fb::MessageHeader::Footer,
         });
         msg.add_header(header);
         msg.finish()
@@ -126,19 +134,13 @@ impl<'a> WriteFlatBuffer for IPCChunk<'a> {
         let array_data = self.1;
         let array = Some(IPCArray(self.0, array_data).write_flatbuffer(fbb));
 
-        // Walk the ColumnData depth-first to compute the buffer offsets.
+        // Compute the buffer offsets from the (possibly compressed) length of each buffer.
         let mut buffers = vec![];
         let mut offset = 0;
-        for array_data in array_data.depth_first_traversal() {
-            if let Some(buffer) = array_data.buffer() {
-                buffers.push(fb::Buffer::new(
-                    offset as u64,
-                    buffer.len() as u64,
-                    Compression::None,
-                ));
-                let aligned_size = (buffer.len() + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
-                offset += aligned_size;
-            }
+        for &(compression, length) in self.2 {
+            buffers.push(fb::Buffer::new(offset as u64, length as u64, compression));
+            let aligned_size = (length + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
+            offset += aligned_size;
         }
         let buffers = Some(fbb.create_vector(&buffers));
 
@@ -153,6 +155,19 @@ impl<'a> WriteFlatBuffer for IPCChunk<'a> {
     }
 }
 
+impl<'a> WriteFlatBuffer for IPCFooter<'a> {
+    type Target<'t> = fb::Footer<'t>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        let dtype = Some(self.0.write_flatbuffer(fbb));
+        let chunks = Some(fbb.create_vector(self.1));
+        fb::Footer::create(fbb, &fb::FooterArgs { dtype, chunks })
+    }
+}
+
 impl<'a> WriteFlatBuffer for IPCArray<'a> {
     type Target<'t> = fba::Array<'t>;
 
@@ -186,6 +201,8 @@ impl<'a> WriteFlatBuffer for IPCArray<'a> {
             .collect_vec();
         let children = Some(fbb.create_vector(&children));
 
+        let stats = Some(column_data.statistics().to_set().write_flatbuffer(fbb));
+
         fba::Array::create(
             fbb,
             &fba::ArrayArgs {
@@ -194,6 +211,7 @@ impl<'a> WriteFlatBuffer for IPCArray<'a> {
                 encoding,
                 metadata,
                 children,
+                stats,
             },
         )
     }
@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::mem;
+
+use vortex::array::r#struct::Struct;
+use vortex::flatbuffers::array as fb;
+use vortex::stats::{Stat, StatsSet};
+use vortex::{ArrayDef, ViewContext};
+use vortex_dtype::{DType, PType};
+use vortex_error::VortexResult;
+use vortex_flatbuffers::ReadFlatBuffer;
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// A predicate over the values of an array, used to prune chunks of an IPC stream.
+///
+/// Predicates are evaluated against the statistics stored with each chunk (min, max and
+/// null_count), so a chunk is only skipped when it provably contains no matching rows.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// Compare each value against a scalar, e.g. `value < 10`.
+    Compare(Operator, Scalar),
+    IsNull,
+    IsNotNull,
+    /// Apply the predicate to the named field of a struct array.
+    Field(String, Box<Predicate>),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+}
+
+impl Predicate {
+    pub fn eq<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::Eq, value.into())
+    }
+
+    pub fn not_eq<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::NotEq, value.into())
+    }
+
+    pub fn gt<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::Gt, value.into())
+    }
+
+    pub fn gte<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::Gte, value.into())
+    }
+
+    pub fn lt<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::Lt, value.into())
+    }
+
+    pub fn lte<S: Into<Scalar>>(value: S) -> Self {
+        Self::Compare(Operator::Lte, value.into())
+    }
+
+    pub fn field(name: &str, predicate: Predicate) -> Self {
+        Self::Field(name.to_string(), Box::new(predicate))
+    }
+
+    pub fn and(self, other: Predicate) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Predicate) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Returns true if the statistics of the serialized array prove that no value can match.
+    pub(crate) fn can_prune(
+        &self,
+        ctx: &ViewContext,
+        dtype: &DType,
+        array: fb::Array,
+    ) -> VortexResult<bool> {
+        match self {
+            Predicate::And(lhs, rhs) => {
+                Ok(lhs.can_prune(ctx, dtype, array)? || rhs.can_prune(ctx, dtype, array)?)
+            }
+            Predicate::Or(lhs, rhs) => {
+                Ok(lhs.can_prune(ctx, dtype, array)? && rhs.can_prune(ctx, dtype, array)?)
+            }
+            Predicate::Field(name, predicate) => {
+                let DType::Struct(st, _) = dtype else {
+                    return Ok(false);
+                };
+                let is_struct = ctx
+                    .find_encoding(array.encoding())
+                    .map(|e| e.id() == Struct::ID)
+                    .unwrap_or(false);
+                let Some(idx) = st.names().iter().position(|n| n.as_ref() == name) else {
+                    return Ok(false);
+                };
+                match array.children().filter(|_| is_struct) {
+                    Some(children) if idx < children.len() => {
+                        predicate.can_prune(ctx, &st.dtypes()[idx], children.get(idx))
+                    }
+                    _ => Ok(false),
+                }
+            }
+            _ => {
+                let stats = array
+                    .stats()
+                    .map(|stats| StatsSet::read_flatbuffer(&stats))
+                    .transpose()?
+                    .unwrap_or_default();
+                Ok(self.can_prune_stats(&stats))
+            }
+        }
+    }
+
+    fn can_prune_stats(&self, stats: &StatsSet) -> bool {
+        let min = stats.get(Stat::Min);
+        let max = stats.get(Stat::Max);
+        // Min and max are only null if every value in the array is null.
+        let all_null = min.map(|m| m.is_null()).unwrap_or(false);
+
+        match self {
+            Predicate::IsNull => stats
+                .get(Stat::NullCount)
+                .and_then(|n| usize::try_from(n).ok())
+                .map(|n| n == 0)
+                .unwrap_or(false),
+            Predicate::IsNotNull => all_null,
+            Predicate::Compare(op, value) => {
+                // Comparisons against null never match.
+                if all_null || value.is_null() {
+                    return true;
+                }
+                let (Some(min), Some(max)) = (min, max) else {
+                    return false;
+                };
+                let Some(value) = coerce(value, min) else {
+                    return false;
+                };
+                let (Some(vs_min), Some(vs_max)) = (value.partial_cmp(min), value.partial_cmp(max))
+                else {
+                    return false;
+                };
+                match op {
+                    Operator::Eq => vs_min == Ordering::Less || vs_max == Ordering::Greater,
+                    Operator::NotEq => vs_min == Ordering::Equal && vs_max == Ordering::Equal,
+                    Operator::Gt => vs_max != Ordering::Less,
+                    Operator::Gte => vs_max == Ordering::Greater,
+                    Operator::Lt => vs_min != Ordering::Greater,
+                    Operator::Lte => vs_min == Ordering::Less,
+                }
+            }
+            Predicate::Field(..) | Predicate::And(..) | Predicate::Or(..) => {
+                unreachable!("Handled by can_prune")
+            }
+        }
+    }
+}
+
+/// Convert the value into the type of the statistic, if it can be done without losing precision.
+fn coerce(value: &Scalar, target: &Scalar) -> Option<Scalar> {
+    match (value, target) {
+        (Scalar::Primitive(v), Scalar::Primitive(t)) => {
+            let cast = v.value()?.cast_ptype(t.ptype()).ok()?;
+            let Scalar::Primitive(c) = &cast else {
+                return None;
+            };
+            let Scalar::Primitive(round_trip) = c.value()?.cast_ptype(v.ptype()).ok()? else {
+                return None;
+            };
+            let lossless = round_trip.value() == v.value() && is_negative(v)? == is_negative(c)?;
+            lossless.then_some(cast)
+        }
+        _ => (mem::discriminant(value) == mem::discriminant(target)).then(|| value.clone()),
+    }
+}
+
+fn is_negative(scalar: &PrimitiveScalar) -> Option<bool> {
+    let value = f64::try_from(scalar.value()?.cast_ptype(PType::F64).ok()?).ok()?;
+    Some(value < 0.0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use fallible_iterator::FallibleIterator;
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::r#struct::StructArray;
+    use vortex::stats::{Stat, StatsSet};
+    use vortex::validity::Validity;
+    use vortex::{ArrayDType, Context, IntoArray, OwnedArray, ToStatic};
+    use vortex_scalar::Scalar;
+
+    use crate::iter::FallibleLendingIterator;
+    use crate::predicate::Predicate;
+    use crate::reader::StreamReader;
+    use crate::writer::StreamWriter;
+
+    fn stats(min: i32, max: i32, null_count: usize) -> StatsSet {
+        let mut stats = StatsSet::new();
+        stats.set(Stat::Min, min.into());
+        stats.set(Stat::Max, max.into());
+        stats.set(Stat::NullCount, null_count.into());
+        stats
+    }
+
+    #[test]
+    fn prune_comparisons() {
+        let stats = stats(10, 20, 0);
+        assert!(Predicate::eq(5).can_prune_stats(&stats));
+        assert!(!Predicate::eq(15).can_prune_stats(&stats));
+        assert!(Predicate::lt(10).can_prune_stats(&stats));
+        assert!(!Predicate::lte(10).can_prune_stats(&stats));
+        assert!(Predicate::gt(20).can_prune_stats(&stats));
+        assert!(!Predicate::gte(20).can_prune_stats(&stats));
+        assert!(!Predicate::not_eq(10).can_prune_stats(&stats));
+        assert!(Predicate::not_eq(10).can_prune_stats(&self::stats(10, 10, 0)));
+    }
+
+    #[test]
+    fn prune_nulls() {
+        assert!(Predicate::IsNull.can_prune_stats(&stats(10, 20, 0)));
+        assert!(!Predicate::IsNull.can_prune_stats(&stats(10, 20, 1)));
+        assert!(!Predicate::IsNull.can_prune_stats(&StatsSet::new()));
+
+        let mut all_null = StatsSet::new();
+        all_null.set(Stat::Min, Option::<i32>::None.into());
+        all_null.set(Stat::Max, Option::<i32>::None.into());
+        assert!(Predicate::IsNotNull.can_prune_stats(&all_null));
+        assert!(Predicate::eq(1).can_prune_stats(&all_null));
+    }
+
+    #[test]
+    fn coerce_values() {
+        let stats = stats(10, 20, 0);
+        assert!(Predicate::gt(20i64).can_prune_stats(&stats));
+        assert!(Predicate::eq(5u8).can_prune_stats(&stats));
+        // 10.5 cannot be represented as an i32, so we must not prune.
+        assert!(!Predicate::lt(10.5f64).can_prune_stats(&stats));
+        // u64::MAX wraps around to -1 as an i32.
+        assert!(!Predicate::lt(u64::MAX).can_prune_stats(&self::stats(-5, 20, 0)));
+        assert!(!Predicate::eq(Scalar::from("a")).can_prune_stats(&stats));
+    }
+
+    fn write_chunks(chunks: Vec<OwnedArray>) -> Vec<u8> {
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        let mut buffer = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new(Cursor::new(&mut buffer), &Context::default()).unwrap();
+            writer.write_array(&array).unwrap();
+        }
+        buffer
+    }
+
+    fn filter_first_values(buffer: &[u8], predicate: &Predicate) -> Vec<i32> {
+        let mut reader = StreamReader::try_new(Cursor::new(buffer), &Context::default()).unwrap();
+        let array_reader = reader.next().unwrap().unwrap();
+        array_reader
+            .filter(predicate)
+            .unwrap()
+            .map(|chunk| {
+                let chunk = match StructArray::try_from(&chunk) {
+                    Ok(st) => st.field(0).unwrap().to_static(),
+                    Err(_) => chunk,
+                };
+                Ok(chunk.flatten_primitive()?.typed_data::<i32>()[0])
+            })
+            .collect()
+            .unwrap()
+    }
+
+    #[test]
+    fn filter_chunks() {
+        let buffer = write_chunks(
+            (0..4)
+                .map(|c| PrimitiveArray::from((c * 10..(c + 1) * 10).collect_vec()).into_array())
+                .collect(),
+        );
+
+        assert_eq!(filter_first_values(&buffer, &Predicate::eq(25)), vec![20]);
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::gte(15)),
+            vec![10, 20, 30]
+        );
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::lt(5).or(Predicate::gt(35))),
+            vec![0, 30]
+        );
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::gt(5).and(Predicate::lt(10))),
+            vec![0]
+        );
+        assert!(filter_first_values(&buffer, &Predicate::IsNull).is_empty());
+    }
+
+    #[test]
+    fn filter_struct_field() {
+        let buffer = write_chunks(
+            (0..3)
+                .map(|c| {
+                    StructArray::try_new(
+                        ["a".into(), "b".into()].into(),
+                        vec![
+                            PrimitiveArray::from((c * 10..(c + 1) * 10).collect_vec()).into_array(),
+                            PrimitiveArray::from(vec![c; 10]).into_array(),
+                        ],
+                        10,
+                        Validity::NonNullable,
+                    )
+                    .unwrap()
+                    .into_array()
+                })
+                .collect(),
+        );
+
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::field("a", Predicate::gte(20))),
+            vec![20]
+        );
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::field("b", Predicate::not_eq(1))),
+            vec![0, 20]
+        );
+        assert_eq!(
+            filter_first_values(&buffer, &Predicate::field("c", Predicate::eq(1))),
+            vec![0, 10, 20]
+        );
+    }
+}
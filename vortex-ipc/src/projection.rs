@@ -0,0 +1,265 @@
+use itertools::Itertools;
+use vortex::array::r#struct::StructArray;
+use vortex::flatbuffers::array as fb;
+use vortex::{Array, ArrayTrait, IntoArray, OwnedArray, ToStatic};
+use vortex_dtype::{DType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+/// A reference to a field of a struct array, either by name or by position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for Field {
+    fn from(value: &str) -> Self {
+        Field::Name(value.to_string())
+    }
+}
+
+impl From<String> for Field {
+    fn from(value: String) -> Self {
+        Field::Name(value)
+    }
+}
+
+impl From<usize> for Field {
+    fn from(value: usize) -> Self {
+        Field::Index(value)
+    }
+}
+
+/// The fields of a struct array that should be read from an IPC stream.
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    indices: Vec<usize>,
+    nfields: usize,
+    dtype: DType,
+}
+
+impl Projection {
+    pub fn try_new(dtype: &DType, fields: &[Field]) -> VortexResult<Self> {
+        let DType::Struct(st, nullability) = dtype else {
+            vortex_bail!("Can only project struct arrays, found {}", dtype)
+        };
+
+        let indices: Vec<usize> = fields
+            .iter()
+            .map(|field| match field {
+                Field::Name(name) => st
+                    .names()
+                    .iter()
+                    .position(|n| n.as_ref() == name)
+                    .ok_or_else(|| vortex_err!("Unknown field {} in {}", name, dtype)),
+                Field::Index(idx) => {
+                    if *idx >= st.dtypes().len() {
+                        vortex_bail!(OutOfBounds: *idx, 0, st.dtypes().len())
+                    }
+                    Ok(*idx)
+                }
+            })
+            .try_collect()?;
+
+        let projected = StructDType::new(
+            indices.iter().map(|&i| st.names()[i].clone()).collect(),
+            indices.iter().map(|&i| st.dtypes()[i].clone()).collect(),
+        );
+        Ok(Self {
+            indices,
+            nfields: st.dtypes().len(),
+            dtype: DType::Struct(projected, *nullability),
+        })
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// Which of the buffers of the serialized struct array need to be read.
+    ///
+    /// Buffers are laid out in depth-first order, so the buffers of each child are contiguous.
+    /// The struct's own validity is stored after its fields and is always selected.
+    pub fn buffer_selection(&self, array: fb::Array) -> VortexResult<Vec<bool>> {
+        let nchildren = array.children().map(|c| c.len()).unwrap_or(0);
+        if nchildren < self.nfields {
+            vortex_bail!(
+                InvalidSerde: "Struct chunk has {} children but the schema has {} fields",
+                nchildren,
+                self.nfields
+            )
+        }
+
+        let mut selection = vec![];
+        if array.has_buffer() {
+            selection.push(true);
+        }
+        for (idx, child) in array.children().unwrap_or_default().iter().enumerate() {
+            let selected = idx >= self.nfields || self.indices.contains(&idx);
+            selection.extend(std::iter::repeat(selected).take(cumulative_nbuffers(child)));
+        }
+        Ok(selection)
+    }
+
+    /// Build a struct array holding only the projected fields.
+    pub fn project(&self, array: Array) -> VortexResult<OwnedArray> {
+        let st = StructArray::try_from(&array)?;
+        let fields = self
+            .indices
+            .iter()
+            .map(|&idx| {
+                st.field(idx)
+                    .map(|f| f.to_static())
+                    .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, st.nfields()))
+            })
+            .try_collect()?;
+        let names = self
+            .indices
+            .iter()
+            .map(|&idx| {
+                st.names()
+                    .get(idx)
+                    .cloned()
+                    .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, st.names().len()))
+            })
+            .try_collect()?;
+        StructArray::try_new(names, fields, st.len(), st.validity().to_static())
+            .map(|a| a.into_array())
+    }
+}
+
+/// The number of buffers used by the serialized array and all of its children.
+fn cumulative_nbuffers(array: fb::Array) -> usize {
+    let mut nbuffers = if array.has_buffer() { 1 } else { 0 };
+    for child in array.children().unwrap_or_default() {
+        nbuffers += cumulative_nbuffers(child)
+    }
+    nbuffers
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::r#struct::StructArray;
+    use vortex::validity::Validity;
+    use vortex::{ArrayDType, Context, IntoArray, OwnedArray, ToStatic};
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::iter::FallibleLendingIterator;
+    use crate::projection::Field;
+    use crate::reader::StreamReader;
+    use crate::writer::StreamWriter;
+
+    fn struct_chunk(offset: i32) -> OwnedArray {
+        StructArray::try_new(
+            ["a".into(), "b".into(), "c".into()].into(),
+            vec![
+                PrimitiveArray::from((offset..offset + 5).collect_vec()).into_array(),
+                PrimitiveArray::from(vec![1.5f64; 5]).into_array(),
+                PrimitiveArray::from((offset..offset + 5).map(|v| v as i64 * 2).collect_vec())
+                    .into_array(),
+            ],
+            5,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn write_struct_chunks() -> Vec<u8> {
+        let chunks = vec![struct_chunk(0), struct_chunk(5)];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        let mut buffer = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new(Cursor::new(&mut buffer), &Context::default()).unwrap();
+            writer.write_array(&array).unwrap();
+            writer
+                .write_array(&PrimitiveArray::from(vec![1, 2, 3]).into_array())
+                .unwrap();
+        }
+        buffer
+    }
+
+    #[test]
+    fn read_projection() {
+        let buffer = write_struct_chunks();
+        let mut reader = StreamReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        let mut array_reader = reader
+            .next()
+            .unwrap()
+            .unwrap()
+            .with_projection(&["c".into(), Field::Index(0)])
+            .unwrap();
+
+        let DType::Struct(st, _) = array_reader.dtype() else {
+            panic!("Expected a struct dtype")
+        };
+        assert_eq!(
+            st.names().iter().map(|n| n.as_ref()).collect_vec(),
+            ["c", "a"]
+        );
+        assert_eq!(
+            st.dtypes().as_ref(),
+            [
+                DType::Primitive(PType::I64, Nullability::NonNullable),
+                DType::Primitive(PType::I32, Nullability::NonNullable)
+            ]
+        );
+
+        let mut offset = 0;
+        while let Some(chunk) = array_reader.next().unwrap() {
+            let st = StructArray::try_from(chunk.to_static()).unwrap();
+            assert_eq!(st.nfields(), 2);
+            assert_eq!(
+                st.field(0)
+                    .unwrap()
+                    .flatten_primitive()
+                    .unwrap()
+                    .typed_data::<i64>(),
+                (offset..offset + 5).map(|v| v as i64 * 2).collect_vec()
+            );
+            assert_eq!(
+                st.field(1)
+                    .unwrap()
+                    .flatten_primitive()
+                    .unwrap()
+                    .typed_data::<i32>(),
+                (offset..offset + 5).collect_vec()
+            );
+            offset += 5;
+        }
+        assert_eq!(offset, 10);
+        drop(array_reader);
+
+        // The stream is left in a consistent state for the next array.
+        let next = reader.read_array().unwrap();
+        assert_eq!(
+            next.flatten_primitive().unwrap().typed_data::<i32>(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn invalid_projection() {
+        let buffer = write_struct_chunks();
+        let mut reader = StreamReader::try_new(Cursor::new(&buffer), &Context::default()).unwrap();
+        assert!(reader
+            .next()
+            .unwrap()
+            .unwrap()
+            .with_projection(&["d".into()])
+            .is_err());
+        assert!(reader
+            .next()
+            .unwrap()
+            .unwrap()
+            .with_projection(&[Field::Index(0)])
+            .is_err());
+    }
+}
@@ -22,9 +22,13 @@ use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::ReadFlatBuffer;
 use vortex_scalar::Scalar;
 
-use crate::flatbuffers::ipc::Message;
+use crate::compression::decompress;
+use crate::flatbuffers::ipc::{Chunk, Message};
 use crate::iter::{FallibleLendingIterator, FallibleLendingIteratorඞItem};
 use crate::messages::SerdeContextDeserializer;
+use crate::missing;
+use crate::predicate::Predicate;
+use crate::projection::{Field, Projection};
 
 pub struct StreamReader<R: Read> {
     read: R,
@@ -116,6 +120,7 @@ impl<R: Read> FallibleLendingIterator for StreamReader<R> {
             read: &mut self.read,
             messages: &mut self.messages,
             dtype,
+            projection: None,
             buffers: vec![],
             row_offset: 0,
         }))
@@ -128,13 +133,30 @@ pub struct StreamArrayReader<'a, R: Read> {
     read: &'a mut R,
     messages: &'a mut StreamMessageReader<R>,
     dtype: DType,
+    projection: Option<Projection>,
     buffers: Vec<Buffer>,
     row_offset: usize,
 }
 
 impl<'a, R: Read> StreamArrayReader<'a, R> {
+    /// The DType of the arrays returned by the reader, taking into account any projection.
     pub fn dtype(&self) -> &DType {
-        &self.dtype
+        self.projection
+            .as_ref()
+            .map(|p| p.dtype())
+            .unwrap_or(&self.dtype)
+    }
+
+    /// Only read the given fields of a struct array, skipping over the buffers of all others.
+    ///
+    /// Each chunk is returned as a struct array containing just the selected fields, in the
+    /// order they were requested.
+    pub fn with_projection(mut self, fields: &[Field]) -> VortexResult<Self> {
+        if self.row_offset != 0 {
+            vortex_bail!("Stream has already been (at least partially) consumed")
+        }
+        self.projection = Some(Projection::try_new(&self.dtype, fields)?);
+        Ok(self)
     }
 
     pub fn take(self, indices: &'a Array<'_>) -> VortexResult<TakeIterator<'a, R>> {
@@ -170,6 +192,66 @@ impl<'a, R: Read> StreamArrayReader<'a, R> {
     }
 }
 
+impl<'a, R: Read> StreamArrayReader<'a, R> {
+    /// Returns an iterator over the chunks that may contain values matching the predicate.
+    ///
+    /// Chunks whose statistics prove that no value can match are skipped without reading their
+    /// buffers. The returned chunks are not themselves filtered.
+    pub fn filter(self, predicate: &'a Predicate) -> VortexResult<FilterIterator<'a, R>> {
+        if self.row_offset != 0 {
+            vortex_bail!("Stream has already been (at least partially) consumed")
+        }
+
+        Ok(FilterIterator {
+            reader: self,
+            predicate,
+        })
+    }
+
+    /// Skip over the next chunk in the stream without reading its buffers.
+    fn skip_chunk(&mut self) -> VortexResult<()> {
+        let Some(chunk_msg) = self.messages.peek().and_then(|msg| msg.header_as_chunk()) else {
+            return Ok(());
+        };
+        self.read.skip(chunk_msg.buffer_size())?;
+        self.messages.next(self.read)?;
+        Ok(())
+    }
+}
+
+pub struct FilterIterator<'a, R: Read> {
+    reader: StreamArrayReader<'a, R>,
+    predicate: &'a Predicate,
+}
+
+impl<'a, R: Read> FallibleIterator for FilterIterator<'a, R> {
+    type Item = OwnedArray;
+    type Error = VortexError;
+
+    fn next(&mut self) -> VortexResult<Option<Self::Item>> {
+        loop {
+            let Some(chunk_msg) = self
+                .reader
+                .messages
+                .peek()
+                .and_then(|msg| msg.header_as_chunk())
+            else {
+                return Ok(None);
+            };
+
+            let prune = self.predicate.can_prune(
+                self.reader.ctx,
+                &self.reader.dtype,
+                chunk_msg.array().ok_or_else(missing("array"))?,
+            )?;
+            if !prune {
+                return Ok(self.reader.next()?.map(|chunk| chunk.to_static()));
+            }
+            self.reader.skip_chunk()?;
+        }
+    }
+}
+
 pub struct TakeIterator<'a, R: Read> {
     reader: StreamArrayReader<'a, R>,
     indices: &'a Array<'a>,
@@ -252,25 +334,16 @@ impl<'iter, R: Read> FallibleLendingIterator for StreamArrayReader<'iter, R> {
             return Ok(None);
         };
 
-        // Read all the column's buffers
-        self.buffers.clear();
-        let mut offset = 0;
-        for buffer in chunk_msg.buffers().unwrap_or_default().iter() {
-            let _skip = buffer.offset() - offset;
-            self.read.skip(buffer.offset() - offset)?;
-
-            // TODO(ngates): read into a single buffer, then Arc::clone and slice
-            let mut bytes = Vec::with_capacity(buffer.length() as usize);
-            self.read.read_into(buffer.length(), &mut bytes)?;
-            let arrow_buffer = ArrowBuffer::from_vec(bytes);
-            self.buffers.push(Buffer::from(arrow_buffer));
-
-            offset = buffer.offset() + buffer.length();
+        // Read all the column's buffers, or only those of the projected fields
+        match &self.projection {
+            None => read_chunk_buffers(self.read, chunk_msg, &mut self.buffers)?,
+            Some(projection) => {
+                let selection =
+                    projection.buffer_selection(chunk_msg.array().ok_or_else(missing("array"))?)?;
+                read_selected_buffers(self.read, chunk_msg, &selection, &mut self.buffers)?
+            }
         }
 
-        // Consume any remaining padding after the final buffer.
-        self.read.skip(chunk_msg.buffer_size() - offset)?;
-
         // After reading the buffers we're now able to load the next message.
         let col_array = self
             .messages
@@ -284,12 +357,67 @@ impl<'iter, R: Read> FallibleLendingIterator for StreamArrayReader<'iter, R> {
         // Validate it
         view.to_array().with_dyn(|_| Ok::<(), VortexError>(()))?;
 
-        let array = view.into_array();
+        let array = match &self.projection {
+            None => view.into_array(),
+            Some(projection) => projection.project(view.into_array())?,
+        };
         self.row_offset += array.len();
         Ok(Some(array))
     }
 }
 
+/// Read the buffers that follow the given Chunk message, including any trailing padding.
+pub(crate) fn read_chunk_buffers<R: Read>(
+    read: &mut R,
+    chunk_msg: Chunk,
+    buffers: &mut Vec<Buffer>,
+) -> VortexResult<()> {
+    let selection = vec![true; chunk_msg.buffers().map(|b| b.len()).unwrap_or(0)];
+    read_selected_buffers(read, chunk_msg, &selection, buffers)
+}
+
+/// Read only the selected buffers that follow the given Chunk message, skipping over the rest.
+///
+/// Skipped buffers are replaced with empty buffers so that buffer indices are preserved.
+pub(crate) fn read_selected_buffers<R: Read>(
+    read: &mut R,
+    chunk_msg: Chunk,
+    selection: &[bool],
+    buffers: &mut Vec<Buffer>,
+) -> VortexResult<()> {
+    let chunk_buffers = chunk_msg.buffers().unwrap_or_default();
+    if chunk_buffers.len() != selection.len() {
+        vortex_bail!(
+            InvalidSerde: "Chunk has {} buffers but its array needs {}",
+            chunk_buffers.len(),
+            selection.len()
+        )
+    }
+
+    buffers.clear();
+    let mut offset = 0;
+    for (buffer, &selected) in chunk_buffers.iter().zip(selection) {
+        if !selected {
+            buffers.push(Buffer::from(Vec::new()));
+            continue;
+        }
+        read.skip(buffer.offset() - offset)?;
+
+        // TODO(ngates): read into a single buffer, then Arc::clone and slice
+        let mut bytes = Vec::with_capacity(buffer.length() as usize);
+        read.read_into(buffer.length(), &mut bytes)?;
+        let bytes = decompress(buffer.compression(), bytes)?;
+        let arrow_buffer = ArrowBuffer::from_vec(bytes);
+        buffers.push(Buffer::from(arrow_buffer));
+
+        offset = buffer.offset() + buffer.length();
+    }
+
+    // Consume any remaining padding after the final buffer.
+    read.skip(chunk_msg.buffer_size() - offset)?;
+    Ok(())
+}
+
 pub trait ReadExtensions: Read {
     /// Skip n bytes in the stream.
     fn skip(&mut self, nbytes: u64) -> io::Result<()> {
@@ -309,7 +437,7 @@ pub trait ReadExtensions: Read {
 
 impl<R: Read> ReadExtensions for R {}
 
-struct StreamMessageReader<R: Read> {
+pub(crate) struct StreamMessageReader<R: Read> {
     message: Vec<u8>,
     prev_message: Vec<u8>,
     finished: bool,
@@ -324,7 +452,7 @@ impl<R: Read> StreamMessageReader<R> {
             finished: false,
             phantom: PhantomData,
         };
-        reader.load_next_message(read)?;
+        reader.finished = !reader.load_next_message(read)?;
         Ok(reader)
     }
 
@@ -2,11 +2,14 @@ use std::io::{BufWriter, Write};
 
 use itertools::Itertools;
 use vortex::array::chunked::ChunkedArray;
-use vortex::{Array, ArrayDType, Context, ToArrayData, ViewContext};
+use vortex::array::r#struct::StructArray;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, Context, ToArray, ToArrayData, ViewContext};
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 use vortex_flatbuffers::FlatBufferWriter;
 
+use crate::compression::Compression;
 use crate::messages::{IPCChunk, IPCContext, IPCMessage, IPCSchema};
 use crate::ALIGNMENT;
 
@@ -14,6 +17,7 @@ use crate::ALIGNMENT;
 pub struct StreamWriter<W: Write> {
     write: W,
     ctx: ViewContext,
+    compression: Compression,
 }
 
 impl<W: Write> StreamWriter<BufWriter<W>> {
@@ -31,9 +35,16 @@ impl<W: Write> StreamWriter<W> {
         Ok(Self {
             write,
             ctx: view_ctx,
+            compression: Compression::None,
         })
     }
 
+    /// Compress the buffers of subsequently written chunks, see [`Compression`].
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     pub fn write_array(&mut self, array: &Array) -> VortexResult<()> {
         self.write_schema(array.dtype())?;
         match ChunkedArray::try_from(array) {
@@ -54,29 +65,55 @@ impl<W: Write> StreamWriter<W> {
     }
 
     pub fn write_batch(&mut self, array: &Array) -> VortexResult<()> {
-        // TODO(ngates): support writing from an ArrayView.
-        let data = array.to_array_data();
-        let buffer_offsets = data.all_buffer_offsets(ALIGNMENT);
-
-        // Serialize the Chunk message.
-        self.write
-            .write_message(&IPCMessage::Chunk(IPCChunk(&self.ctx, &data)), ALIGNMENT)?;
-
-        // Keep track of the offset to add padding after each buffer.
-        let mut current_offset = 0;
-        for (buffer, &buffer_end) in data
-            .depth_first_traversal()
-            .flat_map(|data| data.buffer().into_iter())
-            .zip_eq(buffer_offsets.iter().skip(1))
-        {
-            let buffer_len = buffer.len();
-            self.write.write_all(buffer.as_ref())?;
-            let padding = (buffer_end as usize) - current_offset - buffer_len;
-            self.write.write_all(&vec![0; padding])?;
-            current_offset = buffer_end as usize;
-        }
+        write_chunk(&mut self.write, &self.ctx, array, self.compression)
+    }
+}
+
+/// Write a single Chunk message followed by its (padded) buffers.
+pub(crate) fn write_chunk<W: Write>(
+    write: &mut W,
+    ctx: &ViewContext,
+    array: &Array,
+    compression: Compression,
+) -> VortexResult<()> {
+    // TODO(ngates): support writing from an ArrayView.
+    let data = array.to_array_data();
+    compute_zone_map(&data.to_array());
+
+    let buffers: Vec<_> = data
+        .depth_first_traversal()
+        .flat_map(|data| data.buffer().into_iter())
+        .map(|buffer| compression.compress(buffer.as_ref()))
+        .try_collect()?;
+    let buffer_lengths = buffers
+        .iter()
+        .map(|(compression, bytes)| (*compression, bytes.len()))
+        .collect_vec();
 
-        Ok(())
+    // Serialize the Chunk message.
+    write.write_message(
+        &IPCMessage::Chunk(IPCChunk(ctx, &data, &buffer_lengths)),
+        ALIGNMENT,
+    )?;
+
+    // Write each buffer followed by padding up to the alignment.
+    for (_, bytes) in buffers {
+        write.write_all(bytes.as_ref())?;
+        let padding = ((bytes.len() + (ALIGNMENT - 1)) & !(ALIGNMENT - 1)) - bytes.len();
+        write.write_all(&vec![0; padding])?;
+    }
+
+    Ok(())
+}
+
+/// Compute the statistics that readers use to prune chunks, see [`crate::predicate::Predicate`].
+fn compute_zone_map(array: &Array) {
+    if let Ok(st) = StructArray::try_from(array) {
+        st.children().for_each(|field| compute_zone_map(&field));
+        return;
+    }
+    for stat in [Stat::Min, Stat::Max, Stat::NullCount] {
+        array.statistics().compute(stat);
     }
 }
 
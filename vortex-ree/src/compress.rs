@@ -2,6 +2,7 @@ use std::cmp::min;
 
 use itertools::Itertools;
 use num_traits::{AsPrimitive, FromPrimitive};
+use vortex::array::bool::BoolArray;
 use vortex::array::primitive::{Primitive, PrimitiveArray};
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
 use vortex::stats::{ArrayStatistics, Stat};
@@ -137,10 +138,25 @@ pub fn ree_decode<'a>(
     })
 }
 
-pub fn ree_decode_primitive<
-    E: NativePType + AsPrimitive<usize> + FromPrimitive + Ord,
-    T: NativePType,
->(
+pub fn ree_decode_bool<'a>(
+    ends: &PrimitiveArray,
+    values: &BoolArray,
+    validity: Validity,
+    offset: usize,
+    length: usize,
+) -> VortexResult<BoolArray<'a>> {
+    let values = values.boolean_buffer().iter().collect_vec();
+    match_each_integer_ptype!(ends.ptype(), |$E| {
+        Ok(BoolArray::from_vec(ree_decode_primitive(
+            ends.typed_data::<$E>(),
+            &values,
+            offset,
+            length,
+        ), validity))
+    })
+}
+
+pub fn ree_decode_primitive<E: NativePType + AsPrimitive<usize> + FromPrimitive + Ord, T: Copy>(
     run_ends: &[E],
     values: &[T],
     offset: usize,
@@ -1,16 +1,51 @@
+use vortex::array::bool::BoolArray;
 use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::aggregate::{
+    count_distinct, min_max, sum, weighted_sum, CountDistinctFn, MinMaxFn, SumFn,
+};
+use vortex::compute::arithmetic::{
+    arithmetic_scalar, ArithmeticScalarFn, BinaryOperator, OverflowMode,
+};
+use vortex::compute::cast::{cast, CastFn};
+use vortex::compute::compare::{compare, CompareFn, Operator};
+use vortex::compute::filter::FilterFn;
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray, OwnedArray};
-use vortex_dtype::match_each_integer_ptype;
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayTrait, IntoArray, OwnedArray};
+use vortex_dtype::{match_each_integer_ptype, DType};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::REEArray;
 
 impl ArrayCompute for REEArray<'_> {
+    fn arithmetic_scalar(&self) -> Option<&dyn ArithmeticScalarFn> {
+        Some(self)
+    }
+
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn count_distinct(&self) -> Option<&dyn CountDistinctFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -19,6 +54,10 @@ impl ArrayCompute for REEArray<'_> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -30,6 +69,146 @@ impl ScalarAtFn for REEArray<'_> {
     }
 }
 
+impl ArithmeticScalarFn for REEArray<'_> {
+    fn arithmetic_scalar(
+        &self,
+        other: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<OwnedArray> {
+        // The result has the same runs as this array, so the operation only applies to the values.
+        Ok(REEArray::with_offset_and_size(
+            self.ends(),
+            arithmetic_scalar(&self.values(), other, operator, mode)?,
+            self.validity(),
+            self.len(),
+            self.offset(),
+        )?
+        .into_array())
+    }
+}
+
+impl CastFn for REEArray<'_> {
+    fn cast(&self, dtype: &DType) -> VortexResult<OwnedArray> {
+        // The runs are unchanged, so only the values are cast.
+        let validity = self
+            .validity()
+            .cast_nullability(dtype.nullability(), self.len())?;
+        Ok(REEArray::with_offset_and_size(
+            self.ends(),
+            cast(&self.values(), dtype)?,
+            validity,
+            self.len(),
+            self.offset(),
+        )?
+        .into_array())
+    }
+}
+
+impl SumFn for REEArray<'_> {
+    fn sum(&self) -> VortexResult<Scalar> {
+        // Nulls are held outside of the runs, so only arrays without nulls can be summed by run.
+        if !self.logical_validity().all_valid() {
+            return sum(&self.array().clone().flatten_primitive()?.into_array());
+        }
+        let (values, run_lengths) = logical_runs(self)?;
+        weighted_sum(&values, &run_lengths)
+    }
+}
+
+impl MinMaxFn for REEArray<'_> {
+    fn min_max(&self) -> VortexResult<(Scalar, Scalar)> {
+        if !self.logical_validity().all_valid() {
+            return min_max(&self.array().clone().flatten_primitive()?.into_array());
+        }
+        min_max(&logical_runs(self)?.0)
+    }
+}
+
+impl CountDistinctFn for REEArray<'_> {
+    fn count_distinct(&self) -> VortexResult<usize> {
+        if !self.logical_validity().all_valid() {
+            return count_distinct(&self.array().clone().flatten()?.into_array());
+        }
+        count_distinct(&logical_runs(self)?.0)
+    }
+}
+
+/// The values of the runs that overlap the array, along with how many of its values each covers.
+fn logical_runs(array: &REEArray) -> VortexResult<(OwnedArray, Vec<u64>)> {
+    let first = array.find_physical_index(0)?;
+    let last = array.find_physical_index(array.len() - 1)?;
+    let ends = slice(&array.ends(), first, last + 1)?.flatten_primitive()?;
+    let end = array.offset() + array.len();
+    let mut run_start = array.offset();
+    let run_lengths = match_each_integer_ptype!(ends.ptype(), |$P| {
+        ends.typed_data::<$P>()
+            .iter()
+            .map(|&e| {
+                let run_end = (e as usize).min(end);
+                let length = run_end - run_start;
+                run_start = run_end;
+                length as u64
+            })
+            .collect()
+    });
+    Ok((slice(&array.values(), first, last + 1)?, run_lengths))
+}
+
+impl CompareFn for REEArray<'_> {
+    fn compare(&self, other: &Scalar, operator: Operator) -> VortexResult<OwnedArray> {
+        // The result has the same runs as this array, so only the run values are compared.
+        Ok(REEArray::with_offset_and_size(
+            self.ends(),
+            compare(&self.values(), other, operator)?,
+            self.validity(),
+            self.len(),
+            self.offset(),
+        )?
+        .into_array())
+    }
+}
+
+impl FilterFn for REEArray<'_> {
+    fn filter(&self, mask: &BoolArray) -> VortexResult<OwnedArray> {
+        let mask_buffer = mask.boolean_buffer();
+        let ends = self.ends().flatten_primitive()?;
+
+        // Keep every run with at least one selected value, shortened to its selected values.
+        let mut new_ends = Vec::new();
+        let mut kept_runs = Vec::new();
+        let mut start = 0;
+        match_each_integer_ptype!(ends.ptype(), |$P| {
+            for (run, &end) in ends.typed_data::<$P>().iter().enumerate() {
+                let end = (end as usize)
+                    .saturating_sub(self.offset())
+                    .min(self.len());
+                if end <= start {
+                    continue;
+                }
+                let selected = mask_buffer.slice(start, end - start).count_set_bits();
+                if selected > 0 {
+                    new_ends.push(new_ends.last().copied().unwrap_or(0) + selected as u64);
+                    kept_runs.push(run as u64);
+                }
+                start = end;
+            }
+        });
+
+        Ok(REEArray::with_offset_and_size(
+            PrimitiveArray::from(new_ends).into_array(),
+            take(
+                &self.values(),
+                &PrimitiveArray::from(kept_runs).into_array(),
+            )?,
+            self.validity().filter(mask)?,
+            mask_buffer.count_set_bits(),
+            0,
+        )?
+        .into_array())
+    }
+}
+
 impl TakeFn for REEArray<'_> {
     fn take(&self, indices: &Array) -> VortexResult<OwnedArray> {
         let primitive_indices = indices.clone().flatten_primitive()?;
@@ -67,9 +246,18 @@ impl SliceFn for REEArray<'_> {
 
 #[cfg(test)]
 mod test {
+    use vortex::array::bool::BoolArray;
     use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::aggregate::{count_distinct, min_max, sum};
+    use vortex::compute::arithmetic::{arithmetic_scalar, BinaryOperator, OverflowMode};
+    use vortex::compute::cast::cast;
+    use vortex::compute::compare::{compare, Operator};
+    use vortex::compute::filter::filter;
+    use vortex::compute::slice::slice;
     use vortex::compute::take::take;
-    use vortex::ToArray;
+    use vortex::validity::ArrayValidity;
+    use vortex::{ArrayDType, IntoArray, ToArray};
+    use vortex_dtype::{DType, Nullability, PType};
 
     use crate::REEArray;
 
@@ -85,4 +273,122 @@ mod test {
             &[5, 5, 1, 4]
         );
     }
+
+    #[test]
+    fn ree_arithmetic_scalar() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        let sliced = slice(ree.array(), 2, 9).unwrap();
+        let result = arithmetic_scalar(
+            &sliced,
+            &10.into(),
+            BinaryOperator::Mul,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert_eq!(
+            REEArray::try_from(&result).unwrap().values().len(),
+            REEArray::try_from(&sliced).unwrap().values().len()
+        );
+        assert_eq!(
+            result.flatten_primitive().unwrap().typed_data::<i32>(),
+            [10, 40, 40, 40, 20, 20, 50]
+        );
+    }
+
+    #[test]
+    fn ree_aggregates() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        let sliced = slice(ree.array(), 2, 9).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Some(22i64).into());
+        assert_eq!(
+            min_max(&sliced).unwrap(),
+            (Some(1i32).into(), Some(5i32).into())
+        );
+        assert_eq!(count_distinct(&sliced).unwrap(), 4);
+    }
+
+    #[test]
+    fn ree_compare() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        let sliced = slice(ree.array(), 2, 9).unwrap();
+        let result = compare(&sliced, &3.into(), Operator::Gt).unwrap();
+        assert_eq!(
+            REEArray::try_from(&result).unwrap().values().len(),
+            REEArray::try_from(&sliced).unwrap().values().len()
+        );
+        assert_eq!(
+            result
+                .flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, true, true, true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn ree_filter() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        let mask = BoolArray::from(vec![
+            true, false, true, false, false, false, true, false, false, true, true, false,
+        ]);
+        let filtered = REEArray::try_from(filter(ree.array(), &mask).unwrap()).unwrap();
+        assert_eq!(filtered.values().len(), 3);
+        assert_eq!(
+            filtered
+                .into_array()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            &[1, 1, 2, 5, 5]
+        );
+    }
+
+    #[test]
+    fn ree_filter_sliced() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        let sliced = slice(ree.array(), 4, 10).unwrap();
+        let mask = BoolArray::from(vec![true, false, false, true, true, false]);
+        let filtered = filter(&sliced, &mask).unwrap();
+        assert_eq!(
+            filtered.flatten_primitive().unwrap().typed_data::<i32>(),
+            &[4, 2, 5]
+        );
+    }
+
+    #[test]
+    fn ree_cast() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![Some(1u8), Some(1), None, Some(200)]).to_array(),
+        )
+        .unwrap();
+        let dtype = DType::Primitive(PType::I32, Nullability::Nullable);
+        let casted = cast(ree.array(), &dtype).unwrap();
+        assert_eq!(casted.dtype(), &dtype);
+        let casted_ree = REEArray::try_from(&casted).unwrap();
+        assert_eq!(casted_ree.ends().len(), ree.ends().len());
+        let flattened = casted.flatten_primitive().unwrap();
+        assert_eq!(flattened.typed_data::<i32>()[3], 200);
+        assert!(!flattened.is_valid(2));
+
+        let non_nullable = DType::Primitive(PType::I32, Nullability::NonNullable);
+        assert!(cast(ree.array(), &non_nullable).is_err());
+        assert!(cast(&slice(ree.array(), 0, 2).unwrap(), &non_nullable).is_ok());
+    }
 }
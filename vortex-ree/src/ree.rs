@@ -8,7 +8,7 @@ use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
 use vortex_error::vortex_bail;
 
-use crate::compress::{ree_decode, ree_encode};
+use crate::compress::{ree_decode, ree_decode_bool, ree_encode};
 
 impl_encoding!("vortex.ree", REE);
 
@@ -117,6 +117,11 @@ impl ArrayFlatten for REEArray<'_> {
         Self: 'a,
     {
         let pends = self.ends().flatten_primitive()?;
+        if let DType::Bool(_) = self.dtype() {
+            let pvalues = self.values().flatten_bool()?;
+            return ree_decode_bool(&pends, &pvalues, self.validity(), self.offset(), self.len())
+                .map(Flattened::Bool);
+        }
         let pvalues = self.values().flatten_primitive()?;
         ree_decode(&pends, &pvalues, self.validity(), self.offset(), self.len())
             .map(Flattened::Primitive)
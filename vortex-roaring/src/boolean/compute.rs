@@ -1,14 +1,23 @@
 use croaring::Bitmap;
+use vortex::compute::boolean::{BooleanFn, BooleanOperator, NotFn};
 use vortex::compute::scalar_at::ScalarAtFn;
 use vortex::compute::slice::SliceFn;
 use vortex::compute::ArrayCompute;
-use vortex::{IntoArray, OwnedArray};
+use vortex::{Array, ArrayTrait, IntoArray, OwnedArray};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::RoaringBoolArray;
 
 impl ArrayCompute for RoaringBoolArray<'_> {
+    fn boolean(&self) -> Option<&dyn BooleanFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -18,6 +27,32 @@ impl ArrayCompute for RoaringBoolArray<'_> {
     }
 }
 
+impl BooleanFn for RoaringBoolArray<'_> {
+    fn boolean(&self, other: &Array, operator: BooleanOperator) -> VortexResult<OwnedArray> {
+        // Roaring arrays have no nulls, so two of them can be combined as plain bitmaps.
+        let Ok(other) = RoaringBoolArray::try_from(other) else {
+            let flattened = self.array().clone().flatten_bool()?;
+            return BooleanFn::boolean(&flattened, other, operator);
+        };
+
+        let (lhs, rhs) = (self.bitmap(), other.bitmap());
+        let bitmap = match operator {
+            BooleanOperator::And => lhs.and(&rhs),
+            BooleanOperator::Or => lhs.or(&rhs),
+            BooleanOperator::AndNot => lhs.andnot(&rhs),
+            BooleanOperator::Xor => lhs.xor(&rhs),
+        };
+        RoaringBoolArray::try_new(bitmap, self.len()).map(|a| a.into_array())
+    }
+}
+
+impl NotFn for RoaringBoolArray<'_> {
+    fn not(&self) -> VortexResult<OwnedArray> {
+        let bitmap = self.bitmap().flip(0..self.len() as u32);
+        RoaringBoolArray::try_new(bitmap, self.len()).map(|a| a.into_array())
+    }
+}
+
 impl ScalarAtFn for RoaringBoolArray<'_> {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         if self.bitmap().contains(index as u32) {
@@ -36,3 +71,63 @@ impl SliceFn for RoaringBoolArray<'_> {
         RoaringBoolArray::try_new(bitmap, stop - start).map(|a| a.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::bool::BoolArray;
+    use vortex::compute::boolean::{and, and_not, not, or, xor};
+    use vortex::compute::scalar_at::scalar_at;
+    use vortex::{Array, ArrayDef, IntoArray, OwnedArray};
+
+    use crate::{RoaringBool, RoaringBoolArray};
+
+    fn roaring(values: Vec<bool>) -> OwnedArray {
+        RoaringBoolArray::encode(BoolArray::from(values).into_array()).unwrap()
+    }
+
+    fn to_vec(array: &Array) -> Vec<bool> {
+        array
+            .clone()
+            .flatten_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn roaring_operators() {
+        let lhs = roaring(vec![true, true, false, false, false]);
+        let rhs = roaring(vec![true, false, true, false, false]);
+
+        let result = and(&lhs, &rhs).unwrap();
+        assert_eq!(result.encoding().id(), RoaringBool::ID);
+        assert_eq!(to_vec(&result), [true, false, false, false, false]);
+        assert_eq!(
+            to_vec(&or(&lhs, &rhs).unwrap()),
+            [true, true, true, false, false]
+        );
+        assert_eq!(
+            to_vec(&and_not(&lhs, &rhs).unwrap()),
+            [false, true, false, false, false]
+        );
+        assert_eq!(
+            to_vec(&xor(&lhs, &rhs).unwrap()),
+            [false, true, true, false, false]
+        );
+        assert_eq!(
+            to_vec(&not(&lhs).unwrap()),
+            [false, false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn roaring_with_nullable_bool() {
+        let lhs = roaring(vec![true, true, false]);
+        let rhs = BoolArray::from_iter([Some(false), None, None]).into_array();
+        let result = or(&lhs, &rhs).unwrap();
+        assert!(bool::try_from(scalar_at(&result, 0).unwrap()).unwrap());
+        assert!(bool::try_from(scalar_at(&result, 1).unwrap()).unwrap());
+        assert!(scalar_at(&result, 2).unwrap().is_null());
+    }
+}
@@ -1,5 +1,4 @@
-use arrow_buffer::BooleanBuffer;
-use arrow_buffer::Buffer as ArrowBuffer;
+use arrow_buffer::BooleanBufferBuilder;
 use compress::roaring_encode;
 use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
@@ -93,16 +92,14 @@ impl ArrayFlatten for RoaringBoolArray<'_> {
         Self: 'a,
     {
         // TODO(ngates): benchmark the fastest conversion from BitMap.
-        //  Via bitset requires two copies.
-        let bitset = self
-            .bitmap()
-            .to_bitset()
-            .ok_or(vortex_err!("Failed to convert RoaringBitmap to Bitset"))?;
-
-        let bytes = &bitset.as_slice()[0..bitset.size_in_bytes()];
-        let buffer = ArrowBuffer::from_slice_ref(bytes);
+        let mut builder = BooleanBufferBuilder::new(self.len());
+        builder.append_n(self.len(), false);
+        for index in self.bitmap().iter() {
+            builder.set_bit(index as usize, true);
+        }
+
         Ok(Flattened::Bool(BoolArray::try_new(
-            BooleanBuffer::new(buffer, 0, bitset.size_in_bits()),
+            builder.finish(),
             match self.dtype().nullability() {
                 NonNullable => Validity::NonNullable,
                 Nullable => Validity::AllValid,
@@ -5,7 +5,7 @@ use vortex_dtype::Nullability::{NonNullable, Nullable};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::value::ScalarValue;
-use crate::Scalar;
+use crate::{Scalar, Utf8Scalar};
 
 pub type BinaryScalar = ScalarValue<Vec<u8>>;
 
@@ -18,8 +18,22 @@ impl BinaryScalar {
         }
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match dtype {
+            DType::Binary(n) => Ok(Self::try_new(self.value().cloned(), *n)?.into()),
+            DType::Utf8(n) => {
+                let value = self
+                    .value()
+                    .map(|v| {
+                        String::from_utf8(v.clone()).map_err(
+                            |_| vortex_err!(ComputeError: "Binary value is not valid UTF-8"),
+                        )
+                    })
+                    .transpose()?;
+                Ok(Utf8Scalar::try_new(value, *n)?.into())
+            }
+            _ => Err(vortex_err!(MismatchedTypes: "binary", dtype)),
+        }
     }
 
     pub fn nbytes(&self) -> usize {
@@ -4,7 +4,7 @@ use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::value::ScalarValue;
-use crate::Scalar;
+use crate::{PrimitiveScalar, Scalar};
 
 pub type BoolScalar = ScalarValue<bool>;
 
@@ -19,7 +19,12 @@ impl BoolScalar {
 
     pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
         match dtype {
-            DType::Bool(_) => Ok(self.clone().into()),
+            DType::Bool(n) => Ok(Self::try_new(self.value().copied(), *n)?.into()),
+            // Booleans are cast to numbers as 0 and 1.
+            DType::Primitive(..) => match self.value() {
+                None => Scalar::from(PrimitiveScalar::none::<u8>()).cast(dtype),
+                Some(b) => Scalar::from(*b as u8).cast(dtype),
+            },
             _ => Err(vortex_err!(MismatchedTypes: "bool", dtype)),
         }
     }
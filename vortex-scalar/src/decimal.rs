@@ -0,0 +1,72 @@
+use vortex_dtype::{DecimalDType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::{ExtScalar, Scalar};
+
+impl ExtScalar {
+    /// Create a decimal scalar from its unscaled value.
+    pub fn decimal(
+        decimal: DecimalDType,
+        nullability: Nullability,
+        value: Option<i128>,
+    ) -> VortexResult<Self> {
+        let storage = value
+            .map(|v| -> VortexResult<Scalar> {
+                if decimal.is_i64() {
+                    let v = i64::try_from(v)
+                        .map_err(|_| vortex_err!("Value {} does not fit into a {}", v, decimal))?;
+                    Ok(v.into())
+                } else {
+                    decimal.value_to_bytes(v).map(Scalar::from)
+                }
+            })
+            .transpose()?;
+        ExtScalar::try_new(decimal.ext_dtype(), nullability, storage)
+    }
+
+    /// The unscaled value of a decimal scalar, returning an error if it does not fit into an i128.
+    pub fn decimal_value(&self) -> VortexResult<Option<i128>> {
+        let decimal = DecimalDType::try_from(self.ext_dtype())?;
+        let Some(storage) = self.value() else {
+            return Ok(None);
+        };
+        if decimal.is_i64() {
+            return i64::try_from(storage.as_ref()).map(|v| Some(v as i128));
+        }
+        match storage.as_ref() {
+            Scalar::Binary(b) => {
+                let Some(bytes) = b.value() else {
+                    return Ok(None);
+                };
+                decimal.value_from_bytes(bytes.as_slice()).map(Some)
+            }
+            s => vortex_bail!(MismatchedTypes: "binary", s.dtype()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DecimalDType, Nullability};
+
+    use crate::ExtScalar;
+
+    #[test]
+    fn decimal_round_trip() {
+        for precision in [10, 30, 60] {
+            let decimal = DecimalDType::try_new(precision, 2).unwrap();
+            let scalar = ExtScalar::decimal(decimal, Nullability::Nullable, Some(-12345)).unwrap();
+            assert_eq!(scalar.decimal_value().unwrap(), Some(-12345));
+            assert_eq!(scalar.to_string(), "-123.45");
+
+            let null = ExtScalar::decimal(decimal, Nullability::Nullable, None).unwrap();
+            assert_eq!(null.decimal_value().unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn decimal_overflow() {
+        let decimal = DecimalDType::try_new(18, 0).unwrap();
+        assert!(ExtScalar::decimal(decimal, Nullability::NonNullable, Some(i128::MAX)).is_err());
+    }
+}
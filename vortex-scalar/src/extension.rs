@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
-use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, Nullability};
+use vortex_dtype::{DType, DecimalDType, ExtDType, ExtID, ExtMetadata, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::Scalar;
@@ -85,6 +85,19 @@ impl PartialOrd for ExtScalar {
 
 impl Display for ExtScalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Ok(decimal) = DecimalDType::try_from(self.ext_dtype()) {
+            // Binary values are formatted directly, since they may not fit into an i128.
+            return match self.value().map(|s| s.as_ref()) {
+                Some(Scalar::Binary(b)) => match b.value() {
+                    Some(bytes) => write!(f, "{}", decimal.format_le_bytes(bytes.as_slice())),
+                    None => write!(f, "<null>"),
+                },
+                _ => match self.decimal_value() {
+                    Ok(Some(v)) => write!(f, "{}", decimal.format_value(v)),
+                    _ => write!(f, "<null>"),
+                },
+            };
+        }
         write!(
             f,
             "{} ({})",
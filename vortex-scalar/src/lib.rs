@@ -14,6 +14,7 @@ use vortex_error::VortexResult;
 
 mod binary;
 mod bool;
+mod decimal;
 mod extension;
 mod list;
 mod null;
@@ -10,7 +10,7 @@ use vortex_dtype::{DType, Nullability};
 use vortex_dtype::{NativePType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
-use crate::Scalar;
+use crate::{BoolScalar, Scalar, Utf8Scalar};
 
 pub trait PScalarType: NativePType + Into<PScalar> + TryFrom<PScalar, Error = VortexError> {}
 
@@ -86,17 +86,26 @@ impl PrimitiveScalar {
     }
 
     pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
-        let ptype: PType = dtype.try_into()?;
-        match_each_native_ptype!(ptype, |$T| {
-            Ok(PrimitiveScalar::try_new(
-                self.value()
-                .map(|ps| ps.cast_ptype(ptype))
-                .transpose()?
-                .map(|s| $T::try_from(s))
-                .transpose()?,
-                self.nullability,
-            )?.into())
-        })
+        match dtype {
+            DType::Primitive(ptype, n) => match_each_native_ptype!(ptype, |$T| {
+                Ok(PrimitiveScalar::try_new(
+                    self.value()
+                    .map(|ps| ps.cast_ptype(*ptype))
+                    .transpose()?
+                    .map(|s| $T::try_from(s))
+                    .transpose()?,
+                    *n,
+                )?.into())
+            }),
+            // Numbers are true if they are non-zero.
+            DType::Bool(n) => {
+                Ok(BoolScalar::try_new(self.value().map(|v| !v.is_zero()), *n)?.into())
+            }
+            DType::Utf8(n) if self.ptype.is_int() => {
+                Ok(Utf8Scalar::try_new(self.value().map(|v| v.to_string()), *n)?.into())
+            }
+            _ => Err(vortex_err!(MismatchedTypes: self.ptype, dtype)),
+        }
     }
 
     pub fn nbytes(&self) -> usize {
@@ -440,4 +449,35 @@ mod test {
         let u32_scalar_ptype: PType = u32_scalar.dtype().try_into().unwrap();
         assert_eq!(u32_scalar_ptype, PType::U32);
     }
+
+    #[test]
+    fn cast_nullability_and_kinds() {
+        let scalar: Scalar = 10u16.into();
+        let nullable = scalar
+            .cast(&DType::Primitive(PType::U16, Nullability::Nullable))
+            .unwrap();
+        assert!(nullable.dtype().is_nullable());
+
+        let null: Scalar = Option::<u16>::None.into();
+        assert!(null
+            .cast(&DType::Primitive(PType::U32, Nullability::NonNullable))
+            .is_err());
+
+        let string = scalar.cast(&DType::Utf8(Nullability::NonNullable)).unwrap();
+        assert_eq!(String::try_from(string).unwrap(), "10");
+        let bool = scalar.cast(&DType::Bool(Nullability::NonNullable)).unwrap();
+        assert!(bool::try_from(bool).unwrap());
+        let back = Scalar::from(true)
+            .cast(&DType::Primitive(PType::I64, Nullability::NonNullable))
+            .unwrap();
+        assert_eq!(i64::try_from(back).unwrap(), 1);
+
+        let binary = Scalar::from("abc")
+            .cast(&DType::Binary(Nullability::NonNullable))
+            .unwrap();
+        assert_eq!(Vec::<u8>::try_from(binary).unwrap(), b"abc");
+        assert!(Scalar::from(vec![0xffu8])
+            .cast(&DType::Utf8(Nullability::NonNullable))
+            .is_err());
+    }
 }
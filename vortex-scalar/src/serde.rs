@@ -4,12 +4,14 @@ use flatbuffers::{root, FlatBufferBuilder, WIPOffset};
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use vortex_dtype::match_each_native_ptype;
-use vortex_dtype::Nullability;
-use vortex_error::{vortex_bail, VortexError};
+use vortex_dtype::{ExtDType, ExtID, ExtMetadata, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError};
 use vortex_flatbuffers::{FlatBufferRoot, FlatBufferToBytes, ReadFlatBuffer, WriteFlatBuffer};
 
 use crate::flatbuffers::scalar as fb;
-use crate::{PScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::{
+    BinaryScalar, BoolScalar, ExtScalar, NullScalar, PScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+};
 
 impl FlatBufferRoot for Scalar {}
 
@@ -116,20 +118,23 @@ impl ReadFlatBuffer for Scalar {
     fn read_flatbuffer(fb: &Self::Source<'_>) -> Result<Self, Self::Error> {
         let nullability = Nullability::from(fb.nullability());
         match fb.type_type() {
-            fb::Type::Binary => {
-                todo!()
-            }
-            fb::Type::Bool => {
-                todo!()
-            }
-            fb::Type::List => {
-                todo!()
-            }
-            fb::Type::Null => {
-                todo!()
-            }
+            fb::Type::Binary => Ok(Scalar::Binary(BinaryScalar::try_new(
+                fb.type__as_binary()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "missing Binary value"))?
+                    .value()
+                    .map(|bytes| bytes.bytes().to_vec()),
+                nullability,
+            )?)),
+            fb::Type::Bool => Ok(Scalar::Bool(BoolScalar::try_new(
+                fb.type__as_bool().map(|b| b.value()),
+                nullability,
+            )?)),
+            fb::Type::List => vortex_bail!(InvalidSerde: "List scalars cannot be deserialized"),
+            fb::Type::Null => Ok(Scalar::Null(NullScalar::new())),
             fb::Type::Primitive => {
-                let primitive = fb.type__as_primitive().expect("missing Primitive value");
+                let primitive = fb
+                    .type__as_primitive()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "missing Primitive value"))?;
                 let ptype = primitive.ptype().try_into()?;
                 Ok(match_each_native_ptype!(ptype, |$T| {
                     Scalar::Primitive(PrimitiveScalar::try_new(
@@ -143,15 +148,31 @@ impl ReadFlatBuffer for Scalar {
                 }))
             }
             fb::Type::Struct_ => {
-                todo!()
+                vortex_bail!(InvalidSerde: "Struct scalars cannot be deserialized")
             }
             fb::Type::UTF8 => Ok(Scalar::Utf8(Utf8Scalar::try_new(
                 fb.type__as_utf8()
-                    .expect("missing UTF8 value")
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "missing UTF8 value"))?
                     .value()
                     .map(|s| s.to_string()),
                 nullability,
             )?)),
+            fb::Type::Extension => {
+                let ext = fb
+                    .type__as_extension()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "missing Extension value"))?;
+                let id = ext
+                    .id()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "missing id"))?;
+                let metadata = ext.metadata().map(|m| ExtMetadata::from(m.bytes()));
+                Ok(Scalar::Extension(ExtScalar::try_new(
+                    ExtDType::new(ExtID::from(id), metadata),
+                    nullability,
+                    ext.value()
+                        .map(|v| Scalar::read_flatbuffer(&v))
+                        .transpose()?,
+                )?))
+            }
             _ => vortex_bail!(InvalidSerde: "Unrecognized scalar type"),
         }
     }
@@ -248,3 +269,41 @@ impl<'de> Deserialize<'de> for Scalar {
 //         }
 //     }
 // }
+
+#[cfg(test)]
+mod test {
+    use flatbuffers::{root, FlatBufferBuilder};
+    use vortex_dtype::Nullability;
+    use vortex_flatbuffers::ReadFlatBuffer;
+
+    use crate::flatbuffers::scalar as fb;
+    use crate::Scalar;
+
+    fn read_empty(type_type: fb::Type) -> bool {
+        let mut fbb = FlatBufferBuilder::new();
+        let scalar = fb::Scalar::create(
+            &mut fbb,
+            &fb::ScalarArgs {
+                type_type,
+                type_: None,
+                nullability: Nullability::Nullable.into(),
+            },
+        );
+        fbb.finish_minimal(scalar);
+        Scalar::read_flatbuffer(&root::<fb::Scalar>(fbb.finished_data()).unwrap()).is_ok()
+    }
+
+    #[test]
+    fn malformed_scalars() {
+        for type_type in [
+            fb::Type::Binary,
+            fb::Type::List,
+            fb::Type::Primitive,
+            fb::Type::Struct_,
+            fb::Type::UTF8,
+            fb::Type::Extension,
+        ] {
+            assert!(!read_empty(type_type), "{:?}", type_type);
+        }
+    }
+}
@@ -4,7 +4,7 @@ use vortex_dtype::{DType, Nullability::NonNullable, Nullability::Nullable};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::value::ScalarValue;
-use crate::Scalar;
+use crate::{BinaryScalar, Scalar};
 
 pub type Utf8Scalar = ScalarValue<String>;
 
@@ -17,8 +17,14 @@ impl Utf8Scalar {
         }
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match dtype {
+            DType::Utf8(n) => Ok(Self::try_new(self.value().cloned(), *n)?.into()),
+            DType::Binary(n) => {
+                Ok(BinaryScalar::try_new(self.value().map(|s| s.as_bytes().to_vec()), *n)?.into())
+            }
+            _ => Err(vortex_err!(MismatchedTypes: "utf8", dtype)),
+        }
     }
 
     pub fn nbytes(&self) -> usize {